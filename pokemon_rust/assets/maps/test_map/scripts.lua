@@ -1,5 +1,7 @@
 require("pokemon_rust.lua.events");
+require("pokemon_rust.lua.i18n");
 require("pokemon_rust.lua.npc");
+require("pokemon_rust.lua.variables");
 
 function on_map_enter()
     BgmChangeEvent:new("littleroot-town.wav"):dispatch()
@@ -8,15 +10,13 @@ end
 
 function do_it()
     ChainedEvents:new({
-        TextEvent:new("Hello, world!"),
+        TextEvent:new(tr("test_map.hello_world")),
         WarpEvent:new("test_map", 10, 10)
     }):dispatch()
 end
 
 function interact_with_tree()
-    TextEvent:new(
-        "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua."
-    ):dispatch()
+    TextEvent:new(tr("test_map.tree")):dispatch()
 end
 
 function on_map_load()
@@ -56,8 +56,23 @@ function interact_with_npc(npc)
 
     if npc == FIRST_NPC then
         print("Interacted with the first NPC")
-        TextEvent:new("Hello, world!"):dispatch()
+
+        if Variables.get_flag("TALKED_TO_FIRST_NPC") then
+            TextEvent:new(tr("test_map.first_npc_again")):dispatch()
+        else
+            Variables.set_flag("TALKED_TO_FIRST_NPC")
+            TextEvent:new(tr("test_map.first_npc_greeting")):dispatch()
+        end
     elseif npc == SECOND_NPC then
         print("Interacted with the second NPC")
+
+        if ChoiceEvent:yes_no(tr("test_map.second_npc_question")):await() then
+            TextEvent:new(tr("test_map.second_npc_yes")):await()
+            Variables.set_var("LUA_FANS", Variables.get_var("LUA_FANS") + 1)
+        else
+            TextEvent:new(tr("test_map.second_npc_no")):await()
+        end
+
+        TextEvent:new(tr("test_map.second_npc_farewell")):dispatch()
     end
 end
@@ -1,3 +1,23 @@
+require("pokemon_rust.lua.i18n");
+
+Callbacks = { next_key = 0, functions = {} }
+
+function Callbacks.register(callback)
+    local key = Callbacks.next_key
+    Callbacks.next_key = key + 1
+    Callbacks.functions[key] = callback
+    return key
+end
+
+-- Every callback runs once, so it's removed before running. This keeps the
+-- table from growing even if the callback fails or suspends the script.
+function Callbacks.run(key, ...)
+    local callback = Callbacks.functions[key]
+    Callbacks.functions[key] = nil
+    callback(...)
+end
+
+
 Event = {}
 Event.__index = Event
 
@@ -11,6 +31,13 @@ function Event:dispatch()
     rust_dispatch_event(self[1])
 end
 
+-- Dispatches this event and suspends the running script until the event is
+-- complete. The script is then resumed on the next frame. Events that were
+-- created but not dispatched before suspending can't be used afterwards.
+function Event:await()
+    coroutine.yield(rust_await_event(self[1]))
+end
+
 
 BgmChangeEvent = Event:new()
 BgmChangeEvent.__index = BgmChangeEvent
@@ -26,6 +53,49 @@ function BgmChangeEvent.preload(filename)
 end
 
 
+ChoiceEvent = Event:new()
+ChoiceEvent.__index = ChoiceEvent
+
+-- The callback receives the index of the chosen option, starting from 1. It
+-- can be omitted if the event is awaited, since await() returns that index.
+function ChoiceEvent:new(text, options, callback)
+    local obj = {}
+    local key = Callbacks.register(function(option)
+        obj.choice = option
+
+        if callback ~= nil then
+            callback(option)
+        end
+    end)
+
+    obj[1] = rust_create_choice_event(text, options, key)
+    setmetatable(obj, self)
+    return obj
+end
+
+-- The callback receives true if the player chose "Yes", and so does await().
+function ChoiceEvent:yes_no(text, callback)
+    local obj = ChoiceEvent:new(text, { tr("choice.yes"), tr("choice.no") }, function(option)
+        if callback ~= nil then
+            callback(option == 1)
+        end
+    end)
+
+    obj.is_yes_no = true
+    return obj
+end
+
+function ChoiceEvent:await()
+    Event.await(self)
+
+    if self.is_yes_no then
+        return self.choice == 1
+    end
+
+    return self.choice
+end
+
+
 CyclicEvent = Event:new()
 CyclicEvent.__index = CyclicEvent
 
@@ -0,0 +1,6 @@
+-- Returns the string corresponding to a given id in the selected locale.
+-- Placeholders like {name} are replaced by the values in the optional args
+-- table, e.g. tr("greeting", { name = "Red" }).
+function tr(key, args)
+    return rust_tr(key, args)
+end
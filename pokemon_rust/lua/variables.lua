@@ -0,0 +1,29 @@
+Variables = {}
+
+function Variables.get_flag(name)
+    return rust_get_flag(name)
+end
+
+function Variables.set_flag(name, value)
+    if value == nil then
+        value = true
+    end
+
+    rust_set_flag(name, value)
+end
+
+function Variables.get_var(name)
+    return rust_get_var(name)
+end
+
+function Variables.set_var(name, value)
+    rust_set_var(name, value)
+end
+
+function Variables.get_string(name)
+    return rust_get_string(name)
+end
+
+function Variables.set_string(name, value)
+    rust_set_string(name, value)
+end
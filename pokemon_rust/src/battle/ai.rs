@@ -0,0 +1,401 @@
+//! Decision makers for computer-controlled teams. An AI only observes the
+//! backend and picks the action of its team, which is then sent to the
+//! backend as a `FrontendEvent`, just like the player's choices.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::pokemon::{
+    get_all_moves,
+    movement::Move,
+    Stat,
+};
+
+use super::backend::{
+    damage_calc::DamageCalcOptions,
+    rng::StandardBattleRng,
+    BattleBackend,
+    FrontendEvent,
+    FrontendEventKind,
+    Team,
+};
+
+use std::{
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+
+pub trait BattleAi {
+    /// Picks the action that a team will take in the next turn.
+    fn choose_action(&mut self, backend: &BattleBackend, team: Team) -> FrontendEventKind;
+
+    /// Picks the action of a team, wrapping it in a `FrontendEvent`.
+    fn choose_event(&mut self, backend: &BattleBackend, team: Team) -> FrontendEvent {
+        FrontendEvent {
+            team,
+            event: self.choose_action(backend, team),
+        }
+    }
+}
+
+/// An AI that always uses the first move of its active Pokémon.
+#[derive(Clone, Debug, Default)]
+pub struct FirstMoveAi;
+
+impl BattleAi for FirstMoveAi {
+    fn choose_action(&mut self, _backend: &BattleBackend, _team: Team) -> FrontendEventKind {
+        FrontendEventKind::UseMove(0)
+    }
+}
+
+/// An AI that uses a random move of its active Pokémon.
+#[derive(Clone, Debug)]
+pub struct RandomAi {
+    rng: StdRng,
+}
+
+impl RandomAi {
+    pub fn new(seed: u64) -> RandomAi {
+        RandomAi {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl BattleAi for RandomAi {
+    fn choose_action(&mut self, backend: &BattleBackend, team: Team) -> FrontendEventKind {
+        let moves = get_available_moves(backend, team);
+        let (index, _) = moves.choose(&mut self.rng).unwrap();
+
+        FrontendEventKind::UseMove(*index)
+    }
+}
+
+/// An AI that uses the move with the highest expected damage against the
+/// opposing Pokémon, according to the damage calculator and the accuracy of
+/// the move. Status moves are only used if there's nothing better.
+#[derive(Clone, Debug, Default)]
+pub struct GreedyAi;
+
+impl GreedyAi {
+    fn get_expected_damage(
+        backend: &BattleBackend,
+        movement: &Move,
+        user: usize,
+        target: usize,
+    ) -> f32 {
+        let damage = backend
+            .calculate_damage(user, target, movement, &DamageCalcOptions::default())
+            .map_or(0., |range| range.get_average());
+        let accuracy = movement.accuracy.map_or(1., |accuracy| accuracy as f32 / 100.);
+        let hits = movement.multi_hit.as_ref().map_or(1., |_| 3.);
+
+        damage * accuracy * hits
+    }
+}
+
+impl BattleAi for GreedyAi {
+    fn choose_action(&mut self, backend: &BattleBackend, team: Team) -> FrontendEventKind {
+        let user = backend.get_active_pokemon_index(team).unwrap();
+        let target = backend
+            .get_active_pokemon_index(team.get_opponent())
+            .unwrap();
+
+        let (index, _) = get_available_moves(backend, team)
+            .into_iter()
+            .map(|(index, movement)| {
+                let damage = Self::get_expected_damage(backend, movement, user, target);
+                (index, damage)
+            })
+            .fold(None, |best: Option<(usize, f32)>, (index, damage)| match best {
+                Some((_, best_damage)) if best_damage >= damage => best,
+                _ => Some((index, damage)),
+            })
+            .unwrap();
+
+        FrontendEventKind::UseMove(index)
+    }
+}
+
+/// An AI that searches a few turns ahead, e.g for boss trainers.
+///
+/// For every pair of actions of both teams, the AI simulates the turn on
+/// copies of the backend. Each simulation draws its own RNG outcomes (misses,
+/// secondary effects, damage rolls, multi-hit counts...), so averaging them
+/// gives the expected value of the turn. The AI assumes that the opponent
+/// picks the action that is worst for it, and deepens the search one turn at
+/// a time until it reaches `max_depth` or runs out of simulations. Since the
+/// budget counts simulated turns instead of time, the AI picks the same
+/// action for the same seed regardless of how fast the machine is.
+#[derive(Clone, Debug)]
+pub struct ExpertAi {
+    rng: StdRng,
+    /// How many turns ahead the AI looks at most.
+    max_depth: usize,
+    /// How many times each pair of actions is simulated.
+    samples: usize,
+    /// How many turns the AI can simulate before it has to pick an action.
+    /// The deepest search that finished within the budget is the one that
+    /// decides.
+    max_simulations: usize,
+    /// How many turns the AI can still simulate for the current action.
+    remaining_simulations: usize,
+}
+
+impl ExpertAi {
+    pub fn new(seed: u64) -> ExpertAi {
+        ExpertAi {
+            rng: StdRng::seed_from_u64(seed),
+            max_depth: 2,
+            samples: 4,
+            max_simulations: 5000,
+            remaining_simulations: 0,
+        }
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> ExpertAi {
+        self.max_depth = max_depth.max(1);
+        self
+    }
+
+    pub fn with_samples(mut self, samples: usize) -> ExpertAi {
+        self.samples = samples.max(1);
+        self
+    }
+
+    pub fn with_max_simulations(mut self, max_simulations: usize) -> ExpertAi {
+        self.max_simulations = max_simulations;
+        self
+    }
+
+    /// Returns the action with the highest value for a team, looking `depth`
+    /// turns ahead. Returns `None` if the AI runs out of simulations.
+    fn search(
+        &mut self,
+        backend: &BattleBackend,
+        team: Team,
+        depth: usize,
+    ) -> Option<(FrontendEventKind, f32)> {
+        let actions = get_available_actions(backend, team);
+        let mut opponent_actions = get_available_actions(backend, team.get_opponent());
+
+        if backend.is_locked(team.get_opponent()) {
+            // The backend picks the action of the opponent anyway
+            opponent_actions.truncate(1);
+        }
+
+        let mut best: Option<(FrontendEventKind, f32)> = None;
+
+        for action in actions {
+            let mut worst_value = None;
+
+            for opponent_action in &opponent_actions {
+                let value =
+                    self.get_turn_value(backend, team, &action, opponent_action, depth)?;
+
+                worst_value = Some(worst_value.map_or(value, |worst: f32| worst.min(value)));
+            }
+
+            let value = worst_value.unwrap_or_else(|| evaluate(backend, team));
+
+            match best {
+                Some((_, best_value)) if best_value >= value => {},
+                _ => best = Some((action, value)),
+            }
+        }
+
+        best
+    }
+
+    /// Returns the average value of a turn in which both teams take the
+    /// given actions, over several RNG outcomes.
+    fn get_turn_value(
+        &mut self,
+        backend: &BattleBackend,
+        team: Team,
+        action: &FrontendEventKind,
+        opponent_action: &FrontendEventKind,
+        depth: usize,
+    ) -> Option<f32> {
+        let mut total = 0.;
+
+        for _ in 0..self.samples {
+            if self.remaining_simulations == 0 {
+                return None;
+            }
+
+            self.remaining_simulations -= 1;
+
+            let mut lookahead = backend.clone();
+            lookahead.rng = Box::new(StandardBattleRng::new(self.rng.gen()));
+            lookahead.push_frontend_event(FrontendEvent {
+                team,
+                event: action.clone(),
+            });
+            lookahead.push_frontend_event(FrontendEvent {
+                team: team.get_opponent(),
+                event: opponent_action.clone(),
+            });
+            lookahead.tick().for_each(drop);
+
+            total += if depth <= 1 || is_over(&lookahead) {
+                evaluate(&lookahead, team)
+            } else {
+                self.search(&lookahead, team, depth - 1)?.1
+            };
+        }
+
+        Some(total / self.samples as f32)
+    }
+}
+
+impl BattleAi for ExpertAi {
+    fn choose_action(&mut self, backend: &BattleBackend, team: Team) -> FrontendEventKind {
+        let actions = get_available_actions(backend, team);
+
+        if actions.len() == 1 || backend.is_locked(team) {
+            return actions[0].clone();
+        }
+
+        self.remaining_simulations = self.max_simulations;
+        let mut best_action = GreedyAi.choose_action(backend, team);
+
+        for depth in 1..=self.max_depth {
+            match self.search(backend, team, depth) {
+                Some((action, _)) => best_action = action,
+                None => break,
+            }
+        }
+
+        best_action
+    }
+}
+
+/// Runs an AI on a background thread, so that AIs that search for a while
+/// don't stall the frame that asks for their action.
+pub struct BackgroundAi {
+    team: Team,
+    /// The AI, while it isn't thinking.
+    ai: Option<Box<dyn BattleAi + Send>>,
+    /// Receives the AI back along with its action once it's done thinking.
+    pending: Option<Receiver<(Box<dyn BattleAi + Send>, FrontendEvent)>>,
+}
+
+impl BackgroundAi {
+    pub fn new(ai: Box<dyn BattleAi + Send>, team: Team) -> BackgroundAi {
+        BackgroundAi {
+            team,
+            ai: Some(ai),
+            pending: None,
+        }
+    }
+
+    /// Starts choosing the next action of the team on a copy of the backend.
+    /// Does nothing if the AI is already thinking.
+    pub fn start(&mut self, backend: &BattleBackend) {
+        let mut ai = match self.ai.take() {
+            Some(ai) => ai,
+            None => return,
+        };
+
+        let backend = backend.clone();
+        let team = self.team;
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let event = ai.choose_event(&backend, team);
+            // The receiver is gone if the battle ended in the meantime
+            let _ = sender.send((ai, event));
+        });
+
+        self.pending = Some(receiver);
+    }
+
+    /// Returns the chosen action once the AI is done thinking.
+    pub fn poll(&mut self) -> Option<FrontendEvent> {
+        let result = match self.pending.as_ref()?.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => panic!("The AI thread panicked"),
+        };
+
+        let (ai, event) = result;
+        self.ai = Some(ai);
+        self.pending = None;
+
+        Some(event)
+    }
+}
+
+/// Creates an AI by its name, which is one of "first", "random", "greedy" or
+/// "expert". The seed is only used by AIs that make random choices.
+pub fn create_ai(name: &str, seed: u64) -> Option<Box<dyn BattleAi + Send>> {
+    match name {
+        "first" => Some(Box::new(FirstMoveAi)),
+        "random" => Some(Box::new(RandomAi::new(seed))),
+        "greedy" => Some(Box::new(GreedyAi)),
+        "expert" => Some(Box::new(ExpertAi::new(seed))),
+        _ => None,
+    }
+}
+
+/// Returns the actions that a team can take. Switching isn't supported by
+/// the backend yet, so these are the available moves of its active Pokémon.
+pub fn get_available_actions(backend: &BattleBackend, team: Team) -> Vec<FrontendEventKind> {
+    get_available_moves(backend, team)
+        .into_iter()
+        .map(|(index, _)| FrontendEventKind::UseMove(index))
+        .collect()
+}
+
+/// Returns the moves that the active Pokémon of a team can use, along with
+/// their indices. Moves blocked by effects like Disable are left out, unless
+/// none of the moves can be used.
+pub fn get_available_moves(backend: &BattleBackend, team: Team) -> Vec<(usize, &'static Move)> {
+    let movedex = get_all_moves();
+
+    let known_moves: Vec<_> = backend
+        .get_active_pokemon_index(team)
+        .into_iter()
+        .flat_map(|pokemon| backend.get_moves(pokemon).iter().enumerate())
+        .filter_map(|(index, move_id)| move_id.map(|move_id| (index, movedex.get_move(move_id))))
+        .collect();
+
+    let usable_moves: Vec<_> = match backend.get_active_pokemon_index(team) {
+        Some(pokemon) => known_moves
+            .iter()
+            .filter(|(_, movement)| backend.can_use_move(pokemon, movement))
+            .copied()
+            .collect(),
+        None => Vec::new(),
+    };
+
+    if usable_moves.is_empty() {
+        known_moves
+    } else {
+        usable_moves
+    }
+}
+
+/// Checks if the active Pokémon of either team fainted.
+fn is_over(backend: &BattleBackend) -> bool {
+    [Team::P1, Team::P2]
+        .iter()
+        .any(|team| backend.get_active_pokemon(*team).any(|pokemon| pokemon.current_hp == 0))
+}
+
+/// Rates the state of a battle for a team, from -2 (its Pokémon fainted and
+/// the opposing one has full HP) to 2 (the opposite).
+fn evaluate(backend: &BattleBackend, team: Team) -> f32 {
+    let get_score = |team| match backend.get_active_pokemon_index(team) {
+        Some(pokemon) => {
+            let current_hp = backend.get_pokemon(pokemon).current_hp;
+            let max_hp = backend.get_stat(pokemon, Stat::HP);
+            let alive_bonus = if current_hp > 0 { 1. } else { 0. };
+
+            alive_bonus + current_hp as f32 / max_hp as f32
+        },
+        None => 0.,
+    };
+
+    get_score(team) - get_score(team.get_opponent())
+}
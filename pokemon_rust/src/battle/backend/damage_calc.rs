@@ -0,0 +1,246 @@
+//! A damage calculator that predicts the damage of a move without changing
+//! the state of the battle, e.g for AIs and the `damage_calc` tool.
+
+use crate::pokemon::{
+    movement::{FixedDamage, Move, MoveCategory, MoveFlag, MovePower},
+    PokemonType,
+    Stat,
+    StatusCondition,
+};
+
+use super::{get_base_damage, uses_damage_formula, BattleBackend, UsedMove};
+
+/// The random factors of the damage formula, in percent. Each one is equally
+/// likely.
+const DAMAGE_ROLLS: std::ops::RangeInclusive<usize> = 85..=100;
+
+/// Hypothetical conditions of a damage calculation. Fields set to `None` use
+/// the current state of the battle.
+#[derive(Clone, Debug, Default)]
+pub struct DamageCalcOptions {
+    /// Whether the move lands a critical hit. By default, the move only crits
+    /// if it's guaranteed to. Moves with fixed damage never crit.
+    pub is_critical_hit: Option<bool>,
+    /// Whether the user is burned, which halves the damage of physical moves
+    /// that don't deal fixed damage.
+    pub is_burned: Option<bool>,
+    /// Whether Reflect or Light Screen, whichever matches the category of
+    /// the move, protects the target.
+    pub has_screens: Option<bool>,
+    /// The weather of the battle. The backend doesn't have weather yet, so
+    /// by default there's none.
+    pub weather: Option<Weather>,
+}
+
+/// A weather that changes the damage of Fire and Water moves.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Weather {
+    Sun,
+    Rain,
+}
+
+impl Weather {
+    /// Returns the multiplier that the weather applies to the damage of a
+    /// move of the given type.
+    pub fn get_damage_multiplier(self, move_type: PokemonType) -> f32 {
+        match (self, move_type) {
+            (Weather::Sun, PokemonType::Fire) | (Weather::Rain, PokemonType::Water) => 1.5,
+            (Weather::Sun, PokemonType::Water) | (Weather::Rain, PokemonType::Fire) => 0.5,
+            _ => 1.,
+        }
+    }
+}
+
+/// The multipliers that went into a damage calculation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DamageFactors {
+    pub critical_hit: f32,
+    pub stab: f32,
+    pub effectiveness: f32,
+    /// Applied after the other factors, like in the backend.
+    pub burn: f32,
+    pub weather: f32,
+    /// The multiplier of the side conditions of the target, e.g Reflect.
+    pub screens: f32,
+}
+
+impl DamageFactors {
+    /// Returns the modifier of the damage formula for a random factor. The
+    /// burn is left out since it's applied to the final damage.
+    pub(super) fn get_modifier(&self, random: f32) -> f32 {
+        self.weather * self.critical_hit * random * self.stab * self.effectiveness * self.screens
+    }
+}
+
+/// Every possible damage of a move, along with the HP of its target.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DamageRange {
+    /// The equally likely damage values, sorted from lowest to highest. Moves
+    /// using the damage formula have 16 of them, one for each random factor.
+    pub rolls: Vec<usize>,
+    /// The maximum HP of the target.
+    pub target_max_hp: usize,
+    /// The current HP of the target.
+    pub target_current_hp: usize,
+    pub factors: DamageFactors,
+}
+
+impl DamageRange {
+    pub fn get_min(&self) -> usize {
+        self.rolls.first().copied().unwrap_or(0)
+    }
+
+    pub fn get_max(&self) -> usize {
+        self.rolls.last().copied().unwrap_or(0)
+    }
+
+    pub fn get_average(&self) -> f32 {
+        if self.rolls.is_empty() {
+            0.
+        } else {
+            self.rolls.iter().sum::<usize>() as f32 / self.rolls.len() as f32
+        }
+    }
+
+    /// Returns each roll as a percentage of the maximum HP of the target.
+    pub fn get_percentages(&self) -> Vec<f32> {
+        self.rolls
+            .iter()
+            .map(|damage| self.get_percentage(*damage))
+            .collect()
+    }
+
+    pub fn get_min_percentage(&self) -> f32 {
+        self.get_percentage(self.get_min())
+    }
+
+    pub fn get_max_percentage(&self) -> f32 {
+        self.get_percentage(self.get_max())
+    }
+
+    /// Returns the chance that the move knocks out the target from its
+    /// current HP, in the range [0, 1].
+    pub fn get_ko_chance(&self) -> f32 {
+        if self.rolls.is_empty() {
+            return 0.;
+        }
+
+        let ko_rolls = self
+            .rolls
+            .iter()
+            .filter(|damage| **damage >= self.target_current_hp)
+            .count();
+
+        ko_rolls as f32 / self.rolls.len() as f32
+    }
+
+    fn get_percentage(&self, damage: usize) -> f32 {
+        100. * damage as f32 / self.target_max_hp as f32
+    }
+}
+
+impl BattleBackend {
+    /// Calculates every possible damage of a move used by `user` against
+    /// `target`, without changing the battle. Returns `None` for moves that
+    /// don't deal damage.
+    ///
+    /// Like the backend, this doesn't take the secondary effects of other
+    /// status conditions or the hits of multi-hit moves into account.
+    pub fn calculate_damage(
+        &self,
+        user: usize,
+        target: usize,
+        movement: &Move,
+        options: &DamageCalcOptions,
+    ) -> Option<DamageRange> {
+        if movement.category == MoveCategory::Status {
+            return None;
+        }
+
+        let is_critical_hit = uses_damage_formula(movement)
+            && options
+                .is_critical_hit
+                .unwrap_or_else(|| self.is_guaranteed_critical_hit(user, movement));
+        let effectiveness = self.get_type_effectiveness(movement, target);
+        let mut factors =
+            self.get_damage_factors(user, target, movement, is_critical_hit, effectiveness);
+        factors.burn = self.get_burn_factor(user, movement, options);
+
+        if let Some(weather) = options.weather {
+            factors.weather = weather.get_damage_multiplier(movement.move_type);
+        }
+
+        match options.has_screens {
+            Some(true) if !is_critical_hit => factors.screens = 0.5,
+            Some(_) => factors.screens = 1.,
+            None => {},
+        }
+
+        let used_move = UsedMove {
+            user,
+            target,
+            movement,
+        };
+        let target_current_hp = self.get_pokemon(target).current_hp;
+        let user_level = self.get_pokemon(user).level;
+
+        let mut rolls: Vec<usize> = if movement.flags.contains(&MoveFlag::OneHitKO) {
+            vec![target_current_hp]
+        } else if let MovePower::Fixed(fixed_damage) = &movement.base_power {
+            match fixed_damage {
+                FixedDamage::Constant(damage) => vec![*damage],
+                FixedDamage::UserLevel => vec![user_level],
+                FixedDamage::HalfTargetHp => vec![(target_current_hp / 2).max(1)],
+                FixedDamage::RandomUserLevel => (50..=150)
+                    .map(|percentage| (user_level * percentage / 100).max(1))
+                    .collect(),
+            }
+        } else {
+            let power = self.get_move_power(&used_move);
+            let (attack, defense) =
+                self.get_attack_and_defense(user, target, movement.category, is_critical_hit);
+
+            DAMAGE_ROLLS
+                .map(|random| {
+                    let modifier = factors.get_modifier(random as f32 / 100.);
+
+                    get_base_damage(user_level, power, attack, defense, modifier)
+                })
+                .collect()
+        };
+
+        if effectiveness == 0. {
+            rolls = vec![0];
+        } else if factors.burn < 1. {
+            rolls = rolls.into_iter().map(|damage| damage / 2).collect();
+        }
+
+        rolls.sort();
+
+        Some(DamageRange {
+            rolls,
+            target_max_hp: self.get_stat(target, Stat::HP),
+            target_current_hp,
+            factors,
+        })
+    }
+
+    fn get_burn_factor(&self, user: usize, movement: &Move, options: &DamageCalcOptions) -> f32 {
+        let is_burned = options.is_burned.unwrap_or_else(|| {
+            match self.get_pokemon(user).status_condition {
+                Some(StatusCondition::Burn) => true,
+                _ => false,
+            }
+        });
+
+        let is_affected =
+            movement.category == MoveCategory::Physical && uses_damage_formula(movement);
+
+        if is_burned && is_affected {
+            0.5
+        } else {
+            1.
+        }
+    }
+}
+
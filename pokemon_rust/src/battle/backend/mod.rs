@@ -1,83 +1,120 @@
+pub mod damage_calc;
 pub mod rng;
+pub mod snapshot;
 
 use crate::{
+    constants::MOVE_LIMIT,
     overworld::entities::character::CharacterId,
     pokemon::{
         get_all_moves,
         get_all_pokemon_species,
+        get_confusion_self_hit,
+        get_side_condition_effect,
         get_status_condition_effect,
+        get_volatile_status_effect,
         movement::{
+            DamageSideEffect,
+            FixedDamage,
             ModifiedAccuracy,
             ModifiedUsageAttempt,
             Move,
             MoveCategory,
             MoveFlag,
+            MoveId,
             MovePower,
             MultiHit,
+            MultiTurn,
             SimpleEffect,
             SimpleEffectTarget,
+            TargetType,
         },
         Pokemon,
         PokemonSpeciesData,
         PokemonType,
+        SpeciesId,
+        SideCondition,
         SimpleStatusCondition,
+        SimpleVolatileStatus,
         Stat,
         StatusCondition,
         StatusConditionEffect,
+        VolatileStatus,
+        VolatileStatusEffect,
     },
 };
 
-use std::collections::{HashMap, VecDeque};
+use serde::{Deserialize, Serialize};
 
-use self::rng::BattleRng;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 
-use super::types::{Battle, BattleType};
+use self::{damage_calc::DamageFactors, rng::BattleRng};
+
+use super::{
+    rules::{Clause, CriticalHitModel, Ruleset},
+    types::{Battle, BattleType},
+};
+
+/// The denominators of the chance of a critical hit at each critical hit
+/// stage, when critical hits are chance-based.
+const CRITICAL_HIT_CHANCES: [usize; 4] = [24, 8, 2, 1];
 
 /// Represents an event that can be sent from the frontend to the backend.
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct FrontendEvent {
     pub team: Team,
     pub event: FrontendEventKind,
 }
 
 /// The kind of events that the frontend can send to the backend.
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum FrontendEventKind {
     UseMove(usize),
 }
 
 /// The kind of events that the backend can send to the frontend.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum BattleEvent {
     InitialSwitchIn(event::InitialSwitchIn),
     ChangeTurn(event::ChangeTurn),
     UseMove(event::UseMove),
     Damage(event::Damage),
+    Heal(event::Heal),
     Miss(event::Miss),
     StatChange(event::StatChange),
     VolatileStatusCondition(event::VolatileStatusCondition),
     ExpiredVolatileStatusCondition(event::ExpiredVolatileStatusCondition),
     NonVolatileStatusCondition(event::NonVolatileStatusCondition),
     ExpiredNonVolatileStatusCondition(event::ExpiredNonVolatileStatusCondition),
+    SubstituteDamage(event::SubstituteDamage),
+    AddedSideCondition(event::AddedSideCondition),
+    ExpiredSideCondition(event::ExpiredSideCondition),
+    TypeChange(event::TypeChange),
+    Transformed(event::Transformed),
     FailedMove(event::FailedMove),
     Faint(event::Faint),
 }
 
 pub mod event {
+    use serde::{Deserialize, Serialize};
+
     use super::{
         DamageCause,
-        Flag,
+        HealCause,
+        MoveId,
+        PokemonType,
+        SideCondition,
         SimpleStatusCondition,
         Stat,
         StatChangeKind,
         StatusCondition,
         Team,
         TypeEffectiveness,
+        VolatileStatus,
     };
 
     /// Corresponds to the very first switch-in of a battle participant in a
     /// battle.
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct InitialSwitchIn {
         pub team: Team,
         pub pokemon: usize,
@@ -88,18 +125,18 @@ pub mod event {
         pub is_already_sent_out: bool,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct ChangeTurn {
         pub new_turn: usize,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct UseMove {
         pub move_user: usize,
-        pub move_name: String,
+        pub move_id: MoveId,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct Damage {
         pub target: usize,
         pub amount: usize,
@@ -111,64 +148,124 @@ pub mod event {
         pub cause: DamageCause,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Heal {
+        pub target: usize,
+        pub amount: usize,
+        pub cause: HealCause,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct Miss {
         pub target: usize,
         pub move_user: usize,
-        pub caused_by_confusion: bool,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct StatChange {
         pub target: usize,
         pub kind: StatChangeKind,
         pub stat: Stat,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct VolatileStatusCondition {
         pub target: usize,
-        pub added_flag: Flag,
+        pub status: VolatileStatus,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct ExpiredVolatileStatusCondition {
         pub target: usize,
-        pub flag: Flag,
+        pub status: VolatileStatus,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct NonVolatileStatusCondition {
         pub target: usize,
         pub condition: StatusCondition,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct ExpiredNonVolatileStatusCondition {
         pub target: usize,
         pub condition: SimpleStatusCondition,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    /// The substitute of `target` took damage in its place.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct SubstituteDamage {
+        pub target: usize,
+        pub amount: usize,
+    }
+
+    /// A side condition started on the side of `team`, or gained a layer.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct AddedSideCondition {
+        pub team: Team,
+        pub condition: SideCondition,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct ExpiredSideCondition {
+        pub team: Team,
+        pub condition: SideCondition,
+    }
+
+    /// The types of `target` were replaced, e.g by Soak.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct TypeChange {
+        pub target: usize,
+        pub types: Vec<PokemonType>,
+    }
+
+    /// `target` transformed into `into`, copying its species, types, stats
+    /// and moves.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    pub struct Transformed {
+        pub target: usize,
+        pub into: usize,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct FailedMove {
         pub move_user: usize,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     pub struct Faint {
         pub target: usize,
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum DamageCause {
     Move,
     Burn,
     Poison,
     Toxic,
+    Binding { move_id: MoveId },
+    /// The Pokémon was hurt by the recoil of its own move, e.g Double-Edge.
+    Recoil,
+    /// The Pokémon hurt itself in its confusion.
+    Confusion,
+    LeechSeed,
+    Curse,
+    /// The Pokémon paid HP to use a move, e.g Substitute.
+    HpCost,
+    Spikes,
+    StealthRock,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum HealCause {
+    /// The Pokémon drained the HP of `drained`, e.g with Absorb.
+    Drain { drained: usize },
+    /// The Pokémon received the HP that Leech Seed took from `drained`.
+    LeechSeed { drained: usize },
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum TypeEffectiveness {
     Immune,
     BarelyEffective,
@@ -194,7 +291,7 @@ impl TypeEffectiveness {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum StatChangeKind {
     WontGoAnyLower,
     SeverelyFell,
@@ -206,19 +303,34 @@ pub enum StatChangeKind {
     WontGoAnyHigher,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum Team {
     P1,
     P2,
 }
 
+impl Team {
+    pub fn get_opponent(self) -> Team {
+        match self {
+            Team::P1 => Team::P2,
+            Team::P2 => Team::P1,
+        }
+    }
+}
+
+type UsageAttemptHook =
+    fn(backend: &mut BattleBackend, user: usize, mov: &Move) -> ModifiedUsageAttempt;
+
 pub struct UsedMove<'a> {
     user: usize,
     target: usize,
     movement: &'a Move,
 }
 
-#[derive(Debug)]
+/// Cloning a backend is the cheapest way to look ahead, e.g to try a turn
+/// without affecting the real battle. See `BattleSnapshot` for a state that
+/// can be saved.
+#[derive(Clone, Debug)]
 pub struct BattleBackend {
     /// The type of battle that is happening.
     battle_type: BattleType,
@@ -229,32 +341,74 @@ pub struct BattleBackend {
     pub(super) p1: TeamData,
     /// The Pokémon that make up the second team.
     pub(super) p2: TeamData,
-    pokemon_flags: HashMap<usize, FlagContainer>,
-    active_effects: HashMap<usize, Vec<StatusConditionEffect>>,
+    volatile_statuses: HashMap<usize, BTreeMap<SimpleVolatileStatus, ActiveVolatileStatus>>,
+    stat_stages: HashMap<usize, HashMap<Stat, i8>>,
+    /// The id of the last move that each Pokémon used, e.g for Disable.
+    last_used_moves: HashMap<usize, MoveId>,
+    /// The types of the Pokémon whose types changed during the battle, e.g
+    /// by Soak. Pokémon without an entry have the types of their species.
+    type_overrides: HashMap<usize, Vec<PokemonType>>,
+    /// What the Pokémon that used Transform copied from their targets.
+    transformations: HashMap<usize, Transformation>,
+    /// The effects of the status conditions of each Pokémon, along with the
+    /// conditions that caused them.
+    active_effects: HashMap<usize, Vec<(SimpleStatusCondition, StatusConditionEffect)>>,
     input_events: VecDeque<FrontendEvent>,
     event_queue: Vec<BattleEvent>,
     pub(super) pokemon_repository: HashMap<usize, Pokemon>,
+    /// The mechanics and clauses that this battle follows.
+    ruleset: Ruleset,
     /// The RNG that this battle is using.
     pub(super) rng: Box<dyn BattleRng + Sync + Send>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub(super) struct TeamData {
     pub(super) active_pokemon: Option<usize>,
     party: VecDeque<usize>,
     character_id: Option<CharacterId>,
+    side_conditions: BTreeMap<SideCondition, ActiveSideCondition>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct ActiveSideCondition {
+    /// How many times the side condition was stacked, e.g with Spikes.
+    layers: usize,
+    /// How many turns are left until the side condition expires, if it has
+    /// a duration.
+    remaining_turns: Option<usize>,
+}
+
+/// The data that a Pokémon copied from another one with Transform. It only
+/// lasts while the Pokémon is on the field.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Transformation {
+    species_id: SpeciesId,
+    /// The copied stats. HP is the only stat that isn't copied.
+    stats: [usize; 6],
+    moves: [Option<MoveId>; MOVE_LIMIT],
+    pp: [usize; MOVE_LIMIT],
 }
 
-#[derive(Debug, Default)]
-struct FlagContainer {
-    flags: HashMap<&'static str, Flag>,
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct ActiveVolatileStatus {
+    status: VolatileStatus,
+    /// How many turns are left until the status expires, if it has a
+    /// duration. Each status decides what counts as a turn, e.g confusion
+    /// counts move attempts.
+    remaining_turns: Option<usize>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum Flag {
-    Confusion { remaining_move_attempts: usize },
-    Flinch,
-    StatStages(HashMap<Stat, i8>),
+/// How far a move got when it was processed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum MoveOutcome {
+    /// The user couldn't use the move, e.g because it flinched or the move
+    /// failed.
+    Prevented,
+    /// The user started charging the move.
+    Charged,
+    Missed,
+    Executed,
 }
 
 pub struct MultiHitData {
@@ -269,26 +423,28 @@ impl BattleBackend {
             active_pokemon: None,
             party: VecDeque::new(),
             character_id: data.p1.character_id,
+            side_conditions: BTreeMap::new(),
         };
         let mut p2 = TeamData {
             active_pokemon: None,
             party: VecDeque::new(),
             character_id: data.p2.character_id,
+            side_conditions: BTreeMap::new(),
         };
-        let mut pokemon_flags = HashMap::new();
+        let mut volatile_statuses = HashMap::new();
 
         for pokemon in data.p1.party.pokemon {
             let index = pokemon_repository.len();
             pokemon_repository.insert(index, pokemon);
             p1.party.push_back(index);
-            pokemon_flags.insert(index, FlagContainer::default());
+            volatile_statuses.insert(index, BTreeMap::new());
         }
 
         for pokemon in data.p2.party.pokemon {
             let index = pokemon_repository.len();
             pokemon_repository.insert(index, pokemon);
             p2.party.push_back(index);
-            pokemon_flags.insert(index, FlagContainer::default());
+            volatile_statuses.insert(index, BTreeMap::new());
         }
 
         BattleBackend {
@@ -296,15 +452,22 @@ impl BattleBackend {
             turn: 0,
             p1,
             p2,
+            volatile_statuses,
+            stat_stages: HashMap::new(),
+            last_used_moves: HashMap::new(),
+            type_overrides: HashMap::new(),
+            transformations: HashMap::new(),
             active_effects: HashMap::new(),
             input_events: VecDeque::new(),
             event_queue: Vec::new(),
             pokemon_repository,
+            ruleset: data.ruleset,
             rng,
-            pokemon_flags,
         }
     }
 
+    /// Sends the action of a team to the backend. Events sent by teams whose
+    /// active Pokémon is locked into a move (see `is_locked`) are ignored.
     pub fn push_frontend_event(&mut self, event: FrontendEvent) {
         self.input_events.push_back(event);
     }
@@ -341,47 +504,46 @@ impl BattleBackend {
                 is_already_sent_out: false,
             }));
 
-        // TODO: trigger things like Intimidate, entry hazards, Drought, etc.
-        // The order is determined by speed.
+        self.process_switch_in(self.p2.active_pokemon.unwrap());
+        self.process_switch_in(self.p1.active_pokemon.unwrap());
+
+        // TODO: trigger things like Intimidate, Drought, etc. The order is
+        // determined by speed.
     }
 
     fn process_turn(&mut self) {
         let (p1_action, p2_action) = self.decompose_input_events();
+        let p1 = self.p1.active_pokemon.unwrap();
+        let p2 = self.p2.active_pokemon.unwrap();
 
-        match (p1_action, p2_action) {
-            (FrontendEventKind::UseMove(p1_index), FrontendEventKind::UseMove(p2_index)) => {
-                let movedex = get_all_moves();
-                let p1 = self.p1.active_pokemon.unwrap();
-                let p2 = self.p2.active_pokemon.unwrap();
-
-                let p1_move = {
-                    let p1_move_id = self.pokemon_repository[&p1].moves[p1_index]
-                        .as_ref()
-                        .unwrap();
-                    let p1_move = movedex.get_move(&p1_move_id).unwrap();
-
-                    UsedMove {
-                        user: p1,
-                        target: p2,
-                        movement: &p1_move,
-                    }
-                };
-
-                let p2_move = {
-                    let p2_move_id = self.pokemon_repository[&p2].moves[p2_index]
-                        .as_ref()
-                        .unwrap();
-                    let p2_move = movedex.get_move(&p2_move_id).unwrap();
+        let p1_move = self.get_used_move(p1, p2, p1_action);
+        let p2_move = self.get_used_move(p2, p1, p2_action);
 
-                    UsedMove {
-                        user: p2,
-                        target: p1,
-                        movement: &p2_move,
-                    }
-                };
+        self.process_moves(vec![p1_move, p2_move].into_iter());
+    }
 
-                self.process_moves(vec![p1_move, p2_move].into_iter());
+    /// Returns the move that a Pokémon will use in this turn. Pokémon that are
+    /// locked into a move use it regardless of the action of their team.
+    fn get_used_move(
+        &self,
+        user: usize,
+        target: usize,
+        action: Option<FrontendEventKind>,
+    ) -> UsedMove<'static> {
+        let movedex = get_all_moves();
+
+        let move_id = match (self.get_locked_move(user), action) {
+            (Some(move_id), _) => move_id,
+            (None, Some(FrontendEventKind::UseMove(index))) => {
+                self.get_moves(user)[index].unwrap()
             },
+            (None, None) => unreachable!(),
+        };
+
+        UsedMove {
+            user,
+            target,
+            movement: movedex.get_move(move_id),
         }
     }
 
@@ -419,88 +581,135 @@ impl BattleBackend {
             return;
         }
 
-        if self.has_flag(used_move.user, "flinch") {
+        if self.has_volatile_status(used_move.user, SimpleVolatileStatus::Recharging) {
+            self.expire_volatile_status(used_move.user, SimpleVolatileStatus::Recharging);
             return;
         }
 
-        if let Some(flag) = self.get_flag_mut(used_move.user, "confusion") {
-            let remaining_move_attempts = match flag {
-                Flag::Confusion { remaining_move_attempts } => remaining_move_attempts,
-                _ => unreachable!(),
-            };
+        // A charged move is released in this turn even if it doesn't succeed
+        let is_charged = self
+            .remove_volatile_status(used_move.user, SimpleVolatileStatus::Charging)
+            .is_some();
+        let outcome = self.execute_move(&used_move, is_charged);
+
+        match used_move.movement.multi_turn {
+            Some(MultiTurn::Recharge) => {
+                // A move that misses doesn't require a recharge turn
+                if outcome == MoveOutcome::Executed {
+                    self.insert_volatile_status(used_move.user, VolatileStatus::Recharging {
+                        move_id: used_move.movement.id,
+                    });
+                }
+            },
+            Some(MultiTurn::Rampage) => self.update_rampage(&used_move, outcome),
+            _ => {},
+        }
+    }
 
-            if *remaining_move_attempts > 0 {
-                *remaining_move_attempts -= 1;
-            } else {
-                let flag = flag.clone();
+    /// Locks the user of a rampage move into it, or ends the rampage once
+    /// its last turn is over, confusing the user.
+    fn update_rampage(&mut self, used_move: &UsedMove, outcome: MoveOutcome) {
+        let kind = SimpleVolatileStatus::Rampage;
 
-                self.event_queue.push(BattleEvent::ExpiredVolatileStatusCondition(
-                    event::ExpiredVolatileStatusCondition {
-                        target: used_move.user,
-                        flag,
-                    }
-                ));
+        if outcome == MoveOutcome::Executed && !self.has_volatile_status(used_move.user, kind) {
+            self.insert_volatile_status(used_move.user, VolatileStatus::Rampage {
+                move_id: used_move.movement.id,
+            });
+        }
 
-                self.remove_flag(used_move.user, "confusion");
-            }
+        let remaining_turns = match self.get_remaining_turns_mut(used_move.user, kind) {
+            Some(remaining_turns) => {
+                *remaining_turns = remaining_turns.saturating_sub(1);
+                *remaining_turns
+            },
+            None => return,
+        };
+
+        match (remaining_turns, outcome) {
+            (0, MoveOutcome::Executed) => {
+                self.remove_volatile_status(used_move.user, kind);
+                self.add_volatile_status(used_move.user, VolatileStatus::Confusion);
+            },
+            (_, MoveOutcome::Executed) => {},
+            // A disrupted rampage ends without confusing the user
+            (_, _) => {
+                self.remove_volatile_status(used_move.user, kind);
+            },
         }
+    }
 
-        let active_effects = self.active_effects
-            .get(&used_move.user)
-            .unwrap_or(&Vec::new())
-            .clone();
+    fn execute_move(&mut self, used_move: &UsedMove, is_charged: bool) -> MoveOutcome {
+        let user = used_move.user;
+        let active_effects = self.get_active_effects(user);
+        let volatile_status_effects = self.get_volatile_status_effects(user);
 
-        for effect in active_effects.iter().filter_map(|effect| effect.on_before_use_move) {
-            if effect(self, used_move.user, &used_move.movement) == ModifiedUsageAttempt::Fail {
-                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
-                    move_user: used_move.user,
-                }));
-                return;
-            }
+        let before_use_move_hooks = volatile_status_effects
+            .iter()
+            .filter_map(|effect| effect.on_before_use_move)
+            .chain(active_effects.iter().filter_map(|effect| effect.on_before_use_move))
+            .collect();
+
+        if !self.ruleset.allows_move(used_move.movement) {
+            self.process_usage_attempt(user, ModifiedUsageAttempt::Fail);
+            return MoveOutcome::Prevented;
+        }
+
+        if !self.check_usage_attempt(user, used_move.movement, before_use_move_hooks) {
+            return MoveOutcome::Prevented;
         }
 
         self.event_queue.push(BattleEvent::UseMove(event::UseMove {
-            move_user: used_move.user,
-            move_name: used_move.movement.display_name.clone(),
+            move_user: user,
+            move_id: used_move.movement.id,
         }));
+        self.last_used_moves.insert(user, used_move.movement.id);
 
-        for effect in active_effects.iter().filter_map(|effect| effect.on_try_use_move) {
-            if effect(self, used_move.user, &used_move.movement) == ModifiedUsageAttempt::Fail {
-                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
-                    move_user: used_move.user,
-                }));
-                return;
+        // The previous hooks might have ended some conditions, e.g a
+        // confusion that wore off, which mustn't affect the move anymore
+        let active_effects = self.get_active_effects(user);
+        let volatile_status_effects = self.get_volatile_status_effects(user);
+
+        let try_use_move_hooks = active_effects
+            .iter()
+            .filter_map(|effect| effect.on_try_use_move)
+            .chain(volatile_status_effects.iter().filter_map(|effect| effect.on_try_use_move))
+            .collect();
+
+        if !self.check_usage_attempt(user, used_move.movement, try_use_move_hooks) {
+            return MoveOutcome::Prevented;
+        }
+
+        if let Some(MultiTurn::Charge { semi_invulnerable }) = used_move.movement.multi_turn {
+            if !is_charged {
+                self.add_volatile_status(user, VolatileStatus::Charging {
+                    move_id: used_move.movement.id,
+                    semi_invulnerable,
+                });
+
+                return MoveOutcome::Charged;
             }
         }
 
         if let Some(handler) = used_move.movement.on_usage_attempt {
-            let result = handler(self, used_move.user, used_move.target, &used_move.movement);
-            if result == ModifiedUsageAttempt::Fail {
-                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
-                    move_user: used_move.user,
-                }));
-                return;
+            let result = handler(self, user, used_move.target, &used_move.movement);
+            if !self.process_usage_attempt(user, result) {
+                return MoveOutcome::Prevented;
             }
         }
 
-        if self.has_flag(used_move.user, "confusion") {
-            if self.rng.check_confusion_miss() {
-                self.event_queue.push(BattleEvent::Miss(event::Miss {
-                    target: used_move.target,
-                    move_user: used_move.user,
-                    caused_by_confusion: true,
-                }));
-                return;
-            }
+        let is_behind_substitute = self.is_behind_substitute(used_move);
+
+        if used_move.movement.category == MoveCategory::Status && is_behind_substitute {
+            self.process_usage_attempt(user, ModifiedUsageAttempt::Fail);
+            return MoveOutcome::Prevented;
         }
 
-        if self.check_miss(&used_move) {
+        if self.check_miss(used_move) {
             self.event_queue.push(BattleEvent::Miss(event::Miss {
                 target: used_move.target,
-                move_user: used_move.user,
-                caused_by_confusion: false,
+                move_user: user,
             }));
-            return;
+            return MoveOutcome::Missed;
         }
 
         match used_move.movement.category {
@@ -516,13 +725,22 @@ impl BattleBackend {
                     };
 
                     for i in 0..number_of_hits {
-                        self.process_damage_effect(&used_move, Some(MultiHitData {
+                        self.process_damage_effect(used_move, Some(MultiHitData {
                             multi_hit_index: i,
                             maximum_number_of_hits: number_of_hits,
                         }));
                     }
                 } else {
-                    self.process_damage_effect(&used_move, None);
+                    self.process_damage_effect(used_move, None);
+                }
+
+                if let Some(MultiTurn::Binding) = used_move.movement.multi_turn {
+                    if !is_behind_substitute && !self.is_fainted(used_move.target) {
+                        self.add_volatile_status(used_move.target, VolatileStatus::Binding {
+                            user,
+                            move_id: used_move.movement.id,
+                        });
+                    }
                 }
             },
             MoveCategory::Status => {
@@ -530,7 +748,83 @@ impl BattleBackend {
             },
         }
 
-        self.process_secondary_effect(&used_move);
+        self.process_secondary_effect(used_move, is_behind_substitute);
+
+        MoveOutcome::Executed
+    }
+
+    /// Calls the usage attempt hooks of the effects of a Pokémon in order,
+    /// until one of them stops the move. Returns whether the move goes on.
+    fn check_usage_attempt(
+        &mut self,
+        user: usize,
+        mov: &Move,
+        hooks: Vec<UsageAttemptHook>,
+    ) -> bool {
+        for hook in hooks {
+            let result = hook(self, user, mov);
+
+            if !self.process_usage_attempt(user, result) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Reports a move that failed. Returns whether the move goes on.
+    fn process_usage_attempt(&mut self, user: usize, result: ModifiedUsageAttempt) -> bool {
+        match result {
+            ModifiedUsageAttempt::Continue => true,
+            ModifiedUsageAttempt::Fail => {
+                self.event_queue.push(BattleEvent::FailedMove(event::FailedMove {
+                    move_user: user,
+                }));
+                false
+            },
+            ModifiedUsageAttempt::Interrupt => false,
+        }
+    }
+
+    /// Checks if the target of a move is protected from it by a substitute.
+    fn is_behind_substitute(&self, used_move: &UsedMove) -> bool {
+        used_move.user != used_move.target
+            && affects_opponents(used_move.movement)
+            && !used_move.movement.flags.contains(&MoveFlag::BypassesSubstitute)
+            && self.has_volatile_status(used_move.target, SimpleVolatileStatus::Substitute)
+    }
+
+    /// Makes a confused Pokémon hit itself with a typeless 40-power physical
+    /// attack, using its own Attack and Defense.
+    pub fn inflict_confusion_damage(&mut self, pokemon: usize) {
+        let movement = get_confusion_self_hit();
+        let level = self.get_pokemon(pokemon).level;
+        let attack = self.get_stat(pokemon, Stat::Attack);
+        let defense = self.get_stat(pokemon, Stat::Defense);
+        let power = match movement.base_power {
+            MovePower::Constant(power) => power,
+            _ => unreachable!(),
+        };
+        let random = self.rng.get_damage_modifier();
+
+        let mut damage = get_base_damage(level, power, attack, defense, random);
+
+        self.get_active_effects(pokemon)
+            .iter()
+            .filter_map(|effect| effect.on_try_deal_damage)
+            .for_each(|effect| {
+                damage = effect(&self, pokemon, pokemon, movement, damage);
+            });
+
+        self.inflict_calculated_damage(
+            pokemon,
+            damage,
+            TypeEffectiveness::Normal,
+            false,
+            None,
+            false,
+            DamageCause::Confusion,
+        );
     }
 
     fn process_damage_effect(
@@ -538,47 +832,43 @@ impl BattleBackend {
         used_move: &UsedMove,
         multi_hit_data: Option<MultiHitData>,
     ) {
-        let is_critical_hit = used_move.movement.critical_hit;
-
-        let (attack, defense) = match (is_critical_hit, used_move.movement.category) {
-            (false, MoveCategory::Physical) => (
-                self.get_stat(used_move.user, Stat::Attack),
-                self.get_stat(used_move.target, Stat::Defense),
-            ),
-            (true, MoveCategory::Physical) => (
-                self.get_attack_critical_hit(used_move.user),
-                self.get_defense_critical_hit(used_move.target),
-            ),
-            (false, MoveCategory::Special) => (
-                self.get_stat(used_move.user, Stat::SpecialAttack),
-                self.get_stat(used_move.target, Stat::SpecialDefense),
-            ),
-            (true, MoveCategory::Special) => (
-                self.get_special_attack_critical_hit(used_move.user),
-                self.get_special_defense_critical_hit(used_move.target),
-            ),
-            _ => unreachable!(),
-        };
+        // Damage that doesn't come from the damage formula can't be critical
+        let is_critical_hit =
+            uses_damage_formula(used_move.movement) && self.check_critical_hit(used_move);
+        let (attack, defense) = self.get_attack_and_defense(
+            used_move.user,
+            used_move.target,
+            used_move.movement.category,
+            is_critical_hit,
+        );
 
         self.inflict_damage(&used_move, attack, defense, is_critical_hit, multi_hit_data);
     }
 
-    fn process_secondary_effect(&mut self, used_move: &UsedMove) {
+    fn process_secondary_effect(&mut self, used_move: &UsedMove, is_behind_substitute: bool) {
         if let Some(effect) = used_move.movement.secondary_effect.as_ref() {
+            // Substitutes block every effect on the target of the move, but
+            // not the ones on its user
+            let affects_target = match &effect.effect {
+                SimpleEffect::StatChange { target: SimpleEffectTarget::MoveUser, .. }
+                | SimpleEffect::OnHit(_) => false,
+                _ => true,
+            };
+
+            if is_behind_substitute && affects_target {
+                return;
+            }
+
             if !self.rng.check_secondary_effect(effect.chance) {
                 return;
             }
 
             match &effect.effect {
                 SimpleEffect::Confusion => {
-                    let duration = self.rng.get_confusion_duration();
-
-                    self.add_volatile_status_condition(used_move.target, Flag::Confusion {
-                        remaining_move_attempts: duration,
-                    });
+                    self.add_volatile_status(used_move.target, VolatileStatus::Confusion);
                 },
                 SimpleEffect::Flinch => {
-                    self.add_volatile_status_condition(used_move.target, Flag::Flinch);
+                    self.add_volatile_status(used_move.target, VolatileStatus::Flinch);
                 },
                 SimpleEffect::StatChange { changes, target } => {
                     let target = match target {
@@ -586,6 +876,19 @@ impl BattleBackend {
                         SimpleEffectTarget::MoveUser => used_move.user,
                     };
 
+                    let target_team = self.get_pokemon_team(target);
+                    let is_protected_by_mist = target != used_move.user
+                        && self.has_side_condition(target_team, SideCondition::Mist);
+
+                    let changes: Vec<_> = changes
+                        .iter()
+                        .filter(|(_, delta)| !is_protected_by_mist || *delta > 0)
+                        .collect();
+
+                    if changes.is_empty() && used_move.movement.category == MoveCategory::Status {
+                        self.process_usage_attempt(used_move.user, ModifiedUsageAttempt::Fail);
+                    }
+
                     for (stat, delta) in changes {
                         self.change_stat_stage(target, *stat, *delta);
                     }
@@ -593,7 +896,9 @@ impl BattleBackend {
                 SimpleEffect::StatusCondition(status_condition) => {
                     self.add_non_volatile_status_condition(used_move.target, *status_condition);
                 },
-                _ => todo!(),
+                SimpleEffect::OnHit(callback) => {
+                    callback(self, used_move.user, used_move.target, used_move.movement);
+                },
             }
         }
     }
@@ -609,52 +914,73 @@ impl BattleBackend {
     }
 
     fn process_turn_end_events(&mut self) {
-        if let Some(index) = self.p1.active_pokemon {
-            self.remove_flag(index, "flinch");
+        for team in [Team::P1, Team::P2].iter() {
+            if let Some(index) = self.get_active_pokemon_index(*team) {
+                for kind in self.get_volatile_status_kinds(index) {
+                    let on_turn_end = get_volatile_status_effect(kind).on_turn_end;
+
+                    if let Some(effect) = on_turn_end {
+                        if !self.is_fainted(index) && self.has_volatile_status(index, kind) {
+                            effect(self, index);
+                        }
+                    }
+                }
 
-            self.active_effects
-                .get(&index)
-                .unwrap_or(&Vec::new())
-                .clone()
-                .iter()
-                .filter_map(|effect| effect.on_turn_end)
-                .for_each(|effect| {
-                    effect(self, index);
-                });
-        }
+                if self.is_fainted(index) {
+                    continue;
+                }
 
-        if let Some(index) = self.p2.active_pokemon {
-            self.remove_flag(index, "flinch");
+                self.get_active_effects(index)
+                    .iter()
+                    .filter_map(|effect| effect.on_turn_end)
+                    .for_each(|effect| {
+                        effect(self, index);
+                    });
+            }
+        }
 
-            self.active_effects
-                .get(&index)
-                .unwrap_or(&Vec::new())
-                .clone()
-                .iter()
-                .filter_map(|effect| effect.on_turn_end)
-                .for_each(|effect| {
-                    effect(self, index);
-                });
+        for team in [Team::P1, Team::P2].iter() {
+            for condition in self.get_side_conditions(*team) {
+                self.count_down_side_condition(*team, condition);
+            }
         }
     }
 
-    fn decompose_input_events(&mut self) -> (FrontendEventKind, FrontendEventKind) {
-        if self.input_events.len() != 2 {
-            panic!(
-                "Invalid number of input events: {}",
-                self.input_events.len()
-            );
+    /// Splits the input events by team. Teams that are locked into a move
+    /// don't need to send an event, and whatever they send is discarded.
+    fn decompose_input_events(
+        &mut self,
+    ) -> (Option<FrontendEventKind>, Option<FrontendEventKind>) {
+        let mut p1_action = None;
+        let mut p2_action = None;
+
+        for input_event in self.input_events.drain(..) {
+            let action = match input_event.team {
+                Team::P1 => &mut p1_action,
+                Team::P2 => &mut p2_action,
+            };
+
+            if action.is_some() {
+                panic!("Invalid input events: each event must be emitted by a different team.");
+            }
+
+            *action = Some(input_event.event);
         }
 
-        let mut input_events = self.input_events.drain(..);
-        let first = input_events.next().unwrap();
-        let second = input_events.next().unwrap();
+        for team in [Team::P1, Team::P2].iter() {
+            let action = match team {
+                Team::P1 => &mut p1_action,
+                Team::P2 => &mut p2_action,
+            };
 
-        match (first.team, second.team) {
-            (Team::P1, Team::P2) => (first.event, second.event),
-            (Team::P2, Team::P1) => (second.event, first.event),
-            _ => panic!("Invalid input events: each event must be emitted by a different team."),
+            if self.is_locked(*team) {
+                *action = None;
+            } else if action.is_none() {
+                panic!("Missing input event for team {:?}", team);
+            }
         }
+
+        (p1_action, p2_action)
     }
 }
 
@@ -671,6 +997,14 @@ impl BattleBackend {
 
         let (damage, is_ohko) = if used_move.movement.flags.contains(&MoveFlag::OneHitKO) {
             (None, true)
+        } else if let MovePower::Fixed(fixed_damage) = &used_move.movement.base_power {
+            let damage = if effectiveness == 0. {
+                0
+            } else {
+                self.get_fixed_damage(used_move, fixed_damage)
+            };
+
+            (Some(damage), false)
         } else {
             let damage =
                 self.get_move_damage(&used_move, attack, defense, effectiveness, is_critical_hit);
@@ -678,33 +1012,112 @@ impl BattleBackend {
             (Some(damage), false)
         };
 
+        // Fixed damage moves ignore type effectiveness, except for immunities
+        let effectiveness = match used_move.movement.base_power {
+            MovePower::Fixed(_) if effectiveness > 0. => TypeEffectiveness::Normal,
+            _ => TypeEffectiveness::from(effectiveness),
+        };
+
         let target = self.pokemon_repository.get_mut(&used_move.target).unwrap();
-        let mut damage = damage.unwrap_or(target.current_hp);
+        let target_hp = target.current_hp;
+        let mut damage = damage.unwrap_or(target_hp);
 
-        self.active_effects
-            .get(&used_move.user)
-            .unwrap_or(&Vec::new())
-            .iter()
-            .filter_map(|effect| effect.on_try_deal_damage)
-            .for_each(|effect| {
-                damage = effect(
-                    &self,
-                    used_move.user,
-                    used_move.target,
-                    &used_move.movement,
-                    damage
-                );
-            });
+        // Fixed damage isn't modified by the user's conditions, e.g burns
+        if uses_damage_formula(used_move.movement) {
+            self.get_active_effects(used_move.user)
+                .iter()
+                .filter_map(|effect| effect.on_try_deal_damage)
+                .for_each(|effect| {
+                    damage = effect(
+                        &self,
+                        used_move.user,
+                        used_move.target,
+                        &used_move.movement,
+                        damage
+                    );
+                });
+        }
+
+        if !used_move.movement.flags.contains(&MoveFlag::BypassesSubstitute) {
+            let on_damage_hooks = self
+                .get_volatile_status_effects(used_move.target)
+                .into_iter()
+                .filter_map(|effect| effect.on_damage);
+
+            for hook in on_damage_hooks {
+                damage = match hook(self, used_move.target, damage) {
+                    Some(damage) => damage,
+                    None => return,
+                };
+            }
+        }
 
         self.inflict_calculated_damage(
             used_move.target,
             damage,
-            TypeEffectiveness::from(effectiveness),
+            effectiveness,
             is_critical_hit,
             multi_hit_data,
             is_ohko,
             DamageCause::Move,
-        )
+        );
+
+        let dealt_damage = damage.min(target_hp);
+
+        if dealt_damage > 0 {
+            self.process_damage_side_effect(used_move, dealt_damage);
+        }
+    }
+
+    /// Applies the recoil or drain of a move after it dealt damage.
+    fn process_damage_side_effect(&mut self, used_move: &UsedMove, dealt_damage: usize) {
+        let (fraction, is_recoil) = match used_move.movement.damage_side_effect {
+            Some(DamageSideEffect::Recoil(fraction)) => (fraction, true),
+            Some(DamageSideEffect::Drain(fraction)) => (fraction, false),
+            None => return,
+        };
+
+        if self.is_fainted(used_move.user) {
+            return;
+        }
+
+        let amount = ((dealt_damage as f32) * fraction).round().max(1.) as usize;
+
+        if is_recoil {
+            self.inflict_calculated_damage(
+                used_move.user,
+                amount,
+                TypeEffectiveness::Normal,
+                false,
+                None,
+                false,
+                DamageCause::Recoil,
+            );
+        } else {
+            self.heal(used_move.user, amount, HealCause::Drain {
+                drained: used_move.target,
+            });
+        }
+    }
+
+    /// Restores the HP of a Pokémon, up to its maximum HP. Nothing happens if
+    /// the Pokémon already has full HP.
+    pub fn heal(&mut self, target: usize, amount: usize, cause: HealCause) {
+        let max_hp = self.get_stat(target, Stat::HP);
+        let target_pokemon = self.get_pokemon_mut(target);
+        let amount = amount.min(max_hp.saturating_sub(target_pokemon.current_hp));
+
+        if amount == 0 {
+            return;
+        }
+
+        target_pokemon.current_hp += amount;
+
+        self.event_queue.push(BattleEvent::Heal(event::Heal {
+            target,
+            amount,
+            cause,
+        }));
     }
 
     pub fn inflict_calculated_damage(
@@ -759,20 +1172,218 @@ impl BattleBackend {
             self.event_queue.push(BattleEvent::Faint(event::Faint {
                 target,
             }));
+
+            self.process_switch_out(target);
         }
     }
 
-    fn add_volatile_status_condition(&mut self, target: usize, flag: Flag) {
-        self.add_flag(target, flag.clone());
+    /// Clears the volatile statuses of a Pokémon that left the field, and
+    /// ends the ones that depend on it, e.g the binding of its Wrap.
+    fn process_switch_out(&mut self, pokemon: usize) {
+        self.volatile_statuses.get_mut(&pokemon).unwrap().clear();
+        self.last_used_moves.remove(&pokemon);
+        self.type_overrides.remove(&pokemon);
+        self.transformations.remove(&pokemon);
 
-        self.event_queue
-            .push(BattleEvent::VolatileStatusCondition(event::VolatileStatusCondition {
-                target,
-                added_flag: flag,
+        for team in [Team::P1, Team::P2].iter() {
+            if let Some(index) = self.get_active_pokemon_index(*team) {
+                for kind in self.get_volatile_status_kinds(index) {
+                    let on_switch_out = get_volatile_status_effect(kind).on_switch_out;
+
+                    if let Some(effect) = on_switch_out {
+                        if self.has_volatile_status(index, kind) {
+                            effect(self, index, pokemon);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Triggers the effects of the side conditions of the team of a Pokémon
+    /// that entered the field, e.g entry hazards. Since Pokémon can't switch
+    /// in mid-battle yet, the backend only calls it for the initial
+    /// switch-ins.
+    pub fn process_switch_in(&mut self, pokemon: usize) {
+        let team = self.get_pokemon_team(pokemon);
+
+        for condition in self.get_side_conditions(team) {
+            let on_switch_in = get_side_condition_effect(condition).on_switch_in;
+
+            if let Some(effect) = on_switch_in {
+                let layers = self.get_side_condition_layers(team, condition);
+
+                if !self.is_fainted(pokemon) && layers > 0 {
+                    effect(self, pokemon, layers);
+                }
+            }
+        }
+    }
+
+    /// Makes the substitute of a Pokémon take damage in its place. The
+    /// substitute breaks once its HP run out.
+    pub fn damage_substitute(&mut self, target: usize, damage: usize) {
+        let kind = SimpleVolatileStatus::Substitute;
+
+        let (amount, is_broken) = match self.get_volatile_status_mut(target, kind) {
+            Some(VolatileStatus::Substitute { hp }) => {
+                let amount = damage.min(*hp);
+                *hp -= amount;
+
+                (amount, *hp == 0)
+            },
+            _ => return,
+        };
+
+        self.event_queue.push(BattleEvent::SubstituteDamage(event::SubstituteDamage {
+            target,
+            amount,
+        }));
+
+        if is_broken {
+            self.expire_volatile_status(target, kind);
+        }
+    }
+
+    /// Adds a volatile status to a Pokémon and reports it. Nothing happens
+    /// if the Pokémon can't receive it (see `can_add_volatile_status`).
+    pub fn add_volatile_status(&mut self, target: usize, status: VolatileStatus) {
+        if self.insert_volatile_status(target, status.clone()) {
+            self.event_queue
+                .push(BattleEvent::VolatileStatusCondition(event::VolatileStatusCondition {
+                    target,
+                    status,
+                }));
+        }
+    }
+
+    /// Adds a volatile status to a Pokémon without reporting it. Returns
+    /// whether the status was added.
+    fn insert_volatile_status(&mut self, target: usize, status: VolatileStatus) -> bool {
+        if !self.can_add_volatile_status(target, &status) {
+            return false;
+        }
+
+        let kind = SimpleVolatileStatus::from(&status);
+        let remaining_turns = get_volatile_status_effect(kind)
+            .duration
+            .map(|duration| duration(&mut *self.rng));
+
+        self.volatile_statuses
+            .get_mut(&target)
+            .unwrap()
+            .insert(kind, ActiveVolatileStatus {
+                status,
+                remaining_turns,
+            });
+
+        true
+    }
+
+    /// Removes a volatile status from a Pokémon without reporting it.
+    pub fn remove_volatile_status(
+        &mut self,
+        target: usize,
+        kind: SimpleVolatileStatus,
+    ) -> Option<VolatileStatus> {
+        self.volatile_statuses
+            .get_mut(&target)
+            .unwrap()
+            .remove(&kind)
+            .map(|active_status| active_status.status)
+    }
+
+    /// Removes a volatile status from a Pokémon and reports that it ended.
+    pub fn expire_volatile_status(&mut self, target: usize, kind: SimpleVolatileStatus) {
+        if let Some(status) = self.remove_volatile_status(target, kind) {
+            self.event_queue.push(BattleEvent::ExpiredVolatileStatusCondition(
+                event::ExpiredVolatileStatusCondition {
+                    target,
+                    status,
+                }
+            ));
+        }
+    }
+
+    /// Decrements the remaining turns of a volatile status, making it expire
+    /// once they're over. Returns whether the status expired.
+    pub fn count_down_volatile_status(
+        &mut self,
+        target: usize,
+        kind: SimpleVolatileStatus,
+    ) -> bool {
+        let remaining_turns = match self.get_remaining_turns_mut(target, kind) {
+            Some(remaining_turns) => {
+                *remaining_turns = remaining_turns.saturating_sub(1);
+                *remaining_turns
+            },
+            None => return false,
+        };
+
+        if remaining_turns == 0 {
+            self.expire_volatile_status(target, kind);
+        }
+
+        remaining_turns == 0
+    }
+
+    /// Adds a side condition to a team and reports it. Adding a side
+    /// condition that the team already has stacks another layer of it, if
+    /// possible (see `can_add_side_condition`).
+    pub fn add_side_condition(&mut self, team: Team, condition: SideCondition) {
+        if !self.can_add_side_condition(team, condition) {
+            return;
+        }
+
+        let duration = get_side_condition_effect(condition).duration;
+
+        self.get_team_data_mut(team)
+            .side_conditions
+            .entry(condition)
+            .or_insert(ActiveSideCondition {
+                layers: 0,
+                remaining_turns: duration,
+            })
+            .layers += 1;
+
+        self.event_queue.push(BattleEvent::AddedSideCondition(event::AddedSideCondition {
+            team,
+            condition,
+        }));
+    }
+
+    /// Removes a side condition from a team and reports that it ended.
+    pub fn expire_side_condition(&mut self, team: Team, condition: SideCondition) {
+        if self.get_team_data_mut(team).side_conditions.remove(&condition).is_some() {
+            self.event_queue.push(BattleEvent::ExpiredSideCondition(event::ExpiredSideCondition {
+                team,
+                condition,
             }));
+        }
+    }
+
+    /// Decrements the remaining turns of a side condition, making it expire
+    /// once they're over. Side conditions without a duration are unaffected.
+    fn count_down_side_condition(&mut self, team: Team, condition: SideCondition) {
+        let remaining_turns = match self
+            .get_team_data_mut(team)
+            .side_conditions
+            .get_mut(&condition)
+            .and_then(|active_condition| active_condition.remaining_turns.as_mut())
+        {
+            Some(remaining_turns) => {
+                *remaining_turns = remaining_turns.saturating_sub(1);
+                *remaining_turns
+            },
+            None => return,
+        };
+
+        if remaining_turns == 0 {
+            self.expire_side_condition(team, condition);
+        }
     }
 
-    fn add_non_volatile_status_condition(&mut self, target: usize, condition: StatusCondition) {
+    pub fn add_non_volatile_status_condition(&mut self, target: usize, condition: StatusCondition) {
         if self.can_inflict_non_volatile_status_condition_to(target, condition.into()) {
             let target_pokemon = self.get_pokemon_mut(target);
 
@@ -785,7 +1396,7 @@ impl BattleBackend {
             self.active_effects
                 .get_mut(&target)
                 .unwrap()
-                .push(get_status_condition_effect(condition.into()));
+                .push((condition.into(), get_status_condition_effect(condition.into())));
 
             self.event_queue
                 .push(BattleEvent::NonVolatileStatusCondition(
@@ -800,45 +1411,58 @@ impl BattleBackend {
     pub fn remove_non_volatile_status_condition(&mut self, target: usize) {
         let target_pokemon = self.get_pokemon_mut(target);
         let condition = target_pokemon.status_condition.take().unwrap();
+        let simple_condition: SimpleStatusCondition = condition.into();
+
+        if let Some(effects) = self.active_effects.get_mut(&target) {
+            effects.retain(|(source, _)| *source != simple_condition);
+        }
 
         self.event_queue.push(BattleEvent::ExpiredNonVolatileStatusCondition(
             event::ExpiredNonVolatileStatusCondition {
                 target,
-                condition: condition.into(),
+                condition: simple_condition,
             },
         ));
     }
 
-    fn add_flag(&mut self, target: usize, flag: Flag) {
-        let key = match flag {
-            Flag::Confusion { .. } => "confusion",
-            Flag::Flinch => "flinch",
-            Flag::StatStages(_) => unreachable!(),
-        };
+    /// Replaces the types of a Pokémon until it leaves the field.
+    pub fn change_types(&mut self, target: usize, types: Vec<PokemonType>) {
+        self.type_overrides.insert(target, types.clone());
 
-        self.pokemon_flags
-            .get_mut(&target)
-            .unwrap()
-            .flags
-            .insert(key, flag);
+        self.event_queue
+            .push(BattleEvent::TypeChange(event::TypeChange { target, types }));
     }
 
-    fn remove_flag(&mut self, target: usize, flag_id: &str) {
-        self.pokemon_flags
-            .get_mut(&target)
-            .unwrap()
-            .flags
-            .remove(flag_id);
+    /// Makes a Pokémon copy the species, types, stats (except HP), stat
+    /// stages and moves of another one. Each copied move has 5 PP.
+    pub fn transform(&mut self, target: usize, into: usize) {
+        let mut stats = self.get_pokemon(into).stats;
+        stats[Stat::HP as usize] = self.get_pokemon(target).stats[Stat::HP as usize];
+
+        let transformation = Transformation {
+            species_id: self.get_species(into).id,
+            stats,
+            moves: *self.get_moves(into),
+            pp: [5; MOVE_LIMIT],
+        };
+        let types = self.get_current_types(into).to_vec();
+        let stat_stages = self.stat_stages.get(&into).cloned().unwrap_or_default();
+
+        self.transformations.insert(target, transformation);
+        self.type_overrides.insert(target, types);
+        self.stat_stages.insert(target, stat_stages);
+
+        self.event_queue
+            .push(BattleEvent::Transformed(event::Transformed { target, into }));
     }
 
-    fn change_stat_stage(&mut self, target: usize, stat: Stat, delta: i8) {
-        let stat_stages = self
-            .pokemon_flags
-            .get_mut(&target)
-            .unwrap()
-            .flags
-            .entry("stat_stages")
-            .or_insert(Flag::StatStages(HashMap::default()));
+    pub fn change_stat_stage(&mut self, target: usize, stat: Stat, delta: i8) {
+        let value = self
+            .stat_stages
+            .entry(target)
+            .or_default()
+            .entry(stat)
+            .or_insert(0);
 
         let mut stat_change_kind = match delta {
             -3 => StatChangeKind::SeverelyFell,
@@ -850,19 +1474,12 @@ impl BattleBackend {
             _ => unreachable!(),
         };
 
-        match stat_stages {
-            Flag::StatStages(stages) => {
-                let value = stages.entry(stat).or_insert(0);
-
-                if delta < 0 && *value == -6 {
-                    stat_change_kind = StatChangeKind::WontGoAnyLower;
-                } else if delta > 0 && *value == 6 {
-                    stat_change_kind = StatChangeKind::WontGoAnyHigher;
-                } else {
-                    *value = (*value + delta).max(-6).min(6);
-                }
-            },
-            _ => unreachable!(),
+        if delta < 0 && *value == -6 {
+            stat_change_kind = StatChangeKind::WontGoAnyLower;
+        } else if delta > 0 && *value == 6 {
+            stat_change_kind = StatChangeKind::WontGoAnyHigher;
+        } else {
+            *value = (*value + delta).max(-6).min(6);
         }
 
         self.event_queue
@@ -872,14 +1489,65 @@ impl BattleBackend {
                 stat,
             }));
     }
+
+    /// Updates the battle with an event that happened in another backend,
+    /// e.g the one of the host of a link battle, without processing it or
+    /// reporting it again. Only the state that the messages of the battle
+    /// rely on is kept: the active Pokémon, their HP and their status
+    /// conditions.
+    pub fn mirror_event(&mut self, event: &BattleEvent) {
+        match event {
+            BattleEvent::InitialSwitchIn(event_data) => match event_data.team {
+                Team::P1 => self.p1.active_pokemon = Some(event_data.pokemon),
+                Team::P2 => self.p2.active_pokemon = Some(event_data.pokemon),
+            },
+            BattleEvent::Damage(event_data) => {
+                let target = self.get_pokemon_mut(event_data.target);
+                target.current_hp = target.current_hp.saturating_sub(event_data.amount);
+            },
+            BattleEvent::Heal(event_data) => {
+                let max_hp = self.get_stat(event_data.target, Stat::HP);
+                let target = self.get_pokemon_mut(event_data.target);
+                target.current_hp = (target.current_hp + event_data.amount).min(max_hp);
+            },
+            BattleEvent::VolatileStatusCondition(event_data) => {
+                self.volatile_statuses
+                    .get_mut(&event_data.target)
+                    .unwrap()
+                    .insert(SimpleVolatileStatus::from(&event_data.status), ActiveVolatileStatus {
+                        status: event_data.status.clone(),
+                        remaining_turns: None,
+                    });
+            },
+            BattleEvent::ExpiredVolatileStatusCondition(event_data) => {
+                self.remove_volatile_status(
+                    event_data.target,
+                    SimpleVolatileStatus::from(&event_data.status),
+                );
+            },
+            BattleEvent::NonVolatileStatusCondition(event_data) => {
+                self.get_pokemon_mut(event_data.target).status_condition =
+                    Some(event_data.condition);
+            },
+            BattleEvent::ExpiredNonVolatileStatusCondition(event_data) => {
+                self.get_pokemon_mut(event_data.target).status_condition = None;
+            },
+            _ => {},
+        }
+    }
 }
 
 impl BattleBackend {
+    /// Returns the current species of a Pokémon, which is the one it copied
+    /// if it used Transform.
     pub fn get_species(&self, pokemon: usize) -> &PokemonSpeciesData {
         let pokedex = get_all_pokemon_species();
-        let species_id = &self.get_pokemon(pokemon).species_id;
+        let species_id = match self.transformations.get(&pokemon) {
+            Some(transformation) => transformation.species_id,
+            None => self.get_pokemon(pokemon).species_id,
+        };
 
-        pokedex.get_species(species_id).unwrap()
+        pokedex.get_species(species_id)
     }
 
     pub fn get_pokemon(&self, pokemon: usize) -> &Pokemon {
@@ -902,6 +1570,28 @@ impl BattleBackend {
             .map(move |pokemon| self.get_pokemon(*pokemon))
     }
 
+    /// Returns the index of the active Pokémon of a team, if it has one.
+    pub fn get_active_pokemon_index(&self, team: Team) -> Option<usize> {
+        match team {
+            Team::P1 => self.p1.active_pokemon,
+            Team::P2 => self.p2.active_pokemon,
+        }
+    }
+
+    fn get_team_data(&self, team: Team) -> &TeamData {
+        match team {
+            Team::P1 => &self.p1,
+            Team::P2 => &self.p2,
+        }
+    }
+
+    fn get_team_data_mut(&mut self, team: Team) -> &mut TeamData {
+        match team {
+            Team::P1 => &mut self.p1,
+            Team::P2 => &mut self.p2,
+        }
+    }
+
     pub fn get_pokemon_team(&self, pokemon: usize) -> Team {
         if let Some(index) = self.p1.active_pokemon {
             if index == pokemon {
@@ -926,20 +1616,176 @@ impl BattleBackend {
         unreachable!();
     }
 
-    pub fn get_flag_mut(&mut self, pokemon: usize, flag_id: &str) -> Option<&mut Flag> {
-        self.pokemon_flags
+    /// Returns the id of the move that a Pokémon is locked into, if any. The
+    /// backend picks the action of locked Pokémon by itself.
+    pub fn get_locked_move(&self, pokemon: usize) -> Option<MoveId> {
+        [
+            SimpleVolatileStatus::Charging,
+            SimpleVolatileStatus::Recharging,
+            SimpleVolatileStatus::Rampage,
+            SimpleVolatileStatus::Encore,
+        ]
+        .iter()
+        .filter_map(|kind| self.get_volatile_status(pokemon, *kind))
+        .map(|status| match status {
+            VolatileStatus::Charging { move_id, .. }
+            | VolatileStatus::Recharging { move_id }
+            | VolatileStatus::Rampage { move_id }
+            | VolatileStatus::Encore { move_id } => *move_id,
+            _ => unreachable!(),
+        })
+        .next()
+    }
+
+    /// Checks if the active Pokémon of a team is locked into a move, in which
+    /// case the team doesn't choose an action in the next turn.
+    pub fn is_locked(&self, team: Team) -> bool {
+        self.get_active_pokemon_index(team)
+            .map_or(false, |pokemon| self.get_locked_move(pokemon).is_some())
+    }
+
+    /// Checks if a Pokémon is allowed to use a move, i.e the move isn't
+    /// disabled, no taunt prevents it and the ruleset doesn't ban it.
+    pub fn can_use_move(&self, pokemon: usize, movement: &Move) -> bool {
+        let is_disabled = match self.get_volatile_status(pokemon, SimpleVolatileStatus::Disable) {
+            Some(VolatileStatus::Disable { move_id }) => *move_id == movement.id,
+            _ => false,
+        };
+        let is_taunted = movement.category == MoveCategory::Status
+            && self.has_volatile_status(pokemon, SimpleVolatileStatus::Taunt);
+
+        !is_disabled && !is_taunted && self.ruleset.allows_move(movement)
+    }
+
+    /// Checks if a Pokémon is free to leave the field, i.e nothing traps it.
+    pub fn can_switch_out(&self, pokemon: usize) -> bool {
+        !self.has_volatile_status(pokemon, SimpleVolatileStatus::Trapped)
+            && !self.has_volatile_status(pokemon, SimpleVolatileStatus::Binding)
+    }
+
+    pub fn get_ruleset(&self) -> &Ruleset {
+        &self.ruleset
+    }
+
+    /// Returns the moves that a Pokémon can currently use, which are the ones
+    /// it copied if it used Transform.
+    pub fn get_moves(&self, pokemon: usize) -> &[Option<MoveId>; MOVE_LIMIT] {
+        match self.transformations.get(&pokemon) {
+            Some(transformation) => &transformation.moves,
+            None => &self.get_pokemon(pokemon).moves,
+        }
+    }
+
+    /// Returns the current PP of the moves of a Pokémon (see `get_moves`).
+    pub fn get_pp(&self, pokemon: usize) -> &[usize; MOVE_LIMIT] {
+        match self.transformations.get(&pokemon) {
+            Some(transformation) => &transformation.pp,
+            None => &self.get_pokemon(pokemon).pp,
+        }
+    }
+
+    pub fn is_transformed(&self, pokemon: usize) -> bool {
+        self.transformations.contains_key(&pokemon)
+    }
+
+    /// Returns the id of the last move that a Pokémon used while on the
+    /// field, if any.
+    pub fn get_last_used_move(&self, pokemon: usize) -> Option<MoveId> {
+        self.last_used_moves.get(&pokemon).copied()
+    }
+
+    pub fn has_volatile_status(&self, pokemon: usize, kind: SimpleVolatileStatus) -> bool {
+        self.volatile_statuses[&pokemon].contains_key(&kind)
+    }
+
+    pub fn get_volatile_status(
+        &self,
+        pokemon: usize,
+        kind: SimpleVolatileStatus,
+    ) -> Option<&VolatileStatus> {
+        self.volatile_statuses[&pokemon]
+            .get(&kind)
+            .map(|active_status| &active_status.status)
+    }
+
+    fn get_volatile_status_mut(
+        &mut self,
+        pokemon: usize,
+        kind: SimpleVolatileStatus,
+    ) -> Option<&mut VolatileStatus> {
+        self.volatile_statuses
             .get_mut(&pokemon)
             .unwrap()
-            .flags
-            .get_mut(flag_id)
+            .get_mut(&kind)
+            .map(|active_status| &mut active_status.status)
     }
 
-    pub fn has_flag(&self, pokemon: usize, flag_id: &str) -> bool {
-        self.pokemon_flags
-            .get(&pokemon)
+    /// Returns the number of turns left until a volatile status expires, if
+    /// the Pokémon has it and it has a duration.
+    pub fn get_remaining_turns_mut(
+        &mut self,
+        pokemon: usize,
+        kind: SimpleVolatileStatus,
+    ) -> Option<&mut usize> {
+        self.volatile_statuses
+            .get_mut(&pokemon)
             .unwrap()
-            .flags
-            .contains_key(flag_id)
+            .get_mut(&kind)
+            .and_then(|active_status| active_status.remaining_turns.as_mut())
+    }
+
+    /// Checks if a Pokémon can receive a volatile status, i.e it doesn't
+    /// have one of the same kind already and isn't immune to it.
+    pub fn can_add_volatile_status(&self, target: usize, status: &VolatileStatus) -> bool {
+        let kind = SimpleVolatileStatus::from(status);
+        let can_affect = get_volatile_status_effect(kind)
+            .can_affect
+            .unwrap_or(|_, _, _| true);
+
+        !self.has_volatile_status(target, kind) && can_affect(self, target, status)
+    }
+
+    fn get_volatile_status_kinds(&self, pokemon: usize) -> Vec<SimpleVolatileStatus> {
+        self.volatile_statuses[&pokemon].keys().copied().collect()
+    }
+
+    fn get_active_effects(&self, pokemon: usize) -> Vec<StatusConditionEffect> {
+        self.active_effects
+            .get(&pokemon)
+            .map(|effects| effects.iter().map(|(_, effect)| effect.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    fn get_volatile_status_effects(&self, pokemon: usize) -> Vec<VolatileStatusEffect> {
+        self.get_volatile_status_kinds(pokemon)
+            .into_iter()
+            .map(get_volatile_status_effect)
+            .collect()
+    }
+
+    pub fn has_side_condition(&self, team: Team, condition: SideCondition) -> bool {
+        self.get_team_data(team).side_conditions.contains_key(&condition)
+    }
+
+    /// Returns how many times a side condition is stacked on the side of a
+    /// team, or 0 if the team doesn't have it.
+    pub fn get_side_condition_layers(&self, team: Team, condition: SideCondition) -> usize {
+        self.get_team_data(team)
+            .side_conditions
+            .get(&condition)
+            .map_or(0, |active_condition| active_condition.layers)
+    }
+
+    /// Checks if a side condition can be added to a team, i.e the team
+    /// doesn't have it or it can be stacked further.
+    pub fn can_add_side_condition(&self, team: Team, condition: SideCondition) -> bool {
+        let max_layers = get_side_condition_effect(condition).max_layers;
+
+        self.get_side_condition_layers(team, condition) < max_layers
+    }
+
+    fn get_side_conditions(&self, team: Team) -> Vec<SideCondition> {
+        self.get_team_data(team).side_conditions.keys().copied().collect()
     }
 
     pub fn get_non_volatile_status_condition_mut(
@@ -958,6 +1804,10 @@ impl BattleBackend {
         target: usize,
         condition: SimpleStatusCondition,
     ) -> bool {
+        let is_prevented_by_sleep_clause = condition == SimpleStatusCondition::Sleep
+            && self.ruleset.has_clause(&Clause::Sleep)
+            && self.has_sleeping_teammate(target);
+
         let target_is_not_immune = {
             let can_affect = get_status_condition_effect(condition)
                 .can_affect
@@ -966,7 +1816,29 @@ impl BattleBackend {
             can_affect(self, target)
         };
 
-        !self.has_non_volatile_status_condition(target) && target_is_not_immune
+        let is_protected_by_safeguard =
+            self.has_side_condition(self.get_pokemon_team(target), SideCondition::Safeguard);
+
+        !self.has_non_volatile_status_condition(target)
+            && target_is_not_immune
+            && !is_protected_by_safeguard
+            && !is_prevented_by_sleep_clause
+    }
+
+    /// Checks if another Pokémon of the team of a Pokémon is asleep, e.g for
+    /// the Sleep Clause.
+    fn has_sleeping_teammate(&self, pokemon: usize) -> bool {
+        let team_data = self.get_team_data(self.get_pokemon_team(pokemon));
+
+        team_data
+            .active_pokemon
+            .iter()
+            .chain(&team_data.party)
+            .filter(|teammate| **teammate != pokemon && !self.is_fainted(**teammate))
+            .any(|teammate| match self.get_pokemon(*teammate).status_condition {
+                Some(StatusCondition::Sleep { .. }) => true,
+                _ => false,
+            })
     }
 
     fn get_attack_critical_hit(&self, pokemon: usize) -> usize {
@@ -1013,37 +1885,38 @@ impl BattleBackend {
 
         let mut result = (multiplier * pure_stat as f32) as usize;
 
-        self.active_effects
-            .get(&pokemon)
-            .unwrap_or(&Vec::new())
-            .clone()
+        self.get_active_effects(pokemon)
             .iter()
             .filter_map(|effect| effect.on_stat_calculation)
             .for_each(|effect| {
                 result = effect(self, pokemon, stat, result);
             });
 
+        self.get_side_conditions(self.get_pokemon_team(pokemon))
+            .into_iter()
+            .filter_map(|condition| get_side_condition_effect(condition).on_stat_calculation)
+            .for_each(|effect| {
+                result = effect(self, pokemon, stat, result);
+            });
+
         result
     }
 
     /// Returns the value of a stat without considering stat stages and other
     /// factors.
     fn get_pure_stat(&self, pokemon: usize, stat: Stat) -> usize {
-        self.pokemon_repository[&pokemon].stats[stat as usize]
+        match self.transformations.get(&pokemon) {
+            Some(transformation) => transformation.stats[stat as usize],
+            None => self.pokemon_repository[&pokemon].stats[stat as usize],
+        }
     }
 
     fn get_stat_stage(&self, pokemon: usize, stat: Stat) -> i8 {
-        let hash_map = self.pokemon_flags[&pokemon].flags.get("stat_stages");
-
-        if let Some(hash_map) = hash_map {
-            if let Flag::StatStages(stages) = hash_map {
-                *stages.get(&stat).unwrap_or(&0)
-            } else {
-                unreachable!()
-            }
-        } else {
-            0
-        }
+        self.stat_stages
+            .get(&pokemon)
+            .and_then(|stages| stages.get(&stat))
+            .copied()
+            .unwrap_or(0)
     }
 
     fn get_stat_stage_multiplier(&self, stage: i8) -> f32 {
@@ -1077,7 +1950,24 @@ impl BattleBackend {
             },
             None => match mov.base_power {
                 MovePower::Constant(value) => value,
-                MovePower::Special => 0,
+                MovePower::Special | MovePower::Fixed(_) => 0,
+            },
+        }
+    }
+
+    fn get_fixed_damage(&mut self, used_move: &UsedMove, fixed_damage: &FixedDamage) -> usize {
+        let user_level = self.get_pokemon(used_move.user).level;
+
+        match fixed_damage {
+            FixedDamage::Constant(damage) => *damage,
+            FixedDamage::UserLevel => user_level,
+            FixedDamage::HalfTargetHp => {
+                (self.get_pokemon(used_move.target).current_hp / 2).max(1)
+            },
+            FixedDamage::RandomUserLevel => {
+                let percentage = self.rng.get_psywave_percentage();
+
+                (user_level * percentage / 100).max(1)
             },
         }
     }
@@ -1090,48 +1980,96 @@ impl BattleBackend {
         effectiveness: f32,
         is_critical_hit: bool,
     ) -> usize {
-        let level = self.pokemon_repository[&used_move.user].level as f32;
-        let level_modifier = (2. * level) / 5. + 2.;
-        let power = self.get_move_power(&used_move) as f32;
-        let stat_ratio = (attack as f32) / (defense as f32);
-
-        let modifier = {
-            let targets = 1.; // TODO: handle multi-target moves
-            let weather = 1.; // TODO
-            let critical = if is_critical_hit { 1.25 } else { 1. };
-            let random = self.rng.get_damage_modifier();
-            let stab = if self.check_stab(&used_move.movement, used_move.user) {
-                1.5
-            } else {
-                1.
-            };
-            let other = 1.; // TODO
-
-            targets * weather * critical * random * stab * effectiveness * other
-        };
+        let level = self.pokemon_repository[&used_move.user].level;
+        let power = self.get_move_power(&used_move);
+        let factors = self.get_damage_factors(
+            used_move.user,
+            used_move.target,
+            used_move.movement,
+            is_critical_hit,
+            effectiveness,
+        );
+        let modifier = factors.get_modifier(self.rng.get_damage_modifier());
 
-        let power_stat_ratio = (power * stat_ratio).floor();
-        let level_power_stat_ratio = ((level_modifier * power_stat_ratio) / 50.).floor();
+        get_base_damage(level, power, attack, defense, modifier)
+    }
 
-        let damage = (level_power_stat_ratio + 2.) * modifier;
-        let damage = damage as usize;
+    /// Returns the multipliers of the damage formula, except for the random
+    /// factor. Burns are handled by the status condition itself, so the burn
+    /// factor is always 1.
+    fn get_damage_factors(
+        &self,
+        user: usize,
+        target: usize,
+        movement: &Move,
+        is_critical_hit: bool,
+        effectiveness: f32,
+    ) -> DamageFactors {
+        // TODO: handle multi-target moves
+        let screens = self
+            .get_side_conditions(self.get_pokemon_team(target))
+            .into_iter()
+            .filter_map(|condition| get_side_condition_effect(condition).on_damage_calculation)
+            .map(|effect| effect(movement, is_critical_hit))
+            .product::<f32>();
+
+        DamageFactors {
+            critical_hit: if is_critical_hit { 1.25 } else { 1. },
+            stab: if self.check_stab(movement, user) { 1.5 } else { 1. },
+            effectiveness,
+            burn: 1.,
+            // Battles don't have weather yet, see damage_calc::Weather
+            weather: 1.,
+            screens,
+        }
+    }
 
-        if damage == 0 {
-            1
-        } else {
-            damage
+    /// Returns the offensive stat of the user and the defensive stat of the
+    /// target that a move of the given category uses. Critical hits ignore
+    /// unfavorable stat stages.
+    fn get_attack_and_defense(
+        &self,
+        user: usize,
+        target: usize,
+        category: MoveCategory,
+        is_critical_hit: bool,
+    ) -> (usize, usize) {
+        match (is_critical_hit, category) {
+            (false, MoveCategory::Physical) => (
+                self.get_stat(user, Stat::Attack),
+                self.get_stat(target, Stat::Defense),
+            ),
+            (true, MoveCategory::Physical) => (
+                self.get_attack_critical_hit(user),
+                self.get_defense_critical_hit(target),
+            ),
+            (false, MoveCategory::Special) => (
+                self.get_stat(user, Stat::SpecialAttack),
+                self.get_stat(target, Stat::SpecialDefense),
+            ),
+            (true, MoveCategory::Special) => (
+                self.get_special_attack_critical_hit(user),
+                self.get_special_defense_critical_hit(target),
+            ),
+            _ => unreachable!(),
         }
     }
 
-    fn get_type_effectiveness(&self, mov: &Move, target: usize) -> f32 {
+    pub fn get_type_effectiveness(&self, mov: &Move, target: usize) -> f32 {
         // TODO: handle moves without types (e.g Struggle)
 
+        self.get_type_effectiveness_of(mov.move_type, target)
+    }
+
+    /// Returns the effectiveness of a type against the current types of a
+    /// Pokémon, e.g for Stealth Rock.
+    pub fn get_type_effectiveness_of(&self, attacking_type: PokemonType, target: usize) -> f32 {
         self.get_pokemon_current_types(target)
-            .map(|t| PokemonType::get_effectiveness(mov.move_type, *t))
+            .map(|t| self.ruleset.type_chart.get_effectiveness(attacking_type, *t))
             .product()
     }
 
-    fn check_stab(&self, mov: &Move, user: usize) -> bool {
+    pub fn check_stab(&self, mov: &Move, user: usize) -> bool {
         // TODO: handle moves without types (e.g Struggle)
 
         self.has_type(user, mov.move_type)
@@ -1143,18 +2081,71 @@ impl BattleBackend {
             .is_some()
     }
 
+    /// Returns the current types of a Pokémon, which differ from the ones of
+    /// its species after e.g Soak or Transform.
+    pub fn get_current_types(&self, target: usize) -> &[PokemonType] {
+        match self.type_overrides.get(&target) {
+            Some(types) => types,
+            None => &self.get_species(target).types,
+        }
+    }
+
     fn get_pokemon_current_types(&self, target: usize) -> impl Iterator<Item = &PokemonType> {
-        // TODO: handle Pokémon that currently have a different type than their
-        // original ones (e.g after Soak or Roost)
+        self.get_current_types(target).iter()
+    }
 
-        self.get_species(target)
-            .types
-            .iter()
+    /// Decides whether a move lands a critical hit, according to the critical
+    /// hit model of the ruleset.
+    fn check_critical_hit(&mut self, used_move: &UsedMove) -> bool {
+        let stage = self.get_critical_hit_stage(used_move.user, used_move.movement);
+
+        match self.ruleset.critical_hits {
+            CriticalHitModel::Deterministic => stage > 0,
+            CriticalHitModel::Chance => {
+                let denominator = CRITICAL_HIT_CHANCES[stage.min(CRITICAL_HIT_CHANCES.len() - 1)];
+                self.rng.check_critical_hit(denominator)
+            },
+        }
+    }
+
+    /// Checks if a move is sure to land a critical hit, without rolling for it.
+    fn is_guaranteed_critical_hit(&self, user: usize, movement: &Move) -> bool {
+        let stage = self.get_critical_hit_stage(user, movement);
+
+        match self.ruleset.critical_hits {
+            CriticalHitModel::Deterministic => stage > 0,
+            CriticalHitModel::Chance => stage >= CRITICAL_HIT_CHANCES.len() - 1,
+        }
+    }
+
+    /// Returns the critical hit stage of a move, which Focus Energy raises by
+    /// 2 and `Move::critical_hit` raises by 1.
+    fn get_critical_hit_stage(&self, user: usize, movement: &Move) -> usize {
+        let mut stage = 0;
+
+        if movement.critical_hit {
+            stage += 1;
+        }
+
+        if self.has_volatile_status(user, SimpleVolatileStatus::FocusEnergy) {
+            stage += 2;
+        }
+
+        stage
     }
 
     fn check_miss(&mut self, used_move: &UsedMove) -> bool {
         let mov = used_move.movement;
 
+        let target = used_move.target;
+
+        if affects_opponents(mov)
+            && self.is_semi_invulnerable(target)
+            && !self.can_hit_semi_invulnerable_target(mov, target)
+        {
+            return true;
+        }
+
         let mut accuracy = match mov.accuracy_modifier {
             Some(modifier) => {
                 modifier(self, used_move.user, used_move.target, mov)
@@ -1198,7 +2189,86 @@ impl BattleBackend {
         self.rng.check_freeze_thaw()
     }
 
+    pub fn check_confusion_self_hit(&mut self) -> bool {
+        self.rng.check_confusion_self_hit()
+    }
+
+    /// Picks one of the types that Conversion 2 can turn the user into.
+    pub fn choose_conversion_2_type(&mut self, types: &[PokemonType]) -> PokemonType {
+        types[self.rng.get_conversion_2_type_index(types.len())]
+    }
+
+    pub fn check_infatuation_move_prevention(&mut self) -> bool {
+        self.rng.check_infatuation_move_prevention()
+    }
+
     fn is_fainted(&self, pokemon: usize) -> bool {
         self.get_pokemon(pokemon).current_hp == 0
     }
+
+    fn is_semi_invulnerable(&self, pokemon: usize) -> bool {
+        match self.get_volatile_status(pokemon, SimpleVolatileStatus::Charging) {
+            Some(VolatileStatus::Charging { semi_invulnerable, .. }) => *semi_invulnerable,
+            _ => false,
+        }
+    }
+
+    /// Checks if a move can reach a semi-invulnerable Pokémon, e.g Gust
+    /// hitting a Pokémon that is using Fly.
+    fn can_hit_semi_invulnerable_target(&self, movement: &Move, target: usize) -> bool {
+        let charging_move = match self.get_volatile_status(target, SimpleVolatileStatus::Charging) {
+            Some(VolatileStatus::Charging { move_id, .. }) => get_all_moves().get_move(*move_id),
+            _ => return false,
+        };
+        let charges_with = |flag| charging_move.flags.contains(&flag);
+        let hits_with = |flag| movement.flags.contains(&flag);
+
+        (charges_with(MoveFlag::ChargesInTheAir) && hits_with(MoveFlag::HitsTargetsInTheAir))
+            || (charges_with(MoveFlag::ChargesUnderground)
+                && hits_with(MoveFlag::HitsTargetsUnderground))
+    }
+}
+
+/// The damage formula shared by moves and confusion self-hits. The result is
+/// always at least 1.
+fn get_base_damage(
+    level: usize,
+    power: usize,
+    attack: usize,
+    defense: usize,
+    modifier: f32,
+) -> usize {
+    let level_modifier = (2. * level as f32) / 5. + 2.;
+    let stat_ratio = (attack as f32) / (defense as f32);
+
+    let power_stat_ratio = (power as f32 * stat_ratio).floor();
+    let level_power_stat_ratio = ((level_modifier * power_stat_ratio) / 50.).floor();
+
+    let damage = (level_power_stat_ratio + 2.) * modifier;
+    let damage = damage as usize;
+
+    if damage == 0 {
+        1
+    } else {
+        damage
+    }
+}
+
+/// Checks if the damage of a move is calculated by the damage formula, as
+/// opposed to being fixed, e.g Seismic Toss, or a one-hit KO.
+fn uses_damage_formula(movement: &Move) -> bool {
+    match movement.base_power {
+        MovePower::Fixed(_) => false,
+        _ => !movement.flags.contains(&MoveFlag::OneHitKO),
+    }
+}
+
+fn affects_opponents(movement: &Move) -> bool {
+    match movement.target_type {
+        TargetType::User
+        | TargetType::AllyTeam
+        | TargetType::SingleAdjacentAlly
+        | TargetType::SingleAdjacentAllyOrUser => false,
+        _ => true,
+    }
 }
@@ -1,9 +1,15 @@
 use rand::{
     distributions::{Distribution, Uniform},
     seq::SliceRandom,
-    thread_rng,
+    Error as RandError,
+    RngCore,
+    SeedableRng,
 };
 
+use rand_chacha::ChaCha8Rng;
+
+use serde::{Deserialize, Serialize};
+
 use std::{any::Any, fmt::Debug};
 
 use super::UsedMove;
@@ -27,6 +33,10 @@ pub trait BattleRng: Debug + Downcast {
     /// Trait object-safe version of `Clone` for this trait.
     fn boxed_clone(&self) -> Box<dyn BattleRng + Sync + Send>;
 
+    /// Returns the serializable state of this RNG, or `None` if it can't be
+    /// saved, e.g because it's only meant for tests.
+    fn get_state(&self) -> Option<BattleRngState>;
+
     /// Returns a value in the range [0.85, 1].
     fn get_damage_modifier(&mut self) -> f32;
 
@@ -37,6 +47,10 @@ pub trait BattleRng: Debug + Downcast {
     /// Picks a number r in the range [1, 100] and returns r <= 100 - accuracy.
     fn check_miss(&mut self, accuracy: usize) -> bool;
 
+    /// Picks a number r in the range [1, denominator] and returns r == 1,
+    /// i.e tests for a critical hit with a 1/denominator chance.
+    fn check_critical_hit(&mut self, denominator: usize) -> bool;
+
     /// Picks a number r in the range [1, 100] and returns r <= chance.
     fn check_secondary_effect(&mut self, chance: usize) -> bool;
 
@@ -51,26 +65,143 @@ pub trait BattleRng: Debug + Downcast {
     /// Returns the number of turns that a confusion will last.
     fn get_confusion_duration(&mut self) -> usize;
 
-    /// Tests for a confusion miss (50% chance).
-    fn check_confusion_miss(&mut self) -> bool;
+    /// Tests whether a confused Pokémon hurts itself (50% chance).
+    fn check_confusion_self_hit(&mut self) -> bool;
 
     /// Tests for a paralysis move prevention (25% chance).
     fn check_paralysis_move_prevention(&mut self) -> bool;
 
     /// Tests for a freeze thawing (20% chance).
     fn check_freeze_thaw(&mut self) -> bool;
+
+    /// Tests whether an infatuated Pokémon is immobilized by love (50%
+    /// chance).
+    fn check_infatuation_move_prevention(&mut self) -> bool;
+
+    /// Returns the number of turns that a rampage move (e.g Thrash) will
+    /// last, in the range [2, 3].
+    fn get_rampage_duration(&mut self) -> usize;
+
+    /// Returns the number of turns that a binding move (e.g Wrap) will last,
+    /// in the range [4, 5].
+    fn get_binding_duration(&mut self) -> usize;
+
+    /// Returns the percentage of the level of the user that Psywave deals as
+    /// damage, in the range [50, 150].
+    fn get_psywave_percentage(&mut self) -> usize;
+
+    /// Returns the index of the type that Conversion 2 picks among a number
+    /// of options, in the range [0, options).
+    fn get_conversion_2_type_index(&mut self, options: usize) -> usize;
+}
+
+impl Clone for Box<dyn BattleRng + Sync + Send> {
+    fn clone(&self) -> Self {
+        self.boxed_clone()
+    }
+}
+
+/// The state of a `BattleRng` at some point of a battle, which can be saved
+/// and turned back into an RNG that continues from that point.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum BattleRngState {
+    /// The state of a `StandardBattleRng`.
+    Standard {
+        seed: u64,
+        /// How many 32-bit words were drawn from the generator.
+        words: u64,
+    },
+}
+
+impl BattleRngState {
+    /// Creates an RNG in this state.
+    pub fn create_rng(&self) -> Box<dyn BattleRng + Sync + Send> {
+        match self {
+            BattleRngState::Standard { seed, words } => {
+                Box::new(StandardBattleRng::with_drawn_words(*seed, *words))
+            },
+        }
+    }
 }
 
-#[derive(Clone, Debug, Default)]
-pub struct StandardBattleRng;
+/// Wraps a generator, counting the 32-bit words that were drawn from it.
+/// Since ChaCha produces a single stream of words no matter how they're
+/// requested, the seed and the number of drawn words are enough to restore
+/// it.
+#[derive(Clone, Debug)]
+struct CountingRng {
+    rng: ChaCha8Rng,
+    words: u64,
+}
+
+impl RngCore for CountingRng {
+    fn next_u32(&mut self) -> u32 {
+        self.words += 1;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.words += 2;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.words += (dest.len() as u64 + 3) / 4;
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+        self.words += (dest.len() as u64 + 3) / 4;
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// A seeded random number generator. Two instances created from the same
+/// seed always produce the same sequence of values, which makes battles
+/// reproducible: the seed of a battle and the `FrontendEvent`s that were fed
+/// to it are enough to replay it.
+///
+/// Stored replays and logs depend on the exact sequence of values, so the
+/// generator is always ChaCha8, whose output is fixed by its specification,
+/// and every value is sampled from 32-bit ranges, which draw the same words
+/// on every platform.
+#[derive(Clone, Debug)]
+pub struct StandardBattleRng {
+    seed: u64,
+    rng: CountingRng,
+}
 
 impl StandardBattleRng {
+    pub fn new(seed: u64) -> StandardBattleRng {
+        StandardBattleRng {
+            seed,
+            rng: CountingRng {
+                rng: ChaCha8Rng::seed_from_u64(seed),
+                words: 0,
+            },
+        }
+    }
+
+    /// Creates an RNG that continues after `words` 32-bit words were drawn
+    /// from the one created by `StandardBattleRng::new(seed)`.
+    fn with_drawn_words(seed: u64, words: u64) -> StandardBattleRng {
+        let mut rng = StandardBattleRng::new(seed);
+        rng.rng.rng.set_word_pos(u128::from(words));
+        rng.rng.words = words;
+
+        rng
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
     fn rand(&mut self, lowest: isize, highest: isize) -> isize {
-        Uniform::new(lowest, highest + 1).sample(&mut thread_rng())
+        Uniform::new_inclusive(lowest as i32, highest as i32).sample(&mut self.rng) as isize
     }
 
     fn rand_unsigned(&mut self, lowest: usize, highest: usize) -> usize {
-        Uniform::new(lowest, highest + 1).sample(&mut thread_rng())
+        Uniform::new_inclusive(lowest as u32, highest as u32).sample(&mut self.rng) as usize
     }
 
     fn roll(&mut self, chance: usize) -> bool {
@@ -83,18 +214,29 @@ impl BattleRng for StandardBattleRng {
         Box::new(self.clone())
     }
 
+    fn get_state(&self) -> Option<BattleRngState> {
+        Some(BattleRngState::Standard {
+            seed: self.seed,
+            words: self.rng.words,
+        })
+    }
+
     fn get_damage_modifier(&mut self) -> f32 {
         self.rand(85, 100) as f32 / 100.
     }
 
     fn shuffle_moves<'a>(&mut self, moves: &mut Vec<UsedMove<'a>>) {
-        moves.shuffle(&mut thread_rng());
+        moves.shuffle(&mut self.rng);
     }
 
     fn check_miss(&mut self, accuracy: usize) -> bool {
         self.roll(100 - accuracy)
     }
 
+    fn check_critical_hit(&mut self, denominator: usize) -> bool {
+        self.rand_unsigned(1, denominator) == 1
+    }
+
     fn check_secondary_effect(&mut self, chance: usize) -> bool {
         self.roll(chance)
     }
@@ -111,7 +253,7 @@ impl BattleRng for StandardBattleRng {
         self.rand_unsigned(1, 4)
     }
 
-    fn check_confusion_miss(&mut self) -> bool {
+    fn check_confusion_self_hit(&mut self) -> bool {
         self.roll(50)
     }
 
@@ -122,4 +264,24 @@ impl BattleRng for StandardBattleRng {
     fn check_freeze_thaw(&mut self) -> bool {
         self.roll(20)
     }
+
+    fn check_infatuation_move_prevention(&mut self) -> bool {
+        self.roll(50)
+    }
+
+    fn get_rampage_duration(&mut self) -> usize {
+        self.rand_unsigned(2, 3)
+    }
+
+    fn get_binding_duration(&mut self) -> usize {
+        self.rand_unsigned(4, 5)
+    }
+
+    fn get_psywave_percentage(&mut self) -> usize {
+        self.rand_unsigned(50, 150)
+    }
+
+    fn get_conversion_2_type_index(&mut self, options: usize) -> usize {
+        self.rand_unsigned(0, options - 1)
+    }
 }
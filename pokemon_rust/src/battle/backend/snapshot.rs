@@ -0,0 +1,201 @@
+//! Snapshots of the full state of a battle, including its RNG, which can
+//! restore a backend to an earlier turn or be saved along with the game.
+//!
+//! The state can also be summarized by a digest, which is enough to tell
+//! whether two runs of a battle diverged.
+
+use crate::{
+    constants::MOVE_LIMIT,
+    pokemon::{
+        get_status_condition_effect,
+        movement::MoveId,
+        Pokemon,
+        PokemonType,
+        SideCondition,
+        SimpleVolatileStatus,
+        Stat,
+        StatusCondition,
+    },
+};
+
+use ron::ser::to_string;
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use super::{
+    super::{rules::Ruleset, types::BattleType},
+    rng::BattleRngState,
+    ActiveSideCondition,
+    ActiveVolatileStatus,
+    BattleBackend,
+    FrontendEvent,
+    TeamData,
+    Transformation,
+};
+
+/// The state of a battle between two turns.
+///
+/// Status condition effects aren't stored since they're derived from the
+/// status conditions of the Pokémon, and neither are the events that the
+/// backend hasn't returned yet.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BattleSnapshot {
+    battle_type: BattleType,
+    turn: usize,
+    p1: TeamData,
+    p2: TeamData,
+    volatile_statuses: HashMap<usize, BTreeMap<SimpleVolatileStatus, ActiveVolatileStatus>>,
+    stat_stages: HashMap<usize, HashMap<Stat, i8>>,
+    last_used_moves: HashMap<usize, MoveId>,
+    type_overrides: HashMap<usize, Vec<PokemonType>>,
+    transformations: HashMap<usize, Transformation>,
+    input_events: VecDeque<FrontendEvent>,
+    pokemon_repository: HashMap<usize, Pokemon>,
+    ruleset: Ruleset,
+    rng: BattleRngState,
+}
+
+/// The parts of the state that a digest covers, with every map sorted so
+/// that the digest doesn't depend on their iteration order.
+#[derive(Serialize)]
+struct DigestState<'a> {
+    turn: usize,
+    active_pokemon: [Option<usize>; 2],
+    side_conditions: [&'a BTreeMap<SideCondition, ActiveSideCondition>; 2],
+    /// The current HP, PP and status condition of each Pokémon.
+    pokemon: BTreeMap<usize, (usize, &'a [usize; MOVE_LIMIT], Option<StatusCondition>)>,
+    volatile_statuses: BTreeMap<usize, &'a BTreeMap<SimpleVolatileStatus, ActiveVolatileStatus>>,
+    stat_stages: BTreeMap<usize, Vec<(Stat, i8)>>,
+    type_overrides: BTreeMap<usize, &'a Vec<PokemonType>>,
+}
+
+impl BattleSnapshot {
+    /// The turn of the battle when this snapshot was taken.
+    pub fn get_turn(&self) -> usize {
+        self.turn
+    }
+
+    pub fn get_rng_state(&self) -> &BattleRngState {
+        &self.rng
+    }
+}
+
+impl BattleBackend {
+    /// Takes a snapshot of the current state of the battle. Returns `None`
+    /// if the RNG of the battle can't be saved (see `BattleRng::get_state`).
+    pub fn snapshot(&self) -> Option<BattleSnapshot> {
+        Some(BattleSnapshot {
+            battle_type: self.battle_type.clone(),
+            turn: self.turn,
+            p1: self.p1.clone(),
+            p2: self.p2.clone(),
+            volatile_statuses: self.volatile_statuses.clone(),
+            stat_stages: self.stat_stages.clone(),
+            last_used_moves: self.last_used_moves.clone(),
+            type_overrides: self.type_overrides.clone(),
+            transformations: self.transformations.clone(),
+            input_events: self.input_events.clone(),
+            pokemon_repository: self.pokemon_repository.clone(),
+            ruleset: self.ruleset.clone(),
+            rng: self.rng.get_state()?,
+        })
+    }
+
+    /// Creates a backend in the state of a snapshot.
+    pub fn from_snapshot(snapshot: BattleSnapshot) -> BattleBackend {
+        let active_effects = snapshot
+            .pokemon_repository
+            .iter()
+            .filter_map(|(index, pokemon)| {
+                pokemon.status_condition.map(|condition| {
+                    let effect = get_status_condition_effect(condition.into());
+
+                    (*index, vec![(condition.into(), effect)])
+                })
+            })
+            .collect();
+
+        BattleBackend {
+            battle_type: snapshot.battle_type,
+            turn: snapshot.turn,
+            p1: snapshot.p1,
+            p2: snapshot.p2,
+            volatile_statuses: snapshot.volatile_statuses,
+            stat_stages: snapshot.stat_stages,
+            last_used_moves: snapshot.last_used_moves,
+            type_overrides: snapshot.type_overrides,
+            transformations: snapshot.transformations,
+            active_effects,
+            input_events: snapshot.input_events,
+            event_queue: Vec::new(),
+            pokemon_repository: snapshot.pokemon_repository,
+            ruleset: snapshot.ruleset,
+            rng: snapshot.rng.create_rng(),
+        }
+    }
+
+    /// Brings the battle back to the state of a snapshot, e.g to undo a turn.
+    pub fn restore(&mut self, snapshot: &BattleSnapshot) {
+        *self = BattleBackend::from_snapshot(snapshot.clone());
+    }
+
+    /// Returns a hash of the state that battle events change: the turn, the
+    /// active Pokémon, side conditions, and the HP, PP, status conditions,
+    /// volatile statuses, stat stages and types of every Pokémon. The digest
+    /// is stable across runs, so it can be stored in battle logs.
+    pub fn get_state_digest(&self) -> u64 {
+        let pokemon = self
+            .pokemon_repository
+            .keys()
+            .map(|index| {
+                let pokemon = self.get_pokemon(*index);
+
+                (*index, (pokemon.current_hp, self.get_pp(*index), pokemon.status_condition))
+            })
+            .collect();
+
+        let stat_stages = self
+            .stat_stages
+            .iter()
+            .map(|(index, stages)| {
+                let mut stages: Vec<_> = stages
+                    .iter()
+                    .map(|(stat, stage)| (*stat, *stage))
+                    .collect();
+                stages.sort_by_key(|(stat, _)| *stat as usize);
+
+                (*index, stages)
+            })
+            .collect();
+
+        let state = DigestState {
+            turn: self.turn,
+            active_pokemon: [self.p1.active_pokemon, self.p2.active_pokemon],
+            side_conditions: [&self.p1.side_conditions, &self.p2.side_conditions],
+            pokemon,
+            volatile_statuses: self
+                .volatile_statuses
+                .iter()
+                .map(|(index, statuses)| (*index, statuses))
+                .collect(),
+            stat_stages,
+            type_overrides: self
+                .type_overrides
+                .iter()
+                .map(|(index, types)| (*index, types))
+                .collect(),
+        };
+
+        let serialized_state = to_string(&state).expect("Failed serializing the battle state");
+
+        // FNV-1a, since the hashers of the standard library aren't guaranteed
+        // to give the same results in future versions
+        serialized_state
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+}
@@ -23,7 +23,7 @@ use crate::{
         WINDOW_HEIGHT,
         WINDOW_WIDTH,
     },
-    pokemon::{get_all_pokemon_species, get_pokemon_display_name, Pokemon},
+    pokemon::{get_all_pokemon_species, Pokemon},
 };
 
 use super::super::BattleSystemData;
@@ -124,6 +124,21 @@ impl InfoCard {
         pokemon: &Pokemon,
         system_data: &mut BattleSystemData,
     ) {
+        self.update_health(pokemon, system_data);
+    }
+
+    pub fn heal(
+        &mut self,
+        _amount: usize,
+        pokemon: &Pokemon,
+        system_data: &mut BattleSystemData,
+    ) {
+        self.update_health(pokemon, system_data);
+    }
+}
+
+impl InfoCard {
+    fn update_health(&mut self, pokemon: &Pokemon, system_data: &mut BattleSystemData) {
         let BattleSystemData {
             ui_texts,
             ui_transforms,
@@ -146,9 +161,7 @@ impl InfoCard {
                 .text = content;
         }
     }
-}
 
-impl InfoCard {
     fn get_properties(team: Team) -> HealthBarProperties {
         match team {
             Team::P1 => HealthBarProperties {
@@ -239,7 +252,7 @@ impl InfoCard {
         let pokedex = get_all_pokemon_species();
 
         Self::create_ui_text(
-            get_pokemon_display_name(&pokemon, pokedex).to_string(),
+            system_data.localization.get_pokemon_name(&pokemon, pokedex),
             HEALTH_BAR_POKEMON_NAME_FONT_SIZE,
             properties.content_x,
             properties.top_y - HEALTH_BAR_POKEMON_NAME_FONT_SIZE,
@@ -252,7 +265,10 @@ impl InfoCard {
         properties: &HealthBarProperties,
         system_data: &mut BattleSystemData,
     ) -> Entity {
-        let content = format!("Lv. {}", pokemon.level);
+        let level = pokemon.level.to_string();
+        let content = system_data
+            .localization
+            .tr_with("battle.level", &[("level", &level)]);
         let font_size = HEALTH_BAR_POKEMON_LEVEL_FONT_SIZE;
         let content_width = Self::estimate_text_width(&content, font_size);
 
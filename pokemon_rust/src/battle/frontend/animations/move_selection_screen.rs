@@ -1,33 +1,56 @@
-use amethyst::input::{InputEvent, StringBindings};
+use amethyst::{
+    ecs::Entity,
+    input::{InputEvent, StringBindings},
+    ui::{Anchor, UiText, UiTransform},
+};
 
 use crate::{
     audio::Sound,
     battle::backend::{
+        damage_calc::DamageCalcOptions,
         BattleBackend,
         FrontendEvent,
         FrontendEventKind,
         Team,
     },
     constants::AXIS_SENSITIVITY,
+    pokemon::get_all_moves,
 };
 
 use super::super::{BattleSystemData, FrontendAnimation, TickResult};
 
 use super::SelectionScreen;
 
+const MOVE_BUTTON_WIDTH: f32 = 160.;
+const DAMAGE_OVERLAY_FONT_SIZE: f32 = 16.;
+const DAMAGE_OVERLAY_WIDTH: f32 = 360.;
+
 pub enum MoveSelectionScreen {
     PendingStart,
-    Started { selection_screen: SelectionScreen },
+    Started {
+        selection_screen: SelectionScreen,
+        /// In debug builds, the texts next to each move that show how much
+        /// damage it deals to the opponent.
+        damage_overlay: Vec<Entity>,
+    },
 }
 
 impl MoveSelectionScreen {
     fn select_option(&mut self, system_data: &mut BattleSystemData) -> TickResult {
         if let Self::Started {
-            selection_screen, ..
+            selection_screen,
+            damage_overlay,
         } = self {
             let move_index = selection_screen.get_focused_option();
             selection_screen.remove(system_data);
 
+            for entity in damage_overlay.drain(..) {
+                system_data
+                    .entities
+                    .delete(entity)
+                    .expect("Failed to delete damage overlay");
+            }
+
             TickResult::emit(FrontendEvent {
                 team: Team::P1,
                 event: FrontendEventKind::UseMove(move_index.into()),
@@ -38,23 +61,115 @@ impl MoveSelectionScreen {
     }
 }
 
+/// Describes the damage that each move of the active Pokémon of the player
+/// deals to the opponent, e.g "Tackle: 20.5-24.1% (KO 0%)". Empty move slots
+/// have no description.
+fn get_damage_descriptions(
+    backend: &BattleBackend,
+    system_data: &BattleSystemData,
+) -> Vec<Option<String>> {
+    let user = backend.get_active_pokemon_index(Team::P1).unwrap();
+    let target = backend.get_active_pokemon_index(Team::P2).unwrap();
+    let movedex = get_all_moves();
+
+    backend
+        .get_moves(user)
+        .iter()
+        .map(|move_id| {
+            let movement = movedex.get_move((*move_id)?);
+            let name = system_data.localization.get_move_name(movement);
+            let options = DamageCalcOptions::default();
+
+            let description = match backend.calculate_damage(user, target, movement, &options) {
+                Some(range) => format!(
+                    "{}: {:.1}-{:.1}% (KO {:.0}%)",
+                    name,
+                    range.get_min_percentage(),
+                    range.get_max_percentage(),
+                    100. * range.get_ko_chance(),
+                ),
+                None => name,
+            };
+
+            Some(description)
+        })
+        .collect()
+}
+
+/// Creates the damage overlay of the move selection screen, placing each
+/// description to the left of its button.
+fn create_damage_overlay(
+    backend: &BattleBackend,
+    num_options: usize,
+    system_data: &mut BattleSystemData,
+) -> Vec<Entity> {
+    let descriptions = get_damage_descriptions(backend, system_data);
+    let BattleSystemData {
+        ui_texts,
+        ui_transforms,
+        entities,
+        resources,
+        ..
+    } = system_data;
+
+    descriptions
+        .into_iter()
+        .take(num_options)
+        .enumerate()
+        .filter_map(|(index, description)| Some((index, description?)))
+        .map(|(index, description)| {
+            let mut ui_text = UiText::new(
+                resources.font.clone(),
+                description,
+                [0., 0., 0., 1.],
+                DAMAGE_OVERLAY_FONT_SIZE,
+            );
+            ui_text.align = Anchor::MiddleRight;
+
+            let ui_transform = UiTransform::new(
+                format!("Damage Overlay {}", index),
+                Anchor::BottomRight,
+                Anchor::BottomRight,
+                -SelectionScreen::get_width(MOVE_BUTTON_WIDTH),
+                SelectionScreen::get_button_bottom_y(num_options, index as u8),
+                3.,
+                DAMAGE_OVERLAY_WIDTH,
+                DAMAGE_OVERLAY_FONT_SIZE,
+            );
+
+            entities
+                .build_entity()
+                .with(ui_text, ui_texts)
+                .with(ui_transform, ui_transforms)
+                .build()
+        })
+        .collect()
+}
+
 impl FrontendAnimation for MoveSelectionScreen {
     fn start(
         &mut self,
-        _backend: &BattleBackend,
+        backend: &BattleBackend,
         system_data: &mut BattleSystemData,
     ) {
+        // TODO: show the names of the moves on the buttons
+        let buttons = vec![
+            system_data.resources.fight_button.clone(),
+            system_data.resources.fight_button.clone(),
+            system_data.resources.fight_button.clone(),
+            system_data.resources.fight_button.clone(),
+        ];
+        let num_options = buttons.len();
+
+        let damage_overlay = if cfg!(debug_assertions) {
+            create_damage_overlay(backend, num_options, system_data)
+        } else {
+            Vec::new()
+        };
+
         *self = MoveSelectionScreen::Started {
-            selection_screen: SelectionScreen::new(
-                160.,
-                vec![
-                    system_data.resources.fight_button.clone(),
-                    system_data.resources.fight_button.clone(),
-                    system_data.resources.fight_button.clone(),
-                    system_data.resources.fight_button.clone(),
-                ],
-                system_data,
-            ),
+            selection_screen: SelectionScreen::new(MOVE_BUTTON_WIDTH, buttons, system_data),
+            damage_overlay,
         };
     }
 
@@ -5,9 +5,12 @@ use amethyst::{
     ui::{Anchor, UiImage, UiTransform},
 };
 
+use crate::common::cycle_option;
+
 use super::super::BattleSystemData;
 
 const SELECTION_SCREEN_ARROW_HEIGHT: f32 = 37.;
+const SELECTION_SCREEN_ARROW_WIDTH: f32 = 32.;
 const SELECTION_SCREEN_BUTTON_SCREEN_MARGIN: f32 = 10.;
 const SELECTION_SCREEN_BUTTON_HEIGHT: f32 = 47.;
 
@@ -42,15 +45,20 @@ impl SelectionScreen {
         }
     }
 
+    /// Returns how far the options of a selection screen extend from the
+    /// right edge of the screen, including the selection arrow.
+    pub fn get_width(button_width: f32) -> f32 {
+        SELECTION_SCREEN_BUTTON_SCREEN_MARGIN + button_width + SELECTION_SCREEN_ARROW_WIDTH
+    }
+
     pub fn get_focused_option(&self) -> u8 {
         self.focused_option
     }
 
     pub fn move_selection(&mut self, offset: i8, system_data: &mut BattleSystemData) {
         let num_options = self.button_entities.len() as u8;
-        let scaled_option = ((self.focused_option + num_options) as i8 + offset) as u8;
 
-        self.focused_option = scaled_option % num_options;
+        self.focused_option = cycle_option(self.focused_option, num_options, offset);
         self.update_selection_arrow(system_data);
     }
 
@@ -75,7 +83,7 @@ impl SelectionScreen {
             "Selection Arrow".to_string(),
             -SELECTION_SCREEN_BUTTON_SCREEN_MARGIN - button_width,
             Self::get_selection_arrow_y(num_options, 0),
-            32.,
+            SELECTION_SCREEN_ARROW_WIDTH,
             SELECTION_SCREEN_ARROW_HEIGHT,
             system_data,
         )
@@ -152,7 +160,7 @@ impl SelectionScreen {
         Self::get_button_bottom_y(num_options, focused_option) + height_difference / 2.
     }
 
-    fn get_button_bottom_y(num_options: usize, option_index: u8) -> f32 {
+    pub fn get_button_bottom_y(num_options: usize, option_index: u8) -> f32 {
         let option_index: usize = option_index.into();
         let inverted_option = (num_options - 1 - option_index) as f32;
 
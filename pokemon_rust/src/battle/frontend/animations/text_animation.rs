@@ -30,6 +30,7 @@ impl FrontendAnimation for TextAnimation {
                 ui_transforms,
                 entities,
                 resources,
+                text_layout_data,
                 ..
             } = system_data;
 
@@ -40,6 +41,7 @@ impl FrontendAnimation for TextAnimation {
                 ui_transforms,
                 &entities,
                 &resources,
+                &text_layout_data,
             );
 
             let text_box_entity = entities.build_entity().with(text_box, text_boxes).build();
@@ -21,46 +21,30 @@ use amethyst::{
     shred::ResourceId,
     shrev::EventChannel,
     ui::{UiImage, UiText, UiTransform},
+    utils::application_root_dir,
 };
 
 use crate::{
     audio::SoundKit,
     battle::{
+        ai::{BackgroundAi, BattleAi, ExpertAi, RandomAi},
         backend::{
-            event::{
-                Damage,
-                ExpiredNonVolatileStatusCondition,
-                ExpiredVolatileStatusCondition,
-                Faint,
-                InitialSwitchIn,
-                Miss,
-                NonVolatileStatusCondition,
-                StatChange,
-                UseMove,
-                VolatileStatusCondition,
-            },
+            event::{Damage, Heal},
             rng::StandardBattleRng,
             BattleBackend,
             BattleEvent,
-            DamageCause,
-            Flag,
             FrontendEvent,
-            StatChangeKind,
             Team,
-            TypeEffectiveness,
         },
+        log::BattleLogFile,
+        messages::get_event_messages,
+        replay::{BattleReplay, ReplayFile, ReplayTurn},
         types::Battle,
     },
     common::CommonResources,
     config::GameConfig,
-    pokemon::{
-        get_all_pokemon_species,
-        get_pokemon_display_name,
-        SimpleStatusCondition,
-        Stat,
-        StatusCondition,
-    },
-    text::TextBox,
+    i18n::Localization,
+    text::{TextBox, TextLayoutData},
 };
 
 use self::animations::{ActionSelectionScreen, InfoCard, InitialSwitchInAnimation, TextAnimation};
@@ -79,6 +63,8 @@ pub struct BattleSystemData<'a> {
     ui_transforms: WriteStorage<'a, UiTransform>,
     entities: Entities<'a>,
     resources: ReadExpect<'a, CommonResources>,
+    localization: ReadExpect<'a, Localization>,
+    text_layout_data: TextLayoutData<'a>,
     game_config: ReadExpect<'a, GameConfig>,
     input_event_channel: Read<'a, EventChannel<InputEvent<StringBindings>>>,
     sound_kit: SoundKit<'a>,
@@ -102,6 +88,20 @@ pub struct BattleSystem {
     active_animation_sequence: Option<AnimationSequence>,
     p1_info_card: Option<InfoCard>,
     p2_info_card: Option<InfoCard>,
+    /// Records the current battle to `replays/`, appending every turn to the
+    /// replay file as soon as it's played. Only enabled by
+    /// `GameConfig::record_replays`.
+    recorder: Option<ReplayFile>,
+    /// Writes a structured log of the current battle to `logs/`. Only
+    /// enabled by `GameConfig::write_battle_logs`.
+    log: Option<BattleLogFile>,
+    /// The remaining turns of the replay being played, if any.
+    playback: Option<VecDeque<ReplayTurn>>,
+    /// Chooses the actions of the opponent.
+    opponent_ai: Option<BackgroundAi>,
+    /// The actions of the player for the next turn, which is processed once
+    /// the opponent is done choosing its action.
+    pending_turn: Option<Vec<FrontendEvent>>,
 }
 
 struct AnimationSequence {
@@ -155,6 +155,9 @@ trait FrontendAnimation {
 }
 
 impl BattleSystem {
+    /// Creates a battle system for the `Battle` resource. If there's also a
+    /// `BattleReplay` resource, it's consumed and the system plays it back
+    /// instead of asking for the player's input.
     pub fn new(world: &mut World) -> BattleSystem {
         BattleSystem {
             event_reader: world
@@ -165,50 +168,64 @@ impl BattleSystem {
             active_animation_sequence: None,
             p1_info_card: None,
             p2_info_card: None,
+            recorder: None,
+            log: None,
+            playback: world
+                .remove::<BattleReplay>()
+                .map(|replay| replay.turns.into()),
+            opponent_ai: None,
+            pending_turn: None,
         }
     }
 
     fn handle_next_backend_event(&mut self, system_data: &mut BattleSystemData<'_>) {
         let event = self.event_queue.pop_front().unwrap();
-        println!("{:?}", event);
+
+        let backend = self.backend.as_ref().unwrap();
+        let messages = get_event_messages(
+            backend,
+            &event,
+            self.event_queue.front(),
+            &system_data.localization,
+        );
+
+        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = messages
+            .into_iter()
+            .map(|text| {
+                Box::new(TextAnimation::PendingStart { text })
+                    as Box<dyn FrontendAnimation + Sync + Send>
+            })
+            .collect();
 
         match event {
             BattleEvent::InitialSwitchIn(event_data) => {
-                self.handle_initial_switch_in(event_data);
-            },
-            BattleEvent::ChangeTurn(_) => { },
-            BattleEvent::UseMove(event_data) => {
-                self.handle_use_move(event_data);
+                let team = event_data.team;
+                let animation = Box::new(InitialSwitchInAnimation::PendingStart { event_data });
+
+                // The player's Pokémon is called before it appears, while the
+                // opponent's Pokémon is announced after appearing
+                match team {
+                    Team::P1 => animations.push(animation),
+                    Team::P2 => animations.insert(0, animation),
+                }
             },
             BattleEvent::Damage(event_data) => {
                 self.handle_damage(event_data, system_data);
             },
-            BattleEvent::Miss(event_data) => {
-                self.handle_miss(event_data);
-            },
-            BattleEvent::StatChange(event_data) => {
-                self.handle_stat_change(event_data);
-            },
-            BattleEvent::VolatileStatusCondition(event_data) => {
-                self.handle_volatile_status_condition(event_data);
-            },
-            BattleEvent::ExpiredVolatileStatusCondition(event_data) => {
-                self.handle_expired_volatile_status_condition(event_data);
-            },
-            BattleEvent::NonVolatileStatusCondition(event_data) => {
-                self.handle_non_volatile_status_condition(event_data);
-            },
-            BattleEvent::ExpiredNonVolatileStatusCondition(event_data) => {
-                self.handle_expired_non_volatile_status_condition(event_data);
-            },
-            BattleEvent::FailedMove(_) => {
-                self.handle_failed_move();
-            },
-            BattleEvent::Faint(event_data) => {
-                self.handle_faint(event_data);
+            BattleEvent::Heal(event_data) => {
+                self.handle_heal(event_data, system_data);
             },
+            _ => {},
         }
 
+        self.active_animation_sequence = if animations.is_empty() {
+            None
+        } else {
+            Some(AnimationSequence {
+                animations: animations.into(),
+            })
+        };
+
         self.start_animation(system_data);
     }
 
@@ -243,18 +260,7 @@ impl BattleSystem {
                 active_animation_sequence.animations.pop_front();
 
                 if !emitted_events.is_empty() {
-                    for event in emitted_events {
-                        backend.push_frontend_event(event);
-                    }
-
-                    // TODO: replace this by an AI call
-                    use crate::battle::backend::FrontendEventKind;
-                    backend.push_frontend_event(FrontendEvent {
-                        team: Team::P2,
-                        event: FrontendEventKind::UseMove(0),
-                    });
-
-                    self.event_queue.extend(backend.tick());
+                    self.pending_turn = Some(emitted_events);
                 }
 
                 new_animations
@@ -273,101 +279,9 @@ impl BattleSystem {
 }
 
 impl BattleSystem {
-    fn handle_initial_switch_in(&mut self, event_data: InitialSwitchIn) {
-        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
-
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
-        let pokemon = backend.get_pokemon(event_data.pokemon);
-
-        match event_data.team {
-            Team::P1 => {
-                let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("Go! {}!", display_name),
-                }));
-
-                animations.push(Box::new(InitialSwitchInAnimation::PendingStart {
-                    event_data: event_data.clone(),
-                }));
-            },
-            Team::P2 => {
-                let species = pokedex.get_species(&pokemon.species_id).unwrap();
-
-                animations.push(Box::new(InitialSwitchInAnimation::PendingStart {
-                    event_data: event_data.clone(),
-                }));
-
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("A wild {} appears!", species.display_name),
-                }));
-            },
-        }
-
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
-    }
-
-    fn handle_use_move(&mut self, event_data: UseMove) {
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
-        let pokemon = backend.get_pokemon(event_data.move_user);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-
-        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
-
-        if backend.has_flag(event_data.move_user, "confusion") {
-            animations.push(Box::new(TextAnimation::PendingStart {
-                text: format!("{} is confused!", display_name),
-            }));
-        }
-
-        animations.push(Box::new(TextAnimation::PendingStart {
-            text: format!("{} used {}!", display_name, event_data.move_name),
-        }));
-
-        match self.event_queue.front() {
-            Some(BattleEvent::UseMove(_)) | Some(BattleEvent::ChangeTurn(_)) => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: "But nothing happened!".to_string(),
-                }));
-            },
-            _ => { },
-        }
-
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
-    }
-
     fn handle_damage(&mut self, event_data: Damage, system_data: &mut BattleSystemData<'_>) {
-        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
-
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
+        let backend = self.backend.as_ref().unwrap();
         let pokemon = backend.get_pokemon(event_data.target);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-
-        match event_data.cause {
-            DamageCause::Move => {},
-            DamageCause::Burn => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} is hurt by its burn!", display_name),
-                }));
-            },
-            DamageCause::Poison => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} is hurt by its poison!", display_name),
-                }));
-            },
-            DamageCause::Toxic => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} is hurt by its poison!", display_name),
-                }));
-            },
-        }
 
         let info_card = match backend.get_pokemon_team(event_data.target) {
             Team::P1 => self.p1_info_card.as_mut().unwrap(),
@@ -375,285 +289,41 @@ impl BattleSystem {
         };
 
         info_card.damage(event_data.amount, &pokemon, system_data);
-
-        {
-            let effectiveness_text = match event_data.effectiveness {
-                TypeEffectiveness::Immune => {
-                    Some(format!("It doesn't affect {}...", display_name))
-                },
-                TypeEffectiveness::BarelyEffective => Some("It's barely effective...".to_string()),
-                TypeEffectiveness::NotVeryEffective => {
-                    Some("It's not very effective...".to_string())
-                },
-                TypeEffectiveness::Normal => {
-                    // TODO: remove this after health reduction becomes an animation
-                    Some(format!("{} damage!", event_data.amount))
-                },
-                TypeEffectiveness::SuperEffective => Some("It's super effective!".to_string()),
-                TypeEffectiveness::ExtremelyEffective => {
-                    Some("It's extremely effective!".to_string())
-                },
-            };
-
-            if let Some(text) = effectiveness_text {
-                animations.push(Box::new(TextAnimation::PendingStart { text }));
-            }
-        }
-
-        if event_data.is_critical_hit {
-            animations.push(Box::new(TextAnimation::PendingStart {
-                text: "Critical hit!".to_string(),
-            }));
-        }
-
-        if event_data.is_last_multi_hit_damage {
-            if let Some(index) = event_data.multi_hit_index {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("Hit {} times!", index + 1),
-                }));
-            }
-        }
-
-        if event_data.is_ohko {
-            animations.push(Box::new(TextAnimation::PendingStart {
-                text: "It's a one-hit KO!".to_string(),
-            }));
-        }
-
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
     }
 
-    fn handle_miss(&mut self, event_data: Miss) {
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
+    fn handle_heal(&mut self, event_data: Heal, system_data: &mut BattleSystemData<'_>) {
+        let backend = self.backend.as_ref().unwrap();
         let pokemon = backend.get_pokemon(event_data.target);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-
-        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
 
-        if event_data.caused_by_confusion {
-            animations.push(Box::new(TextAnimation::PendingStart {
-                text: format!("But its confusion caused it to miss!"),
-            }));
-        } else {
-            animations.push(Box::new(TextAnimation::PendingStart {
-                text: format!("But {} avoided the attack!", display_name),
-            }));
-        }
-
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
-    }
-
-    fn handle_stat_change(&mut self, event_data: StatChange) {
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
-        let pokemon = backend.get_pokemon(event_data.target);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-
-        let stat = match event_data.stat {
-            Stat::HP => unreachable!(),
-            Stat::Attack => "attack",
-            Stat::Defense => "defense",
-            Stat::SpecialAttack => "special attack",
-            Stat::SpecialDefense => "special defense",
-            Stat::Speed => "speed",
-            Stat::Accuracy => "accuracy",
-            Stat::Evasion => "evasion",
-        };
-
-        let text = match event_data.kind {
-            StatChangeKind::WontGoAnyLower => {
-                format!("{}'s {} won't go any lower!", display_name, stat)
-            },
-            StatChangeKind::SeverelyFell => format!("{}'s {} severely fell!", display_name, stat),
-            StatChangeKind::HarshlyFell => format!("{}'s {} harshly fell!", display_name, stat),
-            StatChangeKind::Fell => format!("{}'s {} fell!", display_name, stat),
-            StatChangeKind::Rose => format!("{}'s {} rose!", display_name, stat),
-            StatChangeKind::SharplyRose => format!("{}'s {} sharply rose!", display_name, stat),
-            StatChangeKind::DrasticallyRose => {
-                format!("{}'s {} drastically rose!", display_name, stat)
-            },
-            StatChangeKind::WontGoAnyHigher => {
-                format!("{}'s {} won't go any higher!", display_name, stat)
-            },
+        let info_card = match backend.get_pokemon_team(event_data.target) {
+            Team::P1 => self.p1_info_card.as_mut().unwrap(),
+            Team::P2 => self.p2_info_card.as_mut().unwrap(),
         };
 
-        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
-            vec![Box::new(TextAnimation::PendingStart { text })];
-
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
+        info_card.heal(event_data.amount, &pokemon, system_data);
     }
 
-    fn handle_volatile_status_condition(&mut self, event_data: VolatileStatusCondition) {
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
-        let pokemon = backend.get_pokemon(event_data.target);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
-
-        match event_data.added_flag {
-            Flag::Confusion { .. } => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} became confused!", display_name),
-                }));
-            },
-            Flag::Flinch => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} flinched!", display_name),
-                }));
-            },
-            Flag::StatStages(_) => unreachable!(),
+    fn play_next_replay_turn(&mut self, system_data: &mut BattleSystemData<'_>) {
+        if self.p1_info_card.is_none() {
+            self.init_info_cards(system_data);
         }
 
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
-    }
+        let turn = match self.playback.as_mut().and_then(VecDeque::pop_front) {
+            Some(turn) => turn,
+            None => return,
+        };
 
-    fn handle_expired_volatile_status_condition(
-        &mut self,
-        event_data: ExpiredVolatileStatusCondition,
-    ) {
-        let pokedex = get_all_pokemon_species();
         let backend = self.backend.as_mut().unwrap();
-        let pokemon = backend.get_pokemon(event_data.target);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
-
-        match event_data.flag {
-            Flag::Confusion { .. } => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} snapped out of its confusion!", display_name),
-                }));
-            },
-            Flag::Flinch => unreachable!(),
-            Flag::StatStages(_) => unreachable!(),
-        }
 
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
-    }
-
-    fn handle_non_volatile_status_condition(&mut self, event_data: NonVolatileStatusCondition) {
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
-        let pokemon = backend.get_pokemon(event_data.target);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
-
-        match event_data.condition {
-            StatusCondition::Burn => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} got a burn!", display_name),
-                }));
-            },
-            StatusCondition::Poison => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} was poisoned!", display_name),
-                }));
-            },
-            StatusCondition::Paralysis => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} has become paralyzed!", display_name),
-                }));
-            },
-            StatusCondition::Freeze => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} was frozen solid!", display_name),
-                }));
-            },
-            StatusCondition::Toxic { .. } => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} was badly poisoned!", display_name),
-                }));
-            },
-            StatusCondition::Sleep { .. } => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} fell asleep!", display_name),
-                }));
-            },
+        for event in turn.frontend_events {
+            backend.push_frontend_event(event);
         }
 
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
-    }
+        self.event_queue.extend(backend.tick());
 
-    fn handle_expired_non_volatile_status_condition(
-        &mut self,
-        event_data: ExpiredNonVolatileStatusCondition,
-    ) {
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
-        let pokemon = backend.get_pokemon(event_data.target);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-        let mut animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> = Vec::new();
-
-        match event_data.condition {
-            SimpleStatusCondition::Burn => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} is no longer burned!", display_name),
-                }));
-            },
-            SimpleStatusCondition::Freeze => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} thawed out!", display_name),
-                }));
-            },
-            SimpleStatusCondition::Paralysis => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} is no longer paralyzed!", display_name),
-                }));
-            },
-            SimpleStatusCondition::Poison | SimpleStatusCondition::Toxic => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} is no longer poisoned!", display_name),
-                }));
-            },
-            SimpleStatusCondition::Sleep => {
-                animations.push(Box::new(TextAnimation::PendingStart {
-                    text: format!("{} woke up!", display_name),
-                }));
-            },
+        if !self.event_queue.is_empty() {
+            self.handle_next_backend_event(system_data);
         }
-
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
-    }
-
-    fn handle_failed_move(&mut self) {
-        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
-            vec![Box::new(TextAnimation::PendingStart {
-                text: "But it failed!".to_string(),
-            })];
-
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
-    }
-
-    fn handle_faint(&mut self, event_data: Faint) {
-        let pokedex = get_all_pokemon_species();
-        let backend = self.backend.as_mut().unwrap();
-        let pokemon = backend.get_pokemon(event_data.target);
-        let display_name = get_pokemon_display_name(&pokemon, &pokedex);
-
-        let animations: Vec<Box<dyn FrontendAnimation + Sync + Send>> =
-            vec![Box::new(TextAnimation::PendingStart {
-                text: format!("{} fainted!", display_name),
-            })];
-
-        self.active_animation_sequence = Some(AnimationSequence {
-            animations: animations.into(),
-        });
     }
 
     fn push_action_selection_event(&mut self, system_data: &mut BattleSystemData<'_>) {
@@ -686,17 +356,78 @@ impl<'a> System<'a> for BattleSystem {
         if self.active_animation_sequence.is_none() {
             if self.event_queue.is_empty() {
                 match self.backend.as_mut() {
-                    Some(_) => {
+                    Some(_) if self.playback.is_some() => {
+                        self.play_next_replay_turn(&mut system_data);
+                    },
+                    Some(backend) if self.pending_turn.is_some() => {
+                        let opponent_ai = self.opponent_ai.as_mut().unwrap();
+
+                        // The AI thinks on another thread, so the turn waits
+                        // for it instead of stalling the frame
+                        if let Some(opponent_event) = opponent_ai.poll() {
+                            let mut turn_events = self.pending_turn.take().unwrap();
+                            turn_events.push(opponent_event);
+
+                            let recorder = self.recorder.as_mut();
+                            let log = self.log.as_mut();
+                            run_turn(backend, recorder, log, &mut self.event_queue, turn_events);
+                        }
+                    },
+                    Some(backend) if backend.is_locked(Team::P1) => {
+                        // The backend picks the action of a Pokémon that is
+                        // locked into a move, so there's nothing to select
+                        self.opponent_ai.as_mut().unwrap().start(backend);
+                        self.pending_turn = Some(Vec::new());
+                    },
+                    Some(backend) => {
+                        // The opponent chooses its action while the player
+                        // does
+                        self.opponent_ai.as_mut().unwrap().start(backend);
                         self.push_action_selection_event(&mut system_data);
                         self.start_animation(&mut system_data);
                     },
                     None => {
                         let mut backend = BattleBackend::new(
                             system_data.battle.clone(),
-                            Box::new(StandardBattleRng::default()),
+                            Box::new(StandardBattleRng::new(system_data.battle.seed)),
                         );
-
-                        self.event_queue.extend(backend.tick());
+                        let battle_events: Vec<_> = backend.tick().collect();
+
+                        match self.playback.as_mut() {
+                            Some(playback) => {
+                                // The first turn only sends out the Pokémon
+                                playback.pop_front();
+                            },
+                            None => {
+                                let game_config = &system_data.game_config;
+                                let mut recorder = if game_config.record_replays {
+                                    create_replay(&system_data.battle)
+                                } else {
+                                    None
+                                };
+
+                                if let Some(recorder) = recorder.as_mut() {
+                                    write_replay_turn(recorder, &[], &battle_events);
+                                }
+
+                                self.recorder = recorder;
+
+                                let mut log = if game_config.write_battle_logs {
+                                    create_log(&system_data.battle)
+                                } else {
+                                    None
+                                };
+
+                                if let Some(log) = log.as_mut() {
+                                    write_log_turn(log, &backend, &[], &battle_events);
+                                }
+
+                                self.log = log;
+                            },
+                        }
+
+                        self.event_queue.extend(battle_events);
+                        self.opponent_ai = Some(create_opponent_ai(&system_data.battle));
                         self.backend = Some(backend);
                         self.handle_next_backend_event(&mut system_data);
                     },
@@ -709,3 +440,91 @@ impl<'a> System<'a> for BattleSystem {
         self.tick(&mut system_data);
     }
 }
+
+/// Creates the AI of the opponent. Trainers search for their best action,
+/// while wild Pokémon use random moves.
+fn create_opponent_ai(battle: &Battle) -> BackgroundAi {
+    let ai: Box<dyn BattleAi + Send> = match battle.p2.character_id {
+        Some(_) => Box::new(ExpertAi::new(battle.seed)),
+        None => Box::new(RandomAi::new(battle.seed)),
+    };
+
+    BackgroundAi::new(ai, Team::P2)
+}
+
+/// Sends the actions of both teams to the backend, queueing the resulting
+/// events.
+fn run_turn(
+    backend: &mut BattleBackend,
+    recorder: Option<&mut ReplayFile>,
+    log: Option<&mut BattleLogFile>,
+    event_queue: &mut VecDeque<BattleEvent>,
+    turn_events: Vec<FrontendEvent>,
+) {
+    for event in &turn_events {
+        backend.push_frontend_event(event.clone());
+    }
+
+    let battle_events: Vec<_> = backend.tick().collect();
+
+    if let Some(log) = log {
+        write_log_turn(log, backend, &turn_events, &battle_events);
+    }
+
+    if let Some(recorder) = recorder {
+        write_replay_turn(recorder, &turn_events, &battle_events);
+    }
+
+    event_queue.extend(battle_events);
+}
+
+fn create_replay(battle: &Battle) -> Option<ReplayFile> {
+    let path = application_root_dir()
+        .unwrap()
+        .join("replays")
+        .join(format!("battle_{}.ron", battle.seed));
+
+    match ReplayFile::create(&path, battle) {
+        Ok(replay) => Some(replay),
+        Err(err) => {
+            eprintln!("Failed to create the replay of this battle: {}", err);
+            None
+        },
+    }
+}
+
+fn write_replay_turn(
+    replay: &mut ReplayFile,
+    turn_events: &[FrontendEvent],
+    battle_events: &[BattleEvent],
+) {
+    if let Err(err) = replay.write_turn(turn_events, battle_events) {
+        eprintln!("Failed to write the replay of this battle: {}", err);
+    }
+}
+
+fn create_log(battle: &Battle) -> Option<BattleLogFile> {
+    let path = application_root_dir()
+        .unwrap()
+        .join("logs")
+        .join(format!("battle_{}.log", battle.seed));
+
+    match BattleLogFile::create(&path) {
+        Ok(log) => Some(log),
+        Err(err) => {
+            eprintln!("Failed to create the log of this battle: {}", err);
+            None
+        },
+    }
+}
+
+fn write_log_turn(
+    log: &mut BattleLogFile,
+    backend: &BattleBackend,
+    turn_events: &[FrontendEvent],
+    battle_events: &[BattleEvent],
+) {
+    if let Err(err) = log.write_turn(backend, turn_events, battle_events) {
+        eprintln!("Failed to write the log of this battle: {}", err);
+    }
+}
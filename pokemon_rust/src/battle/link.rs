@@ -0,0 +1,790 @@
+//! Link battles between two players over a local network. A host process
+//! runs the backend, while each player connects to it over TCP, sends its
+//! team and actions and receives the resulting `BattleEvent`s.
+//!
+//! Messages are RON values prefixed by their length as a big-endian `u32`.
+//! Every client sees the battle from its own perspective: its team is always
+//! P1, its Pokémon come first, and the details of the opposing team that
+//! haven't been revealed are hidden.
+
+use ron::{
+    de::{from_str, Error as DeserializationError},
+    ser::{to_string, Error as SerializationError},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{Error as IoError, ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    time::{Duration, Instant},
+};
+
+use crate::pokemon::{
+    generator::PokemonBuilder,
+    get_all_moves,
+    get_all_pokemon_species,
+    validation::validate_team_for_ruleset,
+    Nature,
+    Pokemon,
+    StatusCondition,
+    VolatileStatus,
+};
+
+use super::{
+    ai::get_available_moves,
+    backend::{
+        event,
+        rng::StandardBattleRng,
+        BattleBackend,
+        BattleEvent,
+        FrontendEvent,
+        FrontendEventKind,
+        HealCause,
+        Team,
+    },
+    rules::Ruleset,
+    simulation::create_trainer_battle,
+};
+
+/// The version of the protocol. Hosts reject clients with another version.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+pub const DEFAULT_PORT: u16 = 7447;
+
+/// The largest message that can be received, in bytes.
+const MAX_MESSAGE_SIZE: usize = 1 << 20;
+
+/// A message from a client to the host.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum ClientMessage {
+    /// The first message of a client, along with the team that it brings.
+    Hello { version: u32, team: Vec<Pokemon> },
+    /// The action of the client for the next turn.
+    Action(FrontendEventKind),
+}
+
+/// A message from the host to a client, from the perspective of the client.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum ServerMessage {
+    /// Sent once both players joined. The Pokémon of the client come first,
+    /// followed by the redacted Pokémon of the opponent.
+    Welcome {
+        version: u32,
+        own_team: Vec<Pokemon>,
+        opponent_team: Vec<Pokemon>,
+    },
+    /// The host refused the client, e.g because of its protocol version.
+    Rejected { reason: String },
+    Events(Vec<BattleEvent>),
+    /// The host is waiting for the action of the client.
+    ChooseAction,
+    BattleOver(LinkOutcome),
+}
+
+/// How a link battle ended for a client.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum LinkOutcome {
+    Won,
+    Lost,
+    Draw,
+    /// The opponent disconnected or didn't choose its action in time.
+    OpponentForfeited,
+}
+
+/// How a link battle ended for the host.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LinkResult {
+    /// The team that won the battle, if any.
+    pub winner: Option<Team>,
+    pub turns: usize,
+    /// Whether the battle ended because a player disconnected or ran out of
+    /// time.
+    pub forfeit: bool,
+}
+
+/// The settings of a hosted link battle.
+#[derive(Clone, Debug)]
+pub struct LinkConfig {
+    pub seed: u64,
+    pub ruleset: Ruleset,
+    /// How long the players have to join and to choose each action.
+    pub turn_timeout: Duration,
+    /// Turns after which the battle is a draw.
+    pub max_turns: usize,
+}
+
+impl Default for LinkConfig {
+    fn default() -> LinkConfig {
+        LinkConfig {
+            seed: 0,
+            ruleset: Ruleset::default(),
+            turn_timeout: Duration::from_secs(60),
+            max_turns: 500,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum LinkError {
+    Io(IoError),
+    Serialization(SerializationError),
+    Deserialization(DeserializationError),
+    /// The other side speaks another version of the protocol.
+    VersionMismatch { expected: u32, actual: u32 },
+    /// The host refused the client.
+    Rejected(String),
+    UnexpectedMessage,
+    MessageTooLarge(usize),
+}
+
+impl LinkError {
+    /// Checks if this error means that the other side didn't answer in time.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            LinkError::Io(error) => match error.kind() {
+                ErrorKind::TimedOut | ErrorKind::WouldBlock => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl From<IoError> for LinkError {
+    fn from(error: IoError) -> LinkError {
+        LinkError::Io(error)
+    }
+}
+
+impl From<SerializationError> for LinkError {
+    fn from(error: SerializationError) -> LinkError {
+        LinkError::Serialization(error)
+    }
+}
+
+impl From<DeserializationError> for LinkError {
+    fn from(error: DeserializationError) -> LinkError {
+        LinkError::Deserialization(error)
+    }
+}
+
+impl Display for LinkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkError::Io(error) => write!(f, "{}", error),
+            LinkError::Serialization(error) => write!(f, "{}", error),
+            LinkError::Deserialization(error) => write!(f, "{}", error),
+            LinkError::VersionMismatch { expected, actual } => write!(
+                f,
+                "Protocol version mismatch: expected {}, got {}",
+                expected,
+                actual,
+            ),
+            LinkError::Rejected(reason) => write!(f, "Rejected by the host: {}", reason),
+            LinkError::UnexpectedMessage => write!(f, "Unexpected message"),
+            LinkError::MessageTooLarge(size) => write!(f, "Message too large: {} bytes", size),
+        }
+    }
+}
+
+/// Translates the teams and Pokémon indices of the backend into the view of
+/// one of the players, who always sees its team as P1 and its Pokémon first.
+#[derive(Clone, Copy, Debug)]
+pub struct Perspective {
+    team: Team,
+    p1_size: usize,
+    p2_size: usize,
+}
+
+impl Perspective {
+    pub fn new(team: Team, p1_size: usize, p2_size: usize) -> Perspective {
+        Perspective {
+            team,
+            p1_size,
+            p2_size,
+        }
+    }
+
+    pub fn get_team(&self, team: Team) -> Team {
+        match self.team {
+            Team::P1 => team,
+            Team::P2 => team.get_opponent(),
+        }
+    }
+
+    pub fn get_pokemon(&self, pokemon: usize) -> usize {
+        match self.team {
+            Team::P1 => pokemon,
+            Team::P2 if pokemon < self.p1_size => pokemon + self.p2_size,
+            Team::P2 => pokemon - self.p1_size,
+        }
+    }
+
+    pub fn get_event(&self, event: &BattleEvent) -> BattleEvent {
+        let pokemon = |index: usize| self.get_pokemon(index);
+
+        match event.clone() {
+            BattleEvent::InitialSwitchIn(data) => {
+                BattleEvent::InitialSwitchIn(event::InitialSwitchIn {
+                    team: self.get_team(data.team),
+                    pokemon: pokemon(data.pokemon),
+                    ..data
+                })
+            },
+            BattleEvent::ChangeTurn(data) => BattleEvent::ChangeTurn(data),
+            BattleEvent::UseMove(data) => BattleEvent::UseMove(event::UseMove {
+                move_user: pokemon(data.move_user),
+                ..data
+            }),
+            BattleEvent::Damage(data) => BattleEvent::Damage(event::Damage {
+                target: pokemon(data.target),
+                ..data
+            }),
+            BattleEvent::Heal(data) => BattleEvent::Heal(event::Heal {
+                target: pokemon(data.target),
+                cause: match data.cause {
+                    HealCause::Drain { drained } => HealCause::Drain {
+                        drained: pokemon(drained),
+                    },
+                    HealCause::LeechSeed { drained } => HealCause::LeechSeed {
+                        drained: pokemon(drained),
+                    },
+                },
+                ..data
+            }),
+            BattleEvent::Miss(data) => BattleEvent::Miss(event::Miss {
+                target: pokemon(data.target),
+                move_user: pokemon(data.move_user),
+            }),
+            BattleEvent::StatChange(data) => BattleEvent::StatChange(event::StatChange {
+                target: pokemon(data.target),
+                ..data
+            }),
+            BattleEvent::VolatileStatusCondition(data) => {
+                BattleEvent::VolatileStatusCondition(event::VolatileStatusCondition {
+                    target: pokemon(data.target),
+                    status: self.get_volatile_status(data.target, data.status),
+                })
+            },
+            BattleEvent::ExpiredVolatileStatusCondition(data) => {
+                BattleEvent::ExpiredVolatileStatusCondition(
+                    event::ExpiredVolatileStatusCondition {
+                        target: pokemon(data.target),
+                        status: self.get_volatile_status(data.target, data.status),
+                    },
+                )
+            },
+            BattleEvent::NonVolatileStatusCondition(data) => {
+                BattleEvent::NonVolatileStatusCondition(event::NonVolatileStatusCondition {
+                    target: pokemon(data.target),
+                    condition: if self.is_opponent(data.target) {
+                        redact_status_condition(data.condition)
+                    } else {
+                        data.condition
+                    },
+                })
+            },
+            BattleEvent::ExpiredNonVolatileStatusCondition(data) => {
+                BattleEvent::ExpiredNonVolatileStatusCondition(
+                    event::ExpiredNonVolatileStatusCondition {
+                        target: pokemon(data.target),
+                        ..data
+                    },
+                )
+            },
+            BattleEvent::SubstituteDamage(data) => {
+                BattleEvent::SubstituteDamage(event::SubstituteDamage {
+                    target: pokemon(data.target),
+                    ..data
+                })
+            },
+            BattleEvent::AddedSideCondition(data) => {
+                BattleEvent::AddedSideCondition(event::AddedSideCondition {
+                    team: self.get_team(data.team),
+                    ..data
+                })
+            },
+            BattleEvent::ExpiredSideCondition(data) => {
+                BattleEvent::ExpiredSideCondition(event::ExpiredSideCondition {
+                    team: self.get_team(data.team),
+                    ..data
+                })
+            },
+            BattleEvent::TypeChange(data) => BattleEvent::TypeChange(event::TypeChange {
+                target: pokemon(data.target),
+                ..data
+            }),
+            BattleEvent::Transformed(data) => BattleEvent::Transformed(event::Transformed {
+                target: pokemon(data.target),
+                into: pokemon(data.into),
+            }),
+            BattleEvent::FailedMove(data) => BattleEvent::FailedMove(event::FailedMove {
+                move_user: pokemon(data.move_user),
+            }),
+            BattleEvent::Faint(data) => BattleEvent::Faint(event::Faint {
+                target: pokemon(data.target),
+            }),
+        }
+    }
+
+    /// Checks if a Pokémon of the backend belongs to the opposing team of the
+    /// player.
+    fn is_opponent(&self, pokemon: usize) -> bool {
+        let own_size = match self.team {
+            Team::P1 => self.p1_size,
+            Team::P2 => self.p2_size,
+        };
+
+        self.get_pokemon(pokemon) >= own_size
+    }
+
+    fn get_volatile_status(&self, target: usize, status: VolatileStatus) -> VolatileStatus {
+        match status {
+            VolatileStatus::Binding { user, move_id } => VolatileStatus::Binding {
+                user: self.get_pokemon(user),
+                move_id,
+            },
+            VolatileStatus::LeechSeed { user } => VolatileStatus::LeechSeed {
+                user: self.get_pokemon(user),
+            },
+            VolatileStatus::Infatuation { user } => VolatileStatus::Infatuation {
+                user: self.get_pokemon(user),
+            },
+            VolatileStatus::Trapped { user } => VolatileStatus::Trapped {
+                user: self.get_pokemon(user),
+            },
+            // The HP of the substitute would reveal the max HP of its user
+            VolatileStatus::Substitute { .. } if self.is_opponent(target) => {
+                VolatileStatus::Substitute { hp: 0 }
+            },
+            status => status,
+        }
+    }
+}
+
+/// Hides how long an opposing Pokémon will stay asleep.
+fn redact_status_condition(condition: StatusCondition) -> StatusCondition {
+    match condition {
+        StatusCondition::Sleep { .. } => StatusCondition::Sleep { remaining_turns: 0 },
+        condition => condition,
+    }
+}
+
+/// Hides what a player can't know about an opposing Pokémon before the
+/// battle: its moves, item, ability, nature, EVs, IVs, stats other than HP
+/// and how long it will stay asleep. Hidden fields are replaced by neutral
+/// values.
+pub fn redact_pokemon(pokemon: &Pokemon) -> Pokemon {
+    let mut redacted = pokemon.clone();
+    let hp = pokemon.stats[0];
+
+    redacted.nature = Nature::Hardy;
+    redacted.held_item = None;
+    redacted.experience_points = 0;
+    redacted.ability = String::new();
+    redacted.evs = [0; 6];
+    redacted.natural_ivs = [0; 6];
+    redacted.obtained_ivs = [0; 6];
+    redacted.moves = Default::default();
+    redacted.pp = Default::default();
+    redacted.pp_ups = Default::default();
+    redacted.met_at_location = String::new();
+    redacted.met_at_level = 0;
+    redacted.stats = [hp, 0, 0, 0, 0, 0];
+    redacted.status_condition = pokemon.status_condition.map(redact_status_condition);
+
+    redacted
+}
+
+/// Rebuilds a Pokémon that a player brought from the fields that are checked
+/// by the validation, so that its stats, HP and PP are recomputed instead of
+/// trusted. The Pokémon is fully healed, without any status condition.
+fn rebuild_pokemon(pokemon: &Pokemon) -> Pokemon {
+    let species = get_all_pokemon_species().get_species(pokemon.species_id);
+
+    let mut builder = PokemonBuilder::default()
+        .with_nature(pokemon.nature)
+        .with_ability(pokemon.ability.clone())
+        .with_evs(pokemon.evs)
+        .with_ivs(pokemon.natural_ivs)
+        .with_moves(pokemon.moves)
+        .with_gender(pokemon.gender.clone());
+
+    if let Some(held_item) = &pokemon.held_item {
+        builder = builder.with_held_item(held_item.clone());
+    }
+
+    let mut rebuilt = builder.build(species, get_all_moves(), pokemon.level);
+    rebuilt.nickname = pokemon.nickname.clone();
+
+    rebuilt
+}
+
+/// A connection to the host of a link battle.
+pub struct LinkClient {
+    stream: TcpStream,
+    pub own_team: Vec<Pokemon>,
+    /// The redacted team of the opponent.
+    pub opponent_team: Vec<Pokemon>,
+    /// The battle as the client sees it, built from both teams and kept up
+    /// to date with the events that it receives.
+    view: BattleBackend,
+}
+
+impl LinkClient {
+    /// Joins a link battle with a team, waiting until the opponent joins as
+    /// well. `timeout` is how long the client waits for each message of the
+    /// host.
+    pub fn connect(
+        address: impl ToSocketAddrs,
+        team: Vec<Pokemon>,
+        timeout: Duration,
+    ) -> Result<LinkClient, LinkError> {
+        let mut stream = TcpStream::connect(address)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+
+        write_message(&mut stream, &ClientMessage::Hello {
+            version: PROTOCOL_VERSION,
+            team,
+        })?;
+
+        match read_message(&mut stream)? {
+            ServerMessage::Welcome {
+                version,
+                own_team,
+                opponent_team,
+            } => {
+                if version != PROTOCOL_VERSION {
+                    return Err(LinkError::VersionMismatch {
+                        expected: PROTOCOL_VERSION,
+                        actual: version,
+                    });
+                }
+
+                let battle = create_trainer_battle(own_team.clone(), opponent_team.clone(), 0);
+                let view = BattleBackend::new(battle, Box::new(StandardBattleRng::new(0)));
+
+                Ok(LinkClient {
+                    stream,
+                    own_team,
+                    opponent_team,
+                    view,
+                })
+            },
+            ServerMessage::Rejected { reason } => Err(LinkError::Rejected(reason)),
+            _ => Err(LinkError::UnexpectedMessage),
+        }
+    }
+
+    /// Waits for the next message of the host. Events are applied to the
+    /// view of the battle before they're returned.
+    pub fn receive(&mut self) -> Result<ServerMessage, LinkError> {
+        let message = read_message(&mut self.stream)?;
+
+        if let ServerMessage::Events(events) = &message {
+            for event in events {
+                self.view.mirror_event(event);
+            }
+        }
+
+        Ok(message)
+    }
+
+    /// Returns the battle as the client sees it, e.g to describe its events
+    /// with `get_event_messages`. The opposing Pokémon only have the details
+    /// that were revealed.
+    pub fn get_view(&self) -> &BattleBackend {
+        &self.view
+    }
+
+    pub fn send_action(&mut self, action: FrontendEventKind) -> Result<(), LinkError> {
+        write_message(&mut self.stream, &ClientMessage::Action(action))
+    }
+}
+
+struct Player {
+    team: Team,
+    stream: TcpStream,
+    perspective: Perspective,
+}
+
+/// Waits for two players to join and hosts a battle between them. The first
+/// player that joins is P1.
+///
+/// Errors are only returned if the first player can't join. Opponents that
+/// can't join are rejected while P1 keeps waiting for a valid one. Once the
+/// battle starts, a player that disconnects or runs out of time forfeits.
+pub fn host_battle(listener: &TcpListener, config: &LinkConfig) -> Result<LinkResult, LinkError> {
+    let (p1_stream, p1_team) = join_player(listener.accept()?.0, config)?;
+    let (p2_stream, p2_team) = loop {
+        match join_player(listener.accept()?.0, config) {
+            Ok(player) => break player,
+            Err(error) => {
+                eprintln!("Rejected a player that tried to join as the opponent.");
+                eprintln!("Error message: {}", error);
+            },
+        }
+    };
+    let (p1_size, p2_size) = (p1_team.len(), p2_team.len());
+
+    let mut players = [
+        Player {
+            team: Team::P1,
+            stream: p1_stream,
+            perspective: Perspective::new(Team::P1, p1_size, p2_size),
+        },
+        Player {
+            team: Team::P2,
+            stream: p2_stream,
+            perspective: Perspective::new(Team::P2, p1_size, p2_size),
+        },
+    ];
+
+    let welcome_messages = [
+        ServerMessage::Welcome {
+            version: PROTOCOL_VERSION,
+            own_team: p1_team.clone(),
+            opponent_team: p2_team.iter().map(redact_pokemon).collect(),
+        },
+        ServerMessage::Welcome {
+            version: PROTOCOL_VERSION,
+            own_team: p2_team.clone(),
+            opponent_team: p1_team.iter().map(redact_pokemon).collect(),
+        },
+    ];
+
+    for (index, message) in welcome_messages.iter().enumerate() {
+        if write_message(&mut players[index].stream, message).is_err() {
+            return Ok(forfeit(&mut players, index, 0));
+        }
+    }
+
+    let battle = create_trainer_battle(p1_team, p2_team, config.seed)
+        .with_ruleset(config.ruleset.clone());
+    let rng = StandardBattleRng::new(battle.seed);
+    let mut backend = BattleBackend::new(battle, Box::new(rng));
+
+    let events: Vec<BattleEvent> = backend.tick().collect();
+
+    if let Err(team) = send_events(&mut players, &events) {
+        return Ok(forfeit(&mut players, team as usize, 0));
+    }
+
+    for turn in 1..=config.max_turns {
+        // Both players choose their actions at the same time, and each one
+        // has its own time to do so from the moment it's asked
+        let mut deadlines = Vec::with_capacity(players.len());
+
+        for index in 0..players.len() {
+            if write_message(&mut players[index].stream, &ServerMessage::ChooseAction).is_err() {
+                return Ok(forfeit(&mut players, index, turn));
+            }
+
+            deadlines.push(Instant::now() + config.turn_timeout);
+        }
+
+        for index in 0..players.len() {
+            let player = &mut players[index];
+
+            match read_action(player, &backend, deadlines[index]) {
+                Ok(event) => backend.push_frontend_event(FrontendEvent {
+                    team: player.team,
+                    event,
+                }),
+                Err(_) => return Ok(forfeit(&mut players, index, turn)),
+            }
+        }
+
+        let events: Vec<BattleEvent> = backend.tick().collect();
+
+        if let Err(team) = send_events(&mut players, &events) {
+            return Ok(forfeit(&mut players, team as usize, turn));
+        }
+
+        let fainted_teams: Vec<Team> = events
+            .iter()
+            .filter_map(|event| match event {
+                BattleEvent::Faint(data) => Some(backend.get_pokemon_team(data.target)),
+                _ => None,
+            })
+            .collect();
+
+        if !fainted_teams.is_empty() {
+            let winner = match (
+                fainted_teams.contains(&Team::P1),
+                fainted_teams.contains(&Team::P2),
+            ) {
+                (true, false) => Some(Team::P2),
+                (false, true) => Some(Team::P1),
+                _ => None,
+            };
+
+            return Ok(finish(&mut players, winner, turn));
+        }
+    }
+
+    Ok(finish(&mut players, None, config.max_turns))
+}
+
+/// Lets a player that connected join, checking its protocol version and its
+/// team. The Pokémon of the team are rebuilt, see `rebuild_pokemon`.
+fn join_player(
+    mut stream: TcpStream,
+    config: &LinkConfig,
+) -> Result<(TcpStream, Vec<Pokemon>), LinkError> {
+    stream.set_read_timeout(Some(config.turn_timeout))?;
+    stream.set_write_timeout(Some(config.turn_timeout))?;
+
+    let (version, team) = match read_message(&mut stream)? {
+        ClientMessage::Hello { version, team } => (version, team),
+        _ => return Err(LinkError::UnexpectedMessage),
+    };
+
+    let rejection = if version != PROTOCOL_VERSION {
+        Some(format!("the host uses version {} of the protocol", PROTOCOL_VERSION))
+    } else if team.is_empty() {
+        Some("the team is empty".to_string())
+    } else if !validate_team_for_ruleset(&team, &config.ruleset).is_empty() {
+        Some("the team is illegal under the ruleset of the battle".to_string())
+    } else {
+        None
+    };
+
+    if let Some(reason) = rejection {
+        write_message(&mut stream, &ServerMessage::Rejected {
+            reason: reason.clone(),
+        })?;
+
+        return Err(if version != PROTOCOL_VERSION {
+            LinkError::VersionMismatch {
+                expected: PROTOCOL_VERSION,
+                actual: version,
+            }
+        } else {
+            LinkError::Rejected(reason)
+        });
+    }
+
+    Ok((stream, team.iter().map(rebuild_pokemon).collect()))
+}
+
+/// Waits for a valid action of a player until the deadline. Invalid actions
+/// are answered by asking for another one.
+fn read_action(
+    player: &mut Player,
+    backend: &BattleBackend,
+    deadline: Instant,
+) -> Result<FrontendEventKind, LinkError> {
+    loop {
+        let now = Instant::now();
+
+        if now >= deadline {
+            return Err(LinkError::Io(ErrorKind::TimedOut.into()));
+        }
+
+        player.stream.set_read_timeout(Some(deadline - now))?;
+
+        match read_message(&mut player.stream)? {
+            ClientMessage::Action(FrontendEventKind::UseMove(index)) => {
+                let is_valid = backend.is_locked(player.team)
+                    || get_available_moves(backend, player.team)
+                        .iter()
+                        .any(|(move_index, _)| *move_index == index);
+
+                if is_valid {
+                    return Ok(FrontendEventKind::UseMove(index));
+                }
+
+                write_message(&mut player.stream, &ServerMessage::ChooseAction)?;
+            },
+            ClientMessage::Hello { .. } => return Err(LinkError::UnexpectedMessage),
+        }
+    }
+}
+
+/// Sends events to both players from their perspectives. Returns the team
+/// of the first player that couldn't receive them.
+fn send_events(players: &mut [Player; 2], events: &[BattleEvent]) -> Result<(), Team> {
+    for player in players.iter_mut() {
+        let events = events
+            .iter()
+            .map(|event| player.perspective.get_event(event))
+            .collect();
+
+        if write_message(&mut player.stream, &ServerMessage::Events(events)).is_err() {
+            return Err(player.team);
+        }
+    }
+
+    Ok(())
+}
+
+/// Ends the battle because a player left, making the other one win.
+fn forfeit(players: &mut [Player; 2], forfeiting_player: usize, turns: usize) -> LinkResult {
+    let winner = players[1 - forfeiting_player].team;
+    let message = ServerMessage::BattleOver(LinkOutcome::OpponentForfeited);
+
+    // The winner might have left as well, in which case there's nobody to
+    // tell
+    let _ = write_message(&mut players[1 - forfeiting_player].stream, &message);
+
+    LinkResult {
+        winner: Some(winner),
+        turns,
+        forfeit: true,
+    }
+}
+
+fn finish(players: &mut [Player; 2], winner: Option<Team>, turns: usize) -> LinkResult {
+    for player in players.iter_mut() {
+        let outcome = match winner {
+            Some(team) if team == player.team => LinkOutcome::Won,
+            Some(_) => LinkOutcome::Lost,
+            None => LinkOutcome::Draw,
+        };
+
+        let _ = write_message(&mut player.stream, &ServerMessage::BattleOver(outcome));
+    }
+
+    LinkResult {
+        winner,
+        turns,
+        forfeit: false,
+    }
+}
+
+pub(super) fn write_message<T: Serialize>(
+    stream: &mut TcpStream,
+    message: &T,
+) -> Result<(), LinkError> {
+    let content = to_string(message)?;
+    let length = content.len() as u32;
+
+    stream.write_all(&length.to_be_bytes())?;
+    stream.write_all(content.as_bytes())?;
+    stream.flush()?;
+
+    Ok(())
+}
+
+pub(super) fn read_message<T: DeserializeOwned>(stream: &mut TcpStream) -> Result<T, LinkError> {
+    let mut length = [0; 4];
+    stream.read_exact(&mut length)?;
+
+    let length = u32::from_be_bytes(length) as usize;
+
+    if length > MAX_MESSAGE_SIZE {
+        return Err(LinkError::MessageTooLarge(length));
+    }
+
+    let mut content = vec![0; length];
+    stream.read_exact(&mut content)?;
+
+    let content = String::from_utf8(content)
+        .map_err(|error| IoError::new(ErrorKind::InvalidData, error))?;
+
+    Ok(from_str(&content)?)
+}
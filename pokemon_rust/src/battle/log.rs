@@ -0,0 +1,168 @@
+//! Structured logs of battles, meant for external analysis and visualization
+//! tools. A log has one record per line, each in RON, containing an action
+//! that a team chose or an event that the backend produced, along with its
+//! turn and a digest of the state of the battle after that turn (see
+//! `BattleBackend::get_state_digest`).
+//!
+//! Unlike replays, logs are only written and read, never played back.
+
+use ron::{
+    de::{from_str, Error as DeserializationError},
+    ser::{to_string, Error as SerializationError},
+};
+
+use serde::{Deserialize, Serialize};
+
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::{self, File},
+    io::{BufRead, BufWriter, Error as IoError, Write},
+    path::Path,
+};
+
+use super::backend::{BattleBackend, BattleEvent, FrontendEvent};
+
+/// A single line of a battle log.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BattleLogRecord {
+    /// The turn of the event. Turn 0 only sends out the Pokémon.
+    pub turn: usize,
+    pub event: LoggedEvent,
+    /// The digest of the state of the battle at the end of the turn.
+    pub digest: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum LoggedEvent {
+    /// An action that was sent to the backend for this turn.
+    Action(FrontendEvent),
+    /// An event that the backend produced while processing this turn.
+    Battle(BattleEvent),
+}
+
+#[derive(Debug)]
+pub enum BattleLogError {
+    Io(IoError),
+    Serialization(SerializationError),
+    Deserialization {
+        line: usize,
+        error: DeserializationError,
+    },
+}
+
+impl From<IoError> for BattleLogError {
+    fn from(error: IoError) -> BattleLogError {
+        BattleLogError::Io(error)
+    }
+}
+
+impl From<SerializationError> for BattleLogError {
+    fn from(error: SerializationError) -> BattleLogError {
+        BattleLogError::Serialization(error)
+    }
+}
+
+impl Display for BattleLogError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BattleLogError::Io(error) => write!(f, "{}", error),
+            BattleLogError::Serialization(error) => write!(f, "{}", error),
+            BattleLogError::Deserialization { line, error } => {
+                write!(f, "line {}: {}", line, error)
+            },
+        }
+    }
+}
+
+/// Writes the turns of a battle as log records.
+pub struct BattleLogWriter<W: Write> {
+    writer: W,
+    turn: usize,
+}
+
+pub type BattleLogFile = BattleLogWriter<BufWriter<File>>;
+
+impl BattleLogFile {
+    /// Creates a log file, replacing any existing one.
+    pub fn create(path: impl AsRef<Path>) -> Result<BattleLogFile, BattleLogError> {
+        let path = path.as_ref();
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        Ok(BattleLogWriter::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> BattleLogWriter<W> {
+    /// Creates a writer for a battle that hasn't started yet.
+    pub fn new(writer: W) -> BattleLogWriter<W> {
+        BattleLogWriter { writer, turn: 0 }
+    }
+
+    /// Logs a call to `BattleBackend::tick()`, which must have already
+    /// happened, so that the digest reflects the end of the turn.
+    pub fn write_turn(
+        &mut self,
+        backend: &BattleBackend,
+        frontend_events: &[FrontendEvent],
+        battle_events: &[BattleEvent],
+    ) -> Result<(), BattleLogError> {
+        let digest = backend.get_state_digest();
+        let turn = self.turn;
+
+        let actions = frontend_events
+            .iter()
+            .map(|event| LoggedEvent::Action(event.clone()));
+        let events = battle_events
+            .iter()
+            .map(|event| LoggedEvent::Battle(event.clone()));
+
+        for event in actions.chain(events) {
+            if let LoggedEvent::Battle(BattleEvent::ChangeTurn(event_data)) = &event {
+                self.turn = event_data.new_turn;
+            }
+
+            let record = BattleLogRecord {
+                turn,
+                event,
+                digest,
+            };
+
+            writeln!(self.writer, "{}", to_string(&record)?)?;
+        }
+
+        // Keeps the log complete even if the game closes in the middle of the
+        // battle
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads the records of a battle log, skipping empty lines.
+pub fn read_log(reader: impl BufRead) -> Result<Vec<BattleLogRecord>, BattleLogError> {
+    let mut records = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record = from_str(&line).map_err(|error| BattleLogError::Deserialization {
+            line: index + 1,
+            error,
+        })?;
+
+        records.push(record);
+    }
+
+    Ok(records)
+}
@@ -0,0 +1,575 @@
+//! The messages that describe a battle to the player, such as "It's super
+//! effective!". Every frontend shows the events of the backend through these,
+//! so that they all word a battle the same way.
+
+use crate::{
+    i18n::Localization,
+    pokemon::{
+        get_all_moves,
+        get_all_pokemon_species,
+        get_side_condition_effect,
+        get_volatile_status_effect,
+        movement::MoveId,
+        PokemonType,
+        SideCondition,
+        SimpleStatusCondition,
+        SimpleVolatileStatus,
+        Stat,
+        StatusCondition,
+        VolatileStatus,
+    },
+};
+
+use super::backend::{
+    event::{
+        AddedSideCondition,
+        Damage,
+        ExpiredNonVolatileStatusCondition,
+        ExpiredSideCondition,
+        ExpiredVolatileStatusCondition,
+        Heal,
+        InitialSwitchIn,
+        NonVolatileStatusCondition,
+        StatChange,
+        TypeChange,
+        UseMove,
+        VolatileStatusCondition,
+    },
+    BattleBackend,
+    BattleEvent,
+    DamageCause,
+    HealCause,
+    StatChangeKind,
+    Team,
+    TypeEffectiveness,
+};
+
+/// Returns the messages that describe an event, in the order they should be
+/// shown. Some events have no message, e.g the damage of Leech Seed is
+/// explained by the message of the damage it causes.
+///
+/// Names are looked up in the current state of the backend, and the event
+/// that follows is needed to tell whether a move did nothing.
+pub fn get_event_messages(
+    backend: &BattleBackend,
+    event: &BattleEvent,
+    next_event: Option<&BattleEvent>,
+    localization: &Localization,
+) -> Vec<String> {
+    match event {
+        BattleEvent::InitialSwitchIn(event_data) => {
+            vec![get_initial_switch_in_message(backend, event_data, localization)]
+        },
+        BattleEvent::ChangeTurn(_) => Vec::new(),
+        BattleEvent::UseMove(event_data) => {
+            get_use_move_messages(backend, event_data, next_event, localization)
+        },
+        BattleEvent::Damage(event_data) => get_damage_messages(backend, event_data, localization),
+        BattleEvent::Heal(event_data) => get_heal_messages(backend, event_data, localization),
+        BattleEvent::Miss(event_data) => {
+            let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+            vec![localization.tr_with("battle.avoided", &[("pokemon", &display_name)])]
+        },
+        BattleEvent::StatChange(event_data) => {
+            vec![get_stat_change_message(backend, event_data, localization)]
+        },
+        BattleEvent::VolatileStatusCondition(event_data) => {
+            vec![get_volatile_status_condition_message(backend, event_data, localization)]
+        },
+        BattleEvent::ExpiredVolatileStatusCondition(event_data) => vec![
+            get_expired_volatile_status_condition_message(backend, event_data, localization),
+        ],
+        BattleEvent::NonVolatileStatusCondition(event_data) => {
+            vec![get_non_volatile_status_condition_message(backend, event_data, localization)]
+        },
+        BattleEvent::ExpiredNonVolatileStatusCondition(event_data) => vec![
+            get_expired_non_volatile_status_condition_message(backend, event_data, localization),
+        ],
+        BattleEvent::SubstituteDamage(event_data) => {
+            let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+            vec![localization.tr_with("battle.substitute_damaged", &[("pokemon", &display_name)])]
+        },
+        BattleEvent::AddedSideCondition(event_data) => {
+            vec![get_added_side_condition_message(event_data, localization)]
+        },
+        BattleEvent::ExpiredSideCondition(event_data) => {
+            vec![get_expired_side_condition_message(event_data, localization)]
+        },
+        BattleEvent::TypeChange(event_data) => {
+            vec![get_type_change_message(backend, event_data, localization)]
+        },
+        BattleEvent::Transformed(event_data) => {
+            let display_name = get_pokemon_name(backend, event_data.target, localization);
+            let copied_name = get_pokemon_name(backend, event_data.into, localization);
+
+            vec![localization.tr_with(
+                "battle.transformed",
+                &[("pokemon", &display_name), ("target", &copied_name)],
+            )]
+        },
+        BattleEvent::FailedMove(_) => vec![localization.tr("battle.failed")],
+        BattleEvent::Faint(event_data) => {
+            let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+            vec![localization.tr_with("battle.fainted", &[("pokemon", &display_name)])]
+        },
+    }
+}
+
+/// Returns how the messages of the battle refer to a team. The player always
+/// controls the first team.
+pub fn get_team_name(team: Team, localization: &Localization) -> String {
+    match team {
+        Team::P1 => localization.tr("battle.team.player"),
+        Team::P2 => localization.tr("battle.team.opponent"),
+    }
+}
+
+pub fn get_type_name(pokemon_type: PokemonType, localization: &Localization) -> String {
+    let key = match pokemon_type {
+        PokemonType::Normal => "type.normal",
+        PokemonType::Fight => "type.fight",
+        PokemonType::Flying => "type.flying",
+        PokemonType::Poison => "type.poison",
+        PokemonType::Ground => "type.ground",
+        PokemonType::Rock => "type.rock",
+        PokemonType::Bug => "type.bug",
+        PokemonType::Ghost => "type.ghost",
+        PokemonType::Steel => "type.steel",
+        PokemonType::Fire => "type.fire",
+        PokemonType::Water => "type.water",
+        PokemonType::Grass => "type.grass",
+        PokemonType::Electric => "type.electric",
+        PokemonType::Psychic => "type.psychic",
+        PokemonType::Ice => "type.ice",
+        PokemonType::Dragon => "type.dragon",
+        PokemonType::Dark => "type.dark",
+        PokemonType::Fairy => "type.fairy",
+    };
+
+    localization.tr(key)
+}
+
+/// Returns the name of a Pokémon of the battle, which is either its nickname
+/// or the name of its species.
+pub fn get_pokemon_name(
+    backend: &BattleBackend,
+    pokemon: usize,
+    localization: &Localization,
+) -> String {
+    localization.get_pokemon_name(backend.get_pokemon(pokemon), &get_all_pokemon_species())
+}
+
+/// Returns a line with the name, level and HP of a Pokémon of the battle.
+pub fn get_pokemon_summary(
+    backend: &BattleBackend,
+    pokemon: usize,
+    localization: &Localization,
+) -> String {
+    let level = backend.get_pokemon(pokemon).level.to_string();
+
+    localization.tr_with("battle_menu.pokemon_summary", &[
+        ("pokemon", &get_pokemon_name(backend, pokemon, localization)),
+        ("level", &localization.tr_with("battle.level", &[("level", &level)])),
+        ("hp", &backend.get_pokemon(pokemon).current_hp.to_string()),
+        ("max_hp", &backend.get_stat(pokemon, Stat::HP).to_string()),
+    ])
+}
+
+fn get_move_name(move_id: MoveId, localization: &Localization) -> String {
+    localization.get_move_name(get_all_moves().get_move(move_id))
+}
+
+fn get_initial_switch_in_message(
+    backend: &BattleBackend,
+    event_data: &InitialSwitchIn,
+    localization: &Localization,
+) -> String {
+    match event_data.team {
+        Team::P1 => {
+            let display_name = get_pokemon_name(backend, event_data.pokemon, localization);
+
+            localization.tr_with("battle.go", &[("pokemon", &display_name)])
+        },
+        Team::P2 => {
+            let pokedex = get_all_pokemon_species();
+            let pokemon = backend.get_pokemon(event_data.pokemon);
+            let species = pokedex.get_species(pokemon.species_id);
+            let species_name = localization.get_species_name(species);
+
+            localization.tr_with("battle.wild_appears", &[("pokemon", &species_name)])
+        },
+    }
+}
+
+fn get_use_move_messages(
+    backend: &BattleBackend,
+    event_data: &UseMove,
+    next_event: Option<&BattleEvent>,
+    localization: &Localization,
+) -> Vec<String> {
+    let display_name = get_pokemon_name(backend, event_data.move_user, localization);
+    let move_name = get_move_name(event_data.move_id, localization);
+
+    let mut messages = Vec::new();
+
+    if backend.has_volatile_status(event_data.move_user, SimpleVolatileStatus::Confusion) {
+        messages.push(localization.tr_with("battle.confused", &[("pokemon", &display_name)]));
+    }
+
+    messages.push(localization.tr_with(
+        "battle.used_move",
+        &[("pokemon", &display_name), ("move", &move_name)],
+    ));
+
+    match next_event {
+        Some(BattleEvent::UseMove(_)) | Some(BattleEvent::ChangeTurn(_)) => {
+            messages.push(localization.tr("battle.nothing_happened"));
+        },
+        _ => { },
+    }
+
+    messages
+}
+
+fn get_damage_messages(
+    backend: &BattleBackend,
+    event_data: &Damage,
+    localization: &Localization,
+) -> Vec<String> {
+    let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+    let mut messages = Vec::new();
+
+    let cause_key = match &event_data.cause {
+        DamageCause::Move | DamageCause::HpCost => None,
+        DamageCause::Burn => Some("battle.hurt_by_burn"),
+        DamageCause::Poison | DamageCause::Toxic => Some("battle.hurt_by_poison"),
+        DamageCause::Binding { move_id } => {
+            let move_name = get_move_name(*move_id, localization);
+
+            messages.push(localization.tr_with(
+                "battle.hurt_by_binding",
+                &[("pokemon", &display_name), ("move", &move_name)],
+            ));
+
+            None
+        },
+        DamageCause::Recoil => Some("battle.hurt_by_recoil"),
+        DamageCause::Confusion => Some("battle.hurt_by_confusion"),
+        DamageCause::LeechSeed => Some("battle.sapped_by_leech_seed"),
+        DamageCause::Curse => Some("battle.hurt_by_curse"),
+        DamageCause::Spikes => Some("battle.hurt_by_spikes"),
+        DamageCause::StealthRock => Some("battle.hurt_by_stealth_rock"),
+    };
+
+    if let Some(key) = cause_key {
+        messages.push(localization.tr_with(key, &[("pokemon", &display_name)]));
+    }
+
+    messages.push(match event_data.effectiveness {
+        TypeEffectiveness::Immune => {
+            localization.tr_with("battle.immune", &[("pokemon", &display_name)])
+        },
+        TypeEffectiveness::BarelyEffective => localization.tr("battle.barely_effective"),
+        TypeEffectiveness::NotVeryEffective => localization.tr("battle.not_very_effective"),
+        TypeEffectiveness::Normal => {
+            // TODO: remove this after health reduction becomes an animation
+            let amount = event_data.amount.to_string();
+            localization.tr_with("battle.damage", &[("amount", &amount)])
+        },
+        TypeEffectiveness::SuperEffective => localization.tr("battle.super_effective"),
+        TypeEffectiveness::ExtremelyEffective => localization.tr("battle.extremely_effective"),
+    });
+
+    if event_data.is_critical_hit {
+        messages.push(localization.tr("battle.critical_hit"));
+    }
+
+    if event_data.is_last_multi_hit_damage {
+        if let Some(index) = event_data.multi_hit_index {
+            let count = (index + 1).to_string();
+
+            messages.push(localization.tr_with("battle.multi_hit", &[("count", &count)]));
+        }
+    }
+
+    if event_data.is_ohko {
+        messages.push(localization.tr("battle.ohko"));
+    }
+
+    messages
+}
+
+fn get_heal_messages(
+    backend: &BattleBackend,
+    event_data: &Heal,
+    localization: &Localization,
+) -> Vec<String> {
+    match event_data.cause {
+        HealCause::Drain { drained } => {
+            let display_name = get_pokemon_name(backend, drained, localization);
+
+            vec![localization.tr_with("battle.drained", &[("pokemon", &display_name)])]
+        },
+        // The damage event of Leech Seed already explains what happened
+        HealCause::LeechSeed { .. } => Vec::new(),
+    }
+}
+
+fn get_stat_change_message(
+    backend: &BattleBackend,
+    event_data: &StatChange,
+    localization: &Localization,
+) -> String {
+    let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+    let stat = match event_data.stat {
+        Stat::HP => unreachable!(),
+        Stat::Attack => localization.tr("stat.attack"),
+        Stat::Defense => localization.tr("stat.defense"),
+        Stat::SpecialAttack => localization.tr("stat.special_attack"),
+        Stat::SpecialDefense => localization.tr("stat.special_defense"),
+        Stat::Speed => localization.tr("stat.speed"),
+        Stat::Accuracy => localization.tr("stat.accuracy"),
+        Stat::Evasion => localization.tr("stat.evasion"),
+    };
+
+    let key = match event_data.kind {
+        StatChangeKind::WontGoAnyLower => "battle.stat_wont_go_lower",
+        StatChangeKind::SeverelyFell => "battle.stat_severely_fell",
+        StatChangeKind::HarshlyFell => "battle.stat_harshly_fell",
+        StatChangeKind::Fell => "battle.stat_fell",
+        StatChangeKind::Rose => "battle.stat_rose",
+        StatChangeKind::SharplyRose => "battle.stat_sharply_rose",
+        StatChangeKind::DrasticallyRose => "battle.stat_drastically_rose",
+        StatChangeKind::WontGoAnyHigher => "battle.stat_wont_go_higher",
+    };
+
+    localization.tr_with(key, &[("pokemon", &display_name), ("stat", &stat)])
+}
+
+fn get_volatile_status_condition_message(
+    backend: &BattleBackend,
+    event_data: &VolatileStatusCondition,
+    localization: &Localization,
+) -> String {
+    let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+    match &event_data.status {
+        VolatileStatus::Confusion => {
+            localization.tr_with("battle.became_confused", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Flinch => {
+            localization.tr_with("battle.flinched", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Charging { move_id, .. } => {
+            // Moves can have their own charging message, e.g "X flew up high!"
+            let move_key = format!("battle.charging.{}", move_id);
+            let key = match localization.get(&move_key) {
+                Some(_) => move_key.as_str(),
+                None => "battle.charging",
+            };
+
+            localization.tr_with(key, &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Binding { move_id, .. } => {
+            let move_name = get_move_name(*move_id, localization);
+
+            localization.tr_with(
+                "battle.bound",
+                &[("pokemon", &display_name), ("move", &move_name)],
+            )
+        },
+        VolatileStatus::LeechSeed { .. } => {
+            localization.tr_with("battle.seeded", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Disable { move_id } => {
+            let move_name = get_move_name(*move_id, localization);
+
+            localization.tr_with(
+                "battle.disabled",
+                &[("pokemon", &display_name), ("move", &move_name)],
+            )
+        },
+        VolatileStatus::Encore { .. } => {
+            localization.tr_with("battle.encored", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Taunt => {
+            localization.tr_with("battle.taunted", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Infatuation { .. } => {
+            localization.tr_with("battle.fell_in_love", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Substitute { .. } => {
+            localization.tr_with("battle.substitute", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::FocusEnergy => {
+            localization.tr_with("battle.getting_pumped", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Trapped { .. } => {
+            localization.tr_with("battle.cant_escape", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Curse => {
+            localization.tr_with("battle.cursed", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Recharging { .. } | VolatileStatus::Rampage { .. } => {
+            unreachable!()
+        },
+    }
+}
+
+fn get_expired_volatile_status_condition_message(
+    backend: &BattleBackend,
+    event_data: &ExpiredVolatileStatusCondition,
+    localization: &Localization,
+) -> String {
+    let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+    match &event_data.status {
+        VolatileStatus::Confusion => localization.tr_with(
+            "battle.snapped_out_of_confusion",
+            &[("pokemon", &display_name)],
+        ),
+        VolatileStatus::Recharging { .. } => {
+            localization.tr_with("battle.must_recharge", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Binding { move_id, .. } => {
+            let move_name = get_move_name(*move_id, localization);
+
+            localization.tr_with(
+                "battle.freed",
+                &[("pokemon", &display_name), ("move", &move_name)],
+            )
+        },
+        VolatileStatus::Disable { .. } => {
+            localization.tr_with("battle.disable_ended", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Encore { .. } => {
+            localization.tr_with("battle.encore_ended", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Taunt => {
+            localization.tr_with("battle.taunt_ended", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Substitute { .. } => {
+            localization.tr_with("battle.substitute_faded", &[("pokemon", &display_name)])
+        },
+        VolatileStatus::Flinch
+        | VolatileStatus::Charging { .. }
+        | VolatileStatus::Rampage { .. } => unreachable!(),
+        status => {
+            let effect = get_volatile_status_effect(SimpleVolatileStatus::from(status));
+            let status_name = localization.tr(effect.display_name);
+
+            localization.tr_with(
+                "battle.volatile_status_ended",
+                &[("pokemon", &display_name), ("status", &status_name)],
+            )
+        },
+    }
+}
+
+fn get_non_volatile_status_condition_message(
+    backend: &BattleBackend,
+    event_data: &NonVolatileStatusCondition,
+    localization: &Localization,
+) -> String {
+    let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+    let key = match event_data.condition {
+        StatusCondition::Burn => "battle.burned",
+        StatusCondition::Poison => "battle.poisoned",
+        StatusCondition::Paralysis => "battle.paralyzed",
+        StatusCondition::Freeze => "battle.frozen",
+        StatusCondition::Toxic { .. } => "battle.badly_poisoned",
+        StatusCondition::Sleep { .. } => "battle.fell_asleep",
+    };
+
+    localization.tr_with(key, &[("pokemon", &display_name)])
+}
+
+fn get_expired_non_volatile_status_condition_message(
+    backend: &BattleBackend,
+    event_data: &ExpiredNonVolatileStatusCondition,
+    localization: &Localization,
+) -> String {
+    let display_name = get_pokemon_name(backend, event_data.target, localization);
+
+    let key = match event_data.condition {
+        SimpleStatusCondition::Burn => "battle.burn_healed",
+        SimpleStatusCondition::Freeze => "battle.thawed",
+        SimpleStatusCondition::Paralysis => "battle.paralysis_healed",
+        SimpleStatusCondition::Poison | SimpleStatusCondition::Toxic => "battle.poison_healed",
+        SimpleStatusCondition::Sleep => "battle.woke_up",
+    };
+
+    localization.tr_with(key, &[("pokemon", &display_name)])
+}
+
+fn get_added_side_condition_message(
+    event_data: &AddedSideCondition,
+    localization: &Localization,
+) -> String {
+    let team_name = get_team_name(event_data.team, localization);
+
+    let key = match event_data.condition {
+        SideCondition::Reflect => "battle.reflect",
+        SideCondition::LightScreen => "battle.light_screen",
+        SideCondition::Safeguard => "battle.safeguard",
+        SideCondition::Mist => "battle.mist",
+        SideCondition::Tailwind => "battle.tailwind",
+        SideCondition::Spikes => "battle.spikes",
+        SideCondition::ToxicSpikes => "battle.toxic_spikes",
+        SideCondition::StealthRock => "battle.stealth_rock",
+    };
+
+    localization.tr_with(key, &[("team", &team_name)])
+}
+
+fn get_expired_side_condition_message(
+    event_data: &ExpiredSideCondition,
+    localization: &Localization,
+) -> String {
+    let team_name = get_team_name(event_data.team, localization);
+
+    let key = match event_data.condition {
+        SideCondition::Reflect => "battle.reflect_ended",
+        SideCondition::LightScreen => "battle.light_screen_ended",
+        SideCondition::Safeguard => "battle.safeguard_ended",
+        SideCondition::Mist => "battle.mist_ended",
+        SideCondition::Tailwind => "battle.tailwind_ended",
+        SideCondition::ToxicSpikes => "battle.toxic_spikes_ended",
+        condition => {
+            let effect = get_side_condition_effect(condition);
+            let condition_name = localization.tr(effect.display_name);
+
+            return localization.tr_with(
+                "battle.side_condition_ended",
+                &[("team", &team_name), ("condition", &condition_name)],
+            );
+        },
+    };
+
+    localization.tr_with(key, &[("team", &team_name)])
+}
+
+fn get_type_change_message(
+    backend: &BattleBackend,
+    event_data: &TypeChange,
+    localization: &Localization,
+) -> String {
+    let display_name = get_pokemon_name(backend, event_data.target, localization);
+    let type_names = event_data
+        .types
+        .iter()
+        .map(|pokemon_type| get_type_name(*pokemon_type, localization))
+        .collect::<Vec<_>>()
+        .join("/");
+
+    localization.tr_with(
+        "battle.type_changed",
+        &[("pokemon", &display_name), ("type", &type_names)],
+    )
+}
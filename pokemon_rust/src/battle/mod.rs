@@ -1,6 +1,13 @@
+pub mod ai;
 pub mod backend;
 pub mod battle_state;
 pub mod frontend;
+pub mod link;
+pub mod log;
+pub mod messages;
+pub mod replay;
+pub mod rules;
+pub mod simulation;
 pub mod types;
 
 #[cfg(test)]
@@ -0,0 +1,279 @@
+//! Recording and playback of battles. Since the backend is deterministic for
+//! a given RNG seed, a battle can be reproduced from its initial state, its
+//! seed and the `FrontendEvent`s that were sent to it, turn by turn.
+//!
+//! Replays also store the `BattleEvent`s that the backend produced when the
+//! battle was recorded, which allows them to be verified: if the backend
+//! produces different events for the same input, then a change in the
+//! battle mechanics affected the outcome of that battle.
+//!
+//! A replay file has one record per line, each in RON: the initial state of
+//! the battle followed by one `ReplayTurn` per turn. This allows turns to be
+//! appended to the file as they're played.
+
+use ron::{
+    de::{from_str, Error as DeserializationError},
+    ser::{to_string, Error as SerializationError},
+};
+
+use serde::{Deserialize, Serialize};
+
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::{self, File},
+    io::{BufRead, BufReader, BufWriter, Error as IoError, ErrorKind, Write},
+    path::Path,
+};
+
+use super::{
+    backend::{rng::StandardBattleRng, BattleBackend, BattleEvent, FrontendEvent},
+    types::Battle,
+};
+
+/// A recorded battle.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct BattleReplay {
+    /// The state of the battle before its first turn, including the seed of
+    /// its RNG.
+    pub battle: Battle,
+    pub turns: Vec<ReplayTurn>,
+}
+
+/// The input and output of a single call to `BattleBackend::tick()`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ReplayTurn {
+    /// The events that were sent to the backend before this turn was
+    /// processed. Empty for the first turn, which only sends out the Pokémon.
+    pub frontend_events: Vec<FrontendEvent>,
+    /// The events produced by the backend.
+    pub battle_events: Vec<BattleEvent>,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(IoError),
+    Serialization(SerializationError),
+    Deserialization(DeserializationError),
+}
+
+impl From<IoError> for ReplayError {
+    fn from(error: IoError) -> ReplayError {
+        ReplayError::Io(error)
+    }
+}
+
+impl From<SerializationError> for ReplayError {
+    fn from(error: SerializationError) -> ReplayError {
+        ReplayError::Serialization(error)
+    }
+}
+
+impl From<DeserializationError> for ReplayError {
+    fn from(error: DeserializationError) -> ReplayError {
+        ReplayError::Deserialization(error)
+    }
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Io(error) => write!(f, "{}", error),
+            ReplayError::Serialization(error) => write!(f, "{}", error),
+            ReplayError::Deserialization(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Describes the first divergence between a replay and the events that the
+/// backend currently produces for it.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ReplayMismatch {
+    pub turn: usize,
+    pub event_index: usize,
+    pub expected: Option<BattleEvent>,
+    pub actual: Option<BattleEvent>,
+}
+
+impl Display for ReplayMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let describe = |event: &Option<BattleEvent>| match event {
+            Some(event) => format!("{:?}", event),
+            None => "<no event>".to_string(),
+        };
+
+        write!(
+            f,
+            "turn {}, event {}:\n\texpected: {}\n\tactual: {}",
+            self.turn,
+            self.event_index,
+            describe(&self.expected),
+            describe(&self.actual),
+        )
+    }
+}
+
+impl BattleReplay {
+    /// Creates an empty replay for a battle that hasn't started yet.
+    pub fn new(battle: Battle) -> BattleReplay {
+        BattleReplay {
+            battle,
+            turns: Vec::new(),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<BattleReplay, ReplayError> {
+        BattleReplay::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a replay, skipping empty lines.
+    pub fn read(reader: impl BufRead) -> Result<BattleReplay, ReplayError> {
+        let mut lines = Vec::new();
+
+        for line in reader.lines() {
+            let line = line?;
+
+            if !line.trim().is_empty() {
+                lines.push(line);
+            }
+        }
+
+        let (battle, turns) = match lines.split_first() {
+            Some(split) => split,
+            None => {
+                let error = IoError::new(ErrorKind::UnexpectedEof, "empty replay file");
+                return Err(ReplayError::Io(error));
+            },
+        };
+
+        Ok(BattleReplay {
+            battle: from_str(battle)?,
+            turns: turns
+                .iter()
+                .map(|turn| from_str(turn))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Saves this replay, replacing any existing file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ReplayError> {
+        let mut file = ReplayFile::create(path, &self.battle)?;
+
+        for turn in &self.turns {
+            file.write_turn(&turn.frontend_events, &turn.battle_events)?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates a backend in the same state as the recorded one before its
+    /// first turn.
+    pub fn create_backend(&self) -> BattleBackend {
+        BattleBackend::new(
+            self.battle.clone(),
+            Box::new(StandardBattleRng::new(self.battle.seed)),
+        )
+    }
+
+    /// Appends a turn to this replay.
+    pub fn record_turn(
+        &mut self,
+        frontend_events: Vec<FrontendEvent>,
+        battle_events: &[BattleEvent],
+    ) {
+        self.turns.push(ReplayTurn {
+            frontend_events,
+            battle_events: battle_events.to_vec(),
+        });
+    }
+
+    /// Feeds this replay through a new backend, checking that it produces the
+    /// same events as the recorded one.
+    pub fn verify(&self) -> Result<(), ReplayMismatch> {
+        let mut backend = self.create_backend();
+
+        for (turn_index, turn) in self.turns.iter().enumerate() {
+            for event in &turn.frontend_events {
+                backend.push_frontend_event(event.clone());
+            }
+
+            let actual_events: Vec<BattleEvent> = backend.tick().collect();
+
+            let num_events = actual_events.len().max(turn.battle_events.len());
+
+            for event_index in 0..num_events {
+                let expected = turn.battle_events.get(event_index);
+                let actual = actual_events.get(event_index);
+
+                if expected != actual {
+                    return Err(ReplayMismatch {
+                        turn: turn_index,
+                        event_index,
+                        expected: expected.cloned(),
+                        actual: actual.cloned(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a replay as the battle is played, one turn at a time.
+pub struct ReplayWriter<W: Write> {
+    writer: W,
+}
+
+pub type ReplayFile = ReplayWriter<BufWriter<File>>;
+
+impl ReplayFile {
+    /// Creates a replay file, replacing any existing one.
+    pub fn create(path: impl AsRef<Path>, battle: &Battle) -> Result<ReplayFile, ReplayError> {
+        let path = path.as_ref();
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        ReplayWriter::new(BufWriter::new(File::create(path)?), battle)
+    }
+}
+
+impl<W: Write> ReplayWriter<W> {
+    /// Creates a writer for a battle that hasn't started yet, writing its
+    /// initial state.
+    pub fn new(writer: W, battle: &Battle) -> Result<ReplayWriter<W>, ReplayError> {
+        let mut replay_writer = ReplayWriter { writer };
+        replay_writer.write_line(battle)?;
+
+        Ok(replay_writer)
+    }
+
+    /// Appends a turn to the replay.
+    pub fn write_turn(
+        &mut self,
+        frontend_events: &[FrontendEvent],
+        battle_events: &[BattleEvent],
+    ) -> Result<(), ReplayError> {
+        let turn = ReplayTurn {
+            frontend_events: frontend_events.to_vec(),
+            battle_events: battle_events.to_vec(),
+        };
+
+        self.write_line(&turn)
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_line(&mut self, record: &impl Serialize) -> Result<(), ReplayError> {
+        writeln!(self.writer, "{}", to_string(record)?)?;
+
+        // Keeps the replay complete even if the game closes in the middle of
+        // the battle
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
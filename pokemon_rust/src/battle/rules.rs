@@ -0,0 +1,203 @@
+//! Rulesets, which decide the mechanics that a battle follows and the clauses
+//! that restrict the teams and moves allowed in it.
+
+use crate::pokemon::{
+    movement::{Move, MoveFlag, SimpleEffect, SimpleEffectTarget},
+    PokemonType,
+    Stat,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// The mechanics and clauses of a battle. The default ruleset follows the
+/// mechanics of this game and has no clauses.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Ruleset {
+    pub critical_hits: CriticalHitModel,
+    pub type_chart: TypeChart,
+    pub clauses: Vec<Clause>,
+}
+
+impl Default for Ruleset {
+    fn default() -> Ruleset {
+        Ruleset {
+            critical_hits: CriticalHitModel::Deterministic,
+            type_chart: TypeChart::Modern,
+            clauses: Vec::new(),
+        }
+    }
+}
+
+impl Ruleset {
+    /// A ruleset that mimics the first generation of games: chance-based
+    /// critical hits and the type chart of that time.
+    pub fn classic() -> Ruleset {
+        Ruleset {
+            critical_hits: CriticalHitModel::Chance,
+            type_chart: TypeChart::GenerationOne,
+            clauses: Vec::new(),
+        }
+    }
+
+    /// A ruleset for competitive battles: modern mechanics along with the
+    /// usual clauses.
+    pub fn standard() -> Ruleset {
+        Ruleset {
+            critical_hits: CriticalHitModel::Chance,
+            type_chart: TypeChart::Modern,
+            clauses: vec![
+                Clause::Sleep,
+                Clause::Species,
+                Clause::Ohko,
+                Clause::Evasion,
+                Clause::LevelCap(100),
+            ],
+        }
+    }
+
+    pub fn with_clause(mut self, clause: Clause) -> Ruleset {
+        self.clauses.push(clause);
+        self
+    }
+
+    pub fn has_clause(&self, clause: &Clause) -> bool {
+        self.clauses.contains(clause)
+    }
+
+    /// Returns the highest level allowed by the level cap clause, if any.
+    pub fn get_level_cap(&self) -> Option<usize> {
+        self.clauses
+            .iter()
+            .filter_map(|clause| match clause {
+                Clause::LevelCap(level) => Some(*level),
+                _ => None,
+            })
+            .min()
+    }
+
+    /// Checks if the clauses of this ruleset allow a move to be used.
+    pub fn allows_move(&self, movement: &Move) -> bool {
+        let is_banned_ohko = self.has_clause(&Clause::Ohko)
+            && movement.flags.contains(&MoveFlag::OneHitKO);
+        let is_banned_evasion = self.has_clause(&Clause::Evasion) && raises_evasion(movement);
+
+        !is_banned_ohko && !is_banned_evasion
+    }
+}
+
+/// Creates a ruleset by its name, which is one of "default", "classic" or
+/// "standard".
+pub fn create_ruleset(name: &str) -> Option<Ruleset> {
+    match name {
+        "default" => Some(Ruleset::default()),
+        "classic" => Some(Ruleset::classic()),
+        "standard" => Some(Ruleset::standard()),
+        _ => None,
+    }
+}
+
+/// Decides when moves land critical hits.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum CriticalHitModel {
+    /// A move either always or never crits, according to
+    /// `Move::critical_hit`. Focus Energy makes every move crit.
+    Deterministic,
+    /// Critical hits happen by chance. Moves with `Move::critical_hit` and
+    /// Focus Energy raise the critical hit stage, which raises the chance
+    /// from 1/24 up to a guaranteed critical hit.
+    Chance,
+}
+
+/// The type effectiveness table used by a battle.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum TypeChart {
+    /// The chart of the first generation, before the Steel, Dark and Fairy
+    /// types existed. It includes the famous mistake that makes Psychic
+    /// types immune to Ghost moves.
+    GenerationOne,
+    Modern,
+    /// The modern chart turned upside down, as in Inverse Battles: weaknesses
+    /// become resistances, and resistances and immunities become
+    /// weaknesses.
+    Inverse,
+}
+
+impl TypeChart {
+    /// Returns the type effectiveness between two types.
+    pub fn get_effectiveness(
+        self,
+        attacking_type: PokemonType,
+        defending_type: PokemonType,
+    ) -> f32 {
+        let modern_effectiveness = PokemonType::get_effectiveness(attacking_type, defending_type);
+
+        match self {
+            TypeChart::GenerationOne => {
+                get_generation_one_effectiveness(attacking_type, defending_type)
+                    .unwrap_or(modern_effectiveness)
+            },
+            TypeChart::Modern => modern_effectiveness,
+            TypeChart::Inverse => {
+                if modern_effectiveness > 1. {
+                    0.5
+                } else if modern_effectiveness < 1. {
+                    2.
+                } else {
+                    1.
+                }
+            },
+        }
+    }
+}
+
+/// Restrictions on the teams and moves of a battle.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Clause {
+    /// A team can't put more than one opposing Pokémon to sleep at a time.
+    Sleep,
+    /// A team can't have more than one Pokémon of the same species.
+    Species,
+    /// One-hit KO moves (e.g Fissure) are banned.
+    Ohko,
+    /// Moves that raise the evasion of the user (e.g Double Team) are banned.
+    Evasion,
+    /// Pokémon above this level are not allowed.
+    LevelCap(usize),
+}
+
+/// Returns the effectiveness between two types in the first generation, if it
+/// differs from the modern one.
+fn get_generation_one_effectiveness(
+    attacking_type: PokemonType,
+    defending_type: PokemonType,
+) -> Option<f32> {
+    let is_newer_type = |pokemon_type| match pokemon_type {
+        PokemonType::Steel | PokemonType::Dark | PokemonType::Fairy => true,
+        _ => false,
+    };
+
+    if is_newer_type(attacking_type) || is_newer_type(defending_type) {
+        return Some(1.);
+    }
+
+    match (attacking_type, defending_type) {
+        (PokemonType::Bug, PokemonType::Poison) | (PokemonType::Poison, PokemonType::Bug) => {
+            Some(2.)
+        },
+        (PokemonType::Ghost, PokemonType::Psychic) => Some(0.),
+        (PokemonType::Ice, PokemonType::Fire) => Some(1.),
+        _ => None,
+    }
+}
+
+fn raises_evasion(movement: &Move) -> bool {
+    match movement.secondary_effect.as_ref().map(|effect| &effect.effect) {
+        Some(SimpleEffect::StatChange {
+            changes,
+            target: SimpleEffectTarget::MoveUser,
+        }) => changes
+            .iter()
+            .any(|(stat, delta)| *stat == Stat::Evasion && *delta > 0),
+        _ => false,
+    }
+}
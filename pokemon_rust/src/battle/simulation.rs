@@ -0,0 +1,103 @@
+//! Headless battles between two AIs, used to evaluate the balance of moves
+//! and species without going through the frontend.
+
+use crate::{
+    overworld::entities::character::CharacterId,
+    pokemon::{movement::MoveId, Pokemon},
+};
+
+use super::{
+    ai::BattleAi,
+    backend::{rng::StandardBattleRng, BattleBackend, BattleEvent, Team},
+    types::{Battle, BattleCharacterTeam, BattleType, Party},
+};
+
+use std::collections::VecDeque;
+
+/// The outcome of a simulated battle.
+#[derive(Clone, Debug)]
+pub struct SimulationResult {
+    /// The team that won the battle. This is None if the battle ended in a
+    /// draw or reached the turn limit.
+    pub winner: Option<Team>,
+    pub turns: usize,
+    /// Every move that was used during the battle, in order.
+    pub used_moves: Vec<(Team, MoveId)>,
+}
+
+/// Creates a trainer battle between two teams.
+pub fn create_trainer_battle(p1: Vec<Pokemon>, p2: Vec<Pokemon>, seed: u64) -> Battle {
+    let create_team = |pokemon: Vec<Pokemon>, character_id| BattleCharacterTeam {
+        active_pokemon: None,
+        party: Party {
+            pokemon: VecDeque::from(pokemon),
+        },
+        character_id: Some(CharacterId(character_id)),
+    };
+
+    Battle::new(BattleType::Single, create_team(p1, 1), create_team(p2, 2)).with_seed(seed)
+}
+
+/// Runs a battle until one of the active Pokémon faints or `max_turns` turns
+/// have passed.
+///
+/// Since the backend doesn't support switching yet, only the first Pokémon of
+/// each team takes part in the battle.
+pub fn simulate_battle(
+    battle: Battle,
+    p1_ai: &mut dyn BattleAi,
+    p2_ai: &mut dyn BattleAi,
+    max_turns: usize,
+) -> SimulationResult {
+    let rng = StandardBattleRng::new(battle.seed);
+    let mut backend = BattleBackend::new(battle, Box::new(rng));
+    let mut used_moves = Vec::new();
+
+    backend.tick().for_each(drop);
+
+    for turn in 1..=max_turns {
+        let p1_event = p1_ai.choose_event(&backend, Team::P1);
+        let p2_event = p2_ai.choose_event(&backend, Team::P2);
+        backend.push_frontend_event(p1_event);
+        backend.push_frontend_event(p2_event);
+
+        let events: Vec<BattleEvent> = backend.tick().collect();
+        let mut fainted_teams = Vec::new();
+
+        for event in events {
+            match event {
+                BattleEvent::UseMove(event_data) => {
+                    let team = backend.get_pokemon_team(event_data.move_user);
+                    used_moves.push((team, event_data.move_id));
+                },
+                BattleEvent::Faint(event_data) => {
+                    fainted_teams.push(backend.get_pokemon_team(event_data.target));
+                },
+                _ => {},
+            }
+        }
+
+        if !fainted_teams.is_empty() {
+            let winner = match (
+                fainted_teams.contains(&Team::P1),
+                fainted_teams.contains(&Team::P2),
+            ) {
+                (true, false) => Some(Team::P2),
+                (false, true) => Some(Team::P1),
+                _ => None,
+            };
+
+            return SimulationResult {
+                winner,
+                turns: turn,
+                used_moves,
+            };
+        }
+    }
+
+    SimulationResult {
+        winner: None,
+        turns: max_turns,
+        used_moves,
+    }
+}
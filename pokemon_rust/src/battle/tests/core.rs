@@ -1,5 +1,5 @@
 use crate::{
-    battle::backend::{BattleEvent, StatChangeKind, Team, TypeEffectiveness},
+    battle::backend::{BattleEvent, DamageCause, StatChangeKind, Team, TypeEffectiveness},
     pokemon::SimpleStatusCondition,
 };
 
@@ -161,19 +161,19 @@ fn considers_stat_stages_on_damage_calculation() {
 }
 
 #[test]
-fn causes_random_misses_when_confused() {
+fn makes_confused_pokemon_randomly_hurt_themselves() {
     let mut backend = battle! {
         "Butterfree" 4 (max ivs, Serious) vs "Caterpie" 4 (max ivs, Serious)
     };
 
-    test_rng_mut!(backend.rng).force_confusion_miss(2);
+    test_rng_mut!(backend.rng).force_confusion_self_hit(2);
     let turn1 = backend.process_turn("Supersonic", "Tackle");
     let turn2 = backend.process_turn("Harden", "Tackle");
     let turn3 = backend.process_turn("Harden", "Tackle");
 
-    assert_event!(turn1[3], Miss { target: 0, move_user: 1, caused_by_confusion: true, .. });
-    assert_event!(turn2[3], Miss { target: 0, move_user: 1, caused_by_confusion: true, .. });
-    assert_event!(turn3[3], Damage { target: 0, .. });
+    assert_event!(turn1[3], Damage { target: 1, cause: DamageCause::Confusion, .. });
+    assert_event!(turn2[3], Damage { target: 1, cause: DamageCause::Confusion, .. });
+    assert_event!(turn3[3], Damage { target: 0, cause: DamageCause::Move, .. });
 }
 
 #[test]
@@ -188,11 +188,36 @@ fn makes_confusion_expire() {
 
     assert_event!(events[2], ExpiredVolatileStatusCondition {
         target: 1,
-        flag: Flag::Confusion { remaining_move_attempts: 0 },
+        status: VolatileStatus::Confusion,
         ..
     });
 }
 
+#[test]
+fn confusion_that_wears_off_cannot_cause_self_hits() {
+    let mut backend = battle! {
+        "Butterfree" 4 (max ivs, Serious) vs "Caterpie" 4 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_confusion_duration(1);
+    backend.process_turn("Supersonic", "Tackle");
+    test_rng_mut!(backend.rng).force_confusion_self_hit(1);
+    let events = backend.process_turn("Harden", "Tackle");
+
+    assert_event!(events[2], ExpiredVolatileStatusCondition {
+        target: 1,
+        status: VolatileStatus::Confusion,
+        ..
+    });
+    assert!(!events.iter().any(|event| match event {
+        BattleEvent::Damage(Damage {
+            cause: DamageCause::Confusion,
+            ..
+        }) => true,
+        _ => false,
+    }));
+}
+
 #[test]
 fn deals_damage_to_burned_pokemon() {
     let mut backend = battle! {
@@ -386,6 +411,26 @@ fn makes_freeze_expire() {
     });
 }
 
+#[test]
+fn removes_the_effects_of_expired_status_conditions() {
+    let mut backend = battle! {
+        "Hitmonchan" 24 (max ivs, Serious) vs "Metapod" 24 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+    test_rng_mut!(backend.rng).force_freeze_duration(1);
+    backend.process_turn("IcePunch", "Harden");
+    backend.process_turn("IcePunch", "Harden");
+
+    let events = backend.process_turn("IcePunch", "Harden");
+    assert!(events.iter().all(|event| match event {
+        BattleEvent::FailedMove(_) | BattleEvent::ExpiredNonVolatileStatusCondition(_) => false,
+        _ => true,
+    }));
+    assert_event!(events[3], StatChange { target: 1, stat: Stat::Defense, .. });
+}
+
 #[test]
 fn ice_types_cannot_be_frozen() {
     let mut backend = battle! {
@@ -399,3 +444,23 @@ fn ice_types_cannot_be_frozen() {
     assert_event!(events[1], Damage { target: 1, .. });
     assert_eq!(backend.get_pokemon(1).status_condition, None);
 }
+
+#[test]
+fn reproduces_battles_with_the_same_seed() {
+    let p1 = pokemon_setup!("Hitmonchan" 24 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 24 (max ivs, Serious));
+
+    let play = |seed| {
+        let mut backend = create_seeded_wild_battle(p1.clone(), p2.clone(), seed);
+        let mut events: Vec<_> = backend.tick().collect();
+
+        for _ in 0..3 {
+            events.extend(backend.process_turn("IcePunch", "Harden"));
+        }
+
+        events
+    };
+
+    assert_eq!(play(42), play(42));
+    assert_eq!(play(1337), play(1337));
+}
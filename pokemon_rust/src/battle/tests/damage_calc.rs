@@ -0,0 +1,184 @@
+use crate::battle::{
+    backend::{
+        damage_calc::{DamageCalcOptions, Weather},
+        BattleEvent,
+    },
+    rules::{CriticalHitModel, Ruleset},
+};
+
+use super::{prelude::*, TestMethods};
+
+fn calculate(backend: &BattleBackend, name: &str, options: DamageCalcOptions) -> Vec<usize> {
+    let movement = get_all_moves().get_move(move_id(name));
+
+    backend
+        .calculate_damage(0, 1, movement, &options)
+        .unwrap()
+        .rolls
+}
+
+#[test]
+fn calculates_sixteen_rolls_and_the_highest_matches_the_backend() {
+    let mut backend = battle! {
+        "Rattata" 5 (max ivs, Adamant) vs "Pidgey" 5 (max ivs, Adamant)
+    };
+
+    let rolls = calculate(&backend, "Tackle", DamageCalcOptions::default());
+    let events = backend.process_turn("Tackle", "Tackle");
+
+    assert_eq!(rolls.len(), 16);
+    assert!(rolls.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert_eq!(*rolls.last().unwrap(), 9);
+    assert_event!(events[1], Damage { target: 1, amount: 9, .. });
+}
+
+#[test]
+fn critical_hits_match_the_backend() {
+    let ruleset = Ruleset {
+        critical_hits: CriticalHitModel::Chance,
+        ..Ruleset::default()
+    };
+    let battle = create_wild_battle_data(
+        pokemon_setup!("Rattata" 5 (max ivs, Adamant)),
+        pokemon_setup!("Pidgey" 5 (max ivs, Adamant)),
+    )
+    .with_ruleset(ruleset);
+    let mut backend = BattleBackend::new(battle, Box::new(TestRng::default()));
+    let _ = backend.tick();
+
+    let options = DamageCalcOptions {
+        is_critical_hit: Some(true),
+        ..DamageCalcOptions::default()
+    };
+    let rolls = calculate(&backend, "Tackle", options);
+
+    test_rng_mut!(backend.rng).force_critical_hit(1);
+    let events = backend.process_turn("Tackle", "Tackle");
+
+    match &events[1] {
+        BattleEvent::Damage(damage) => {
+            assert!(damage.is_critical_hit);
+            assert_eq!(damage.amount, *rolls.last().unwrap());
+        },
+        event => panic!("Expected damage, got {:?}", event),
+    }
+}
+
+#[test]
+fn burns_halve_physical_damage() {
+    let backend = battle! {
+        "Rattata" 5 (max ivs, Adamant) vs "Pidgey" 5 (max ivs, Adamant)
+    };
+
+    let rolls = calculate(&backend, "Tackle", DamageCalcOptions::default());
+    let burned_rolls = calculate(&backend, "Tackle", DamageCalcOptions {
+        is_burned: Some(true),
+        ..DamageCalcOptions::default()
+    });
+
+    let halved_rolls: Vec<_> = rolls.iter().map(|damage| damage / 2).collect();
+    assert_eq!(burned_rolls, halved_rolls);
+}
+
+#[test]
+fn fixed_damage_ignores_burns_and_critical_hits() {
+    let backend = battle! {
+        "Machop" 40 (max ivs, Serious) vs "Metapod" 45 (max ivs, Serious)
+    };
+
+    let rolls = calculate(&backend, "SeismicToss", DamageCalcOptions {
+        is_critical_hit: Some(true),
+        is_burned: Some(true),
+        ..DamageCalcOptions::default()
+    });
+
+    assert_eq!(rolls, vec![40]);
+}
+
+#[test]
+fn screens_are_ignored_by_critical_hits() {
+    let backend = battle! {
+        "Rattata" 5 (max ivs, Adamant) vs "Pidgey" 5 (max ivs, Adamant)
+    };
+
+    let movement = get_all_moves().get_move(move_id("Tackle"));
+    let screened = backend
+        .calculate_damage(0, 1, movement, &DamageCalcOptions {
+            has_screens: Some(true),
+            ..DamageCalcOptions::default()
+        })
+        .unwrap();
+    let critical_hit = backend
+        .calculate_damage(0, 1, movement, &DamageCalcOptions {
+            is_critical_hit: Some(true),
+            has_screens: Some(true),
+            ..DamageCalcOptions::default()
+        })
+        .unwrap();
+
+    assert_eq!(screened.factors.screens, 0.5);
+    assert_eq!(critical_hit.factors.screens, 1.);
+    assert_eq!(critical_hit.factors.critical_hit, 1.25);
+}
+
+#[test]
+fn weather_boosts_or_weakens_fire_and_water_moves() {
+    let backend = battle! {
+        "Squirtle" 20 (max ivs, Serious) vs "Rattata" 20 (max ivs, Serious)
+    };
+
+    let calculate_weather = |name, weather| {
+        let movement = get_all_moves().get_move(move_id(name));
+
+        backend
+            .calculate_damage(0, 1, movement, &DamageCalcOptions {
+                weather,
+                ..DamageCalcOptions::default()
+            })
+            .unwrap()
+            .factors
+            .weather
+    };
+
+    assert_eq!(calculate_weather("WaterGun", None), 1.);
+    assert_eq!(calculate_weather("WaterGun", Some(Weather::Rain)), 1.5);
+    assert_eq!(calculate_weather("WaterGun", Some(Weather::Sun)), 0.5);
+    assert_eq!(calculate_weather("Tackle", Some(Weather::Rain)), 1.);
+}
+
+#[test]
+fn reports_percentages_and_ko_chance() {
+    let mut backend = battle! {
+        "Rattata" 5 (max ivs, Adamant) vs "Pidgey" 5 (max ivs, Adamant)
+    };
+
+    let movement = get_all_moves().get_move(move_id("Tackle"));
+    let range = backend
+        .calculate_damage(0, 1, movement, &DamageCalcOptions::default())
+        .unwrap();
+    let max_hp = backend.get_stat(1, Stat::HP);
+
+    assert_eq!(range.target_max_hp, max_hp);
+    assert_eq!(range.get_max_percentage(), 100. * 9. / max_hp as f32);
+    assert_eq!(range.get_ko_chance(), 0.);
+
+    backend.get_pokemon_mut(1).current_hp = range.get_max();
+    let range = backend
+        .calculate_damage(0, 1, movement, &DamageCalcOptions::default())
+        .unwrap();
+
+    assert!(range.get_ko_chance() > 0.);
+    assert!(range.get_ko_chance() <= 1.);
+}
+
+#[test]
+fn status_moves_deal_no_damage() {
+    let backend = battle!("Lapras" 25 vs "Metapod" 25);
+
+    let movement = get_all_moves().get_move(move_id("Harden"));
+
+    assert_eq!(
+        backend.calculate_damage(0, 1, movement, &DamageCalcOptions::default()),
+        None
+    );
+}
@@ -0,0 +1,304 @@
+use crate::{
+    battle::{
+        backend::{BattleEvent, FrontendEventKind, Team},
+        link::{
+            host_battle,
+            read_message,
+            write_message,
+            ClientMessage,
+            LinkClient,
+            LinkConfig,
+            LinkError,
+            LinkOutcome,
+            LinkResult,
+            Perspective,
+            ServerMessage,
+            PROTOCOL_VERSION,
+        },
+    },
+    pokemon::{Pokemon, StatusCondition},
+};
+
+use std::{
+    net::{SocketAddr, TcpListener, TcpStream},
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use super::prelude::*;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn start_host(config: LinkConfig) -> (SocketAddr, JoinHandle<Result<LinkResult, LinkError>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let host = thread::spawn(move || host_battle(&listener, &config));
+
+    (address, host)
+}
+
+/// Plays a battle that always uses the first move, returning the client, the
+/// events that it received and the outcome of the battle.
+fn play_first_moves(
+    address: SocketAddr,
+    team: Vec<Pokemon>,
+) -> (LinkClient, Vec<BattleEvent>, LinkOutcome) {
+    let mut client = LinkClient::connect(address, team, TIMEOUT).unwrap();
+    let mut events = Vec::new();
+
+    loop {
+        match client.receive().unwrap() {
+            ServerMessage::Events(new_events) => events.extend(new_events),
+            ServerMessage::ChooseAction => {
+                client.send_action(FrontendEventKind::UseMove(0)).unwrap()
+            },
+            ServerMessage::BattleOver(outcome) => return (client, events, outcome),
+            message => panic!("Unexpected message: {:?}", message),
+        }
+    }
+}
+
+#[test]
+fn hosts_battles_between_two_headless_clients() {
+    let (address, host) = start_host(LinkConfig::default());
+
+    let strong_team = vec![pokemon_setup!("Hitmonchan" 30 (max ivs, Adamant))];
+    let weak_team = vec![pokemon_setup!("Metapod" 5 (max ivs, Serious))];
+    let strong_client = thread::spawn(move || play_first_moves(address, strong_team));
+    let weak_client = thread::spawn(move || play_first_moves(address, weak_team));
+
+    let (strong_client, strong_events, strong_outcome) = strong_client.join().unwrap();
+    let (weak_client, weak_events, weak_outcome) = weak_client.join().unwrap();
+    let result = host.join().unwrap().unwrap();
+
+    assert_eq!(strong_outcome, LinkOutcome::Won);
+    assert_eq!(weak_outcome, LinkOutcome::Lost);
+    assert!(!result.forfeit);
+
+    // Both clients see their own Pokémon first, in the first team
+    for (client, events) in &[(&strong_client, &strong_events), (&weak_client, &weak_events)] {
+        let mut switch_ins: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                BattleEvent::InitialSwitchIn(data) => Some((data.team, data.pokemon)),
+                _ => None,
+            })
+            .collect();
+        switch_ins.sort_by_key(|(_, pokemon)| *pokemon);
+
+        assert_eq!(client.own_team.len(), 1);
+        assert_eq!(switch_ins, vec![(Team::P1, 0), (Team::P2, 1)]);
+    }
+
+    assert!(strong_events.contains(&BattleEvent::Faint(Faint { target: 1 })));
+    assert!(weak_events.contains(&BattleEvent::Faint(Faint { target: 0 })));
+
+    // The views of both clients follow the battle
+    assert_eq!(strong_client.get_view().get_pokemon(1).current_hp, 0);
+    assert_eq!(weak_client.get_view().get_pokemon(0).current_hp, 0);
+    assert_eq!(weak_client.get_view().get_active_pokemon_index(Team::P2), Some(1));
+}
+
+#[test]
+fn hides_the_details_of_the_opposing_team() {
+    let (address, host) = start_host(LinkConfig::default());
+
+    let own_team = vec![pokemon_setup!("Hitmonchan" 30 (max ivs, Adamant))];
+    let opposing_team = vec![pokemon_setup!("Metapod" 5 (max ivs, Adamant))];
+    let client = thread::spawn(move || play_first_moves(address, own_team));
+    let opposing_client = thread::spawn(move || play_first_moves(address, opposing_team));
+
+    let (client, _, _) = client.join().unwrap();
+    opposing_client.join().unwrap();
+    host.join().unwrap().unwrap();
+
+    let opponent = &client.opponent_team[0];
+
+    assert!(client.own_team[0].moves[0].is_some());
+    assert!(opponent.moves.iter().all(Option::is_none));
+    assert_eq!(opponent.natural_ivs, [0; 6]);
+    assert_eq!(opponent.nature, Nature::Hardy);
+    assert_eq!(opponent.stats[1..], [0; 5]);
+    assert!(opponent.stats[0] > 0);
+
+    // Events don't reveal how long the opponent sleeps or the HP of its
+    // substitute either
+    let perspective = Perspective::new(Team::P1, 1, 1);
+    let sleep = |target| {
+        BattleEvent::NonVolatileStatusCondition(NonVolatileStatusCondition {
+            target,
+            condition: StatusCondition::Sleep { remaining_turns: 3 },
+        })
+    };
+    let substitute = |target| {
+        BattleEvent::VolatileStatusCondition(VolatileStatusCondition {
+            target,
+            status: VolatileStatus::Substitute { hp: 20 },
+        })
+    };
+
+    assert_eq!(perspective.get_event(&sleep(0)), sleep(0));
+    assert_eq!(perspective.get_event(&substitute(0)), substitute(0));
+    assert_eq!(
+        perspective.get_event(&sleep(1)),
+        BattleEvent::NonVolatileStatusCondition(NonVolatileStatusCondition {
+            target: 1,
+            condition: StatusCondition::Sleep { remaining_turns: 0 },
+        })
+    );
+    assert_eq!(
+        perspective.get_event(&substitute(1)),
+        BattleEvent::VolatileStatusCondition(VolatileStatusCondition {
+            target: 1,
+            status: VolatileStatus::Substitute { hp: 0 },
+        })
+    );
+}
+
+#[test]
+fn recomputes_the_stats_hp_and_pp_of_the_teams() {
+    let (address, host) = start_host(LinkConfig {
+        max_turns: 1,
+        ..LinkConfig::default()
+    });
+
+    let honest_pokemon = pokemon_setup!("Metapod" 5 (max ivs, Serious));
+    let mut inflated_pokemon = honest_pokemon.clone();
+    inflated_pokemon.stats = [999; 6];
+    inflated_pokemon.current_hp = 999;
+    inflated_pokemon.pp = [99; 4];
+    inflated_pokemon.status_condition = Some(StatusCondition::Burn);
+
+    let inflated_team = vec![inflated_pokemon];
+    let opposing_team = vec![pokemon_setup!("Metapod" 5 (max ivs, Serious))];
+    let client = thread::spawn(move || play_first_moves(address, inflated_team));
+    let opposing_client = thread::spawn(move || play_first_moves(address, opposing_team));
+
+    let (client, _, _) = client.join().unwrap();
+    let (opposing_client, _, _) = opposing_client.join().unwrap();
+    host.join().unwrap().unwrap();
+
+    let rebuilt_pokemon = &client.own_team[0];
+
+    assert_eq!(rebuilt_pokemon.stats, honest_pokemon.stats);
+    assert_eq!(rebuilt_pokemon.current_hp, honest_pokemon.stats[0]);
+    assert_eq!(rebuilt_pokemon.pp, honest_pokemon.pp);
+    assert_eq!(rebuilt_pokemon.status_condition, None);
+    assert_eq!(opposing_client.opponent_team[0].stats[0], honest_pokemon.stats[0]);
+}
+
+#[test]
+fn rejects_clients_with_another_protocol_version() {
+    let (address, host) = start_host(LinkConfig::default());
+
+    let mut stream = TcpStream::connect(address).unwrap();
+    write_message(&mut stream, &ClientMessage::Hello {
+        version: PROTOCOL_VERSION + 1,
+        team: vec![pokemon_setup!("Metapod" 5)],
+    })
+    .unwrap();
+
+    match read_message(&mut stream).unwrap() {
+        ServerMessage::Rejected { .. } => {},
+        message => panic!("Unexpected message: {:?}", message),
+    }
+
+    match host.join().unwrap() {
+        Err(LinkError::VersionMismatch { actual, .. }) => assert_eq!(actual, PROTOCOL_VERSION + 1),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn keeps_waiting_for_an_opponent_after_rejecting_one() {
+    let (address, host) = start_host(LinkConfig::default());
+
+    // Join as P1 before the rejected client connects
+    let mut first_player = TcpStream::connect(address).unwrap();
+    first_player.set_read_timeout(Some(TIMEOUT)).unwrap();
+    write_message(&mut first_player, &ClientMessage::Hello {
+        version: PROTOCOL_VERSION,
+        team: vec![pokemon_setup!("Hitmonchan" 30 (max ivs, Adamant))],
+    })
+    .unwrap();
+
+    let mut rejected_player = TcpStream::connect(address).unwrap();
+    write_message(&mut rejected_player, &ClientMessage::Hello {
+        version: PROTOCOL_VERSION,
+        team: Vec::new(),
+    })
+    .unwrap();
+
+    match read_message(&mut rejected_player).unwrap() {
+        ServerMessage::Rejected { .. } => {},
+        message => panic!("Unexpected message: {:?}", message),
+    }
+
+    let opposing_team = vec![pokemon_setup!("Metapod" 5 (max ivs, Serious))];
+    let opponent = thread::spawn(move || play_first_moves(address, opposing_team));
+
+    let outcome = loop {
+        match read_message(&mut first_player).unwrap() {
+            ServerMessage::Welcome { .. } | ServerMessage::Events(_) => {},
+            ServerMessage::ChooseAction => write_message(
+                &mut first_player,
+                &ClientMessage::Action(FrontendEventKind::UseMove(0)),
+            )
+            .unwrap(),
+            ServerMessage::BattleOver(outcome) => break outcome,
+            message => panic!("Unexpected message: {:?}", message),
+        }
+    };
+    let (_, _, opponent_outcome) = opponent.join().unwrap();
+    let result = host.join().unwrap().unwrap();
+
+    assert_eq!(outcome, LinkOutcome::Won);
+    assert_eq!(opponent_outcome, LinkOutcome::Lost);
+    assert!(!result.forfeit);
+}
+
+#[test]
+fn players_that_disconnect_forfeit() {
+    let (address, host) = start_host(LinkConfig::default());
+
+    let staying_team = vec![pokemon_setup!("Metapod" 5 (max ivs, Serious))];
+    let leaving_team = vec![pokemon_setup!("Metapod" 5 (max ivs, Serious))];
+    let staying_client = thread::spawn(move || play_first_moves(address, staying_team));
+    let leaving_client = thread::spawn(move || {
+        LinkClient::connect(address, leaving_team, TIMEOUT).unwrap();
+    });
+
+    leaving_client.join().unwrap();
+    let (_, _, outcome) = staying_client.join().unwrap();
+    let result = host.join().unwrap().unwrap();
+
+    assert_eq!(outcome, LinkOutcome::OpponentForfeited);
+    assert!(result.forfeit);
+}
+
+#[test]
+fn players_that_run_out_of_time_forfeit() {
+    let (address, host) = start_host(LinkConfig {
+        turn_timeout: Duration::from_millis(200),
+        ..LinkConfig::default()
+    });
+
+    let active_team = vec![pokemon_setup!("Metapod" 5 (max ivs, Serious))];
+    let idle_team = vec![pokemon_setup!("Metapod" 5 (max ivs, Serious))];
+    let active_client = thread::spawn(move || play_first_moves(address, active_team));
+    let idle_client = thread::spawn(move || {
+        let mut client = LinkClient::connect(address, idle_team, TIMEOUT).unwrap();
+
+        // Receive messages without ever choosing an action, until the host
+        // closes the connection
+        while client.receive().is_ok() {}
+    });
+
+    let (_, _, outcome) = active_client.join().unwrap();
+    let result = host.join().unwrap().unwrap();
+
+    idle_client.join().unwrap();
+
+    assert_eq!(outcome, LinkOutcome::OpponentForfeited);
+    assert!(result.forfeit);
+}
@@ -0,0 +1,113 @@
+use crate::battle::{
+    backend::{BattleEvent, FrontendEvent, FrontendEventKind, Team},
+    log::{read_log, BattleLogRecord, BattleLogWriter, LoggedEvent},
+};
+
+use super::prelude::*;
+
+fn log_battle(seed: u64, num_turns: usize) -> String {
+    let p1 = pokemon_setup!("Hitmonchan" 24 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 24 (max ivs, Serious));
+    let mut backend = create_seeded_wild_battle(p1, p2, seed);
+    let mut writer = BattleLogWriter::new(Vec::new());
+
+    let events: Vec<BattleEvent> = backend.tick().collect();
+    writer.write_turn(&backend, &[], &events).unwrap();
+
+    for _ in 0..num_turns {
+        let frontend_events = vec![
+            FrontendEvent {
+                team: Team::P1,
+                event: FrontendEventKind::UseMove(0),
+            },
+            FrontendEvent {
+                team: Team::P2,
+                event: FrontendEventKind::UseMove(0),
+            },
+        ];
+
+        for event in &frontend_events {
+            backend.push_frontend_event(event.clone());
+        }
+
+        let events: Vec<BattleEvent> = backend.tick().collect();
+        writer.write_turn(&backend, &frontend_events, &events).unwrap();
+    }
+
+    String::from_utf8(writer.into_inner()).unwrap()
+}
+
+fn read_records(log: &str) -> Vec<BattleLogRecord> {
+    read_log(log.as_bytes()).unwrap()
+}
+
+#[test]
+fn logs_every_action_and_event_with_its_turn() {
+    let records = read_records(&log_battle(7, 2));
+
+    let is_turn_change = |record: &BattleLogRecord| match record.event {
+        LoggedEvent::Battle(BattleEvent::ChangeTurn(_)) => true,
+        _ => false,
+    };
+
+    assert_eq!(records.iter().filter(|record| is_turn_change(record)).count(), 3);
+    assert!(records.windows(2).all(|pair| pair[0].turn <= pair[1].turn));
+
+    for turn in 0..3 {
+        let turn_records: Vec<_> = records.iter().filter(|record| record.turn == turn).collect();
+        let num_actions = turn_records
+            .iter()
+            .filter(|record| match record.event {
+                LoggedEvent::Action(_) => true,
+                _ => false,
+            })
+            .count();
+
+        assert_eq!(num_actions, if turn == 0 { 0 } else { 2 });
+        assert!(is_turn_change(turn_records.last().unwrap()));
+        assert!(turn_records.iter().all(|record| record.digest == turn_records[0].digest));
+    }
+}
+
+#[test]
+fn digests_change_between_turns() {
+    let records = read_records(&log_battle(7, 2));
+    let mut digests: Vec<_> = records.iter().map(|record| record.digest).collect();
+    digests.dedup();
+
+    assert_eq!(digests.len(), 3);
+}
+
+#[test]
+fn logs_survive_blank_lines() {
+    let log = log_battle(7, 1);
+    let spaced_log = log.replace('\n', "\n\n");
+
+    assert_eq!(read_records(&spaced_log), read_records(&log));
+}
+
+#[test]
+fn rejects_malformed_records() {
+    let log = format!("{}Damage(oops)\n", log_battle(7, 0));
+    let num_lines = log.lines().count();
+
+    match read_log(log.as_bytes()) {
+        Err(error) => assert!(error.to_string().starts_with(&format!("line {}:", num_lines))),
+        Ok(_) => panic!("Expected the malformed record to be rejected"),
+    }
+}
+
+/// Changes in the battle mechanics or in the format of the logs show up as
+/// differences from this golden file. If they're intended, the file must be
+/// regenerated.
+#[test]
+fn matches_the_golden_log() {
+    let log = log_battle(7, 3);
+
+    assert!(
+        log == include_str!("golden/hitmonchan_vs_metapod.log"),
+        "The log differs from golden/hitmonchan_vs_metapod.log. If that's \
+         intended, replace its contents by:\n{}",
+        log,
+    );
+}
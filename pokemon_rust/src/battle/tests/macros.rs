@@ -23,6 +23,9 @@ macro_rules! assert_event {
     ($value:expr, Damage { $($args:tt)* }) => {
         assert_pattern!($value, BattleEvent::Damage(Damage { $($args)* }));
     };
+    ($value:expr, Heal { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::Heal(Heal { $($args)* }));
+    };
     ($value:expr, Miss { $($args:tt)* }) => {
         assert_pattern!($value, BattleEvent::Miss(Miss { $($args)* }));
     };
@@ -57,6 +60,24 @@ macro_rules! assert_event {
             })
         );
     };
+    ($value:expr, SubstituteDamage { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::SubstituteDamage(SubstituteDamage { $($args)* }));
+    };
+    ($value:expr, AddedSideCondition { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::AddedSideCondition(AddedSideCondition { $($args)* }));
+    };
+    ($value:expr, ExpiredSideCondition { $($args:tt)* }) => {
+        assert_pattern!(
+            $value,
+            BattleEvent::ExpiredSideCondition(ExpiredSideCondition { $($args)* })
+        );
+    };
+    ($value:expr, TypeChange { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::TypeChange(TypeChange { $($args)* }));
+    };
+    ($value:expr, Transformed { $($args:tt)* }) => {
+        assert_pattern!($value, BattleEvent::Transformed(Transformed { $($args)* }));
+    };
     ($value:expr, FailedMove { $($args:tt)* }) => {
         assert_pattern!($value, BattleEvent::FailedMove(FailedMove { $($args)* }));
     };
@@ -111,9 +132,10 @@ macro_rules! pokemon_setup {
 
             let pokedex = get_all_pokemon_species();
             let movedex = get_all_moves();
+            let species_id = pokedex.get_species_id($p1_species).unwrap();
 
             p1_builder.build(
-                &pokedex.get_species($p1_species).unwrap(),
+                pokedex.get_species(species_id),
                 &movedex,
                 $p1_level,
             )
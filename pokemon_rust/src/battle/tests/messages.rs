@@ -0,0 +1,100 @@
+use crate::{
+    battle::{
+        backend::BattleEvent,
+        messages::{get_event_messages, get_pokemon_summary},
+    },
+    i18n::Localization,
+};
+
+use super::{prelude::*, TestMethods};
+
+fn get_messages(backend: &BattleBackend, events: &[BattleEvent]) -> Vec<String> {
+    let localization = Localization::load("en");
+
+    events
+        .iter()
+        .enumerate()
+        .flat_map(|(index, event)| {
+            get_event_messages(backend, event, events.get(index + 1), &localization)
+        })
+        .collect()
+}
+
+#[test]
+fn describes_switch_ins() {
+    let mut backend = battle_setup!("Rattata" 3 vs "Pidgey" 3);
+    let events: Vec<_> = backend.tick().collect();
+
+    let messages = get_messages(&backend, &events);
+
+    assert_eq!(messages.len(), 2);
+    assert!(messages.contains(&"Go! Rattata!".to_string()));
+    assert!(messages.contains(&"A wild Pidgey appears!".to_string()));
+}
+
+#[test]
+fn summarizes_the_level_and_hp_of_pokemon() {
+    let mut backend = battle_setup!("Rattata" 3 vs "Pidgey" 3);
+    backend.tick().for_each(drop);
+    backend.get_pokemon_mut(1).current_hp = 5;
+
+    let localization = Localization::load("en");
+    let max_hp = backend.get_stat(1, Stat::HP);
+
+    assert_eq!(
+        get_pokemon_summary(&backend, 1, &localization),
+        format!("Pidgey Lv. 3  HP 5/{}", max_hp),
+    );
+}
+
+#[test]
+fn describes_moves_and_their_effectiveness() {
+    let mut backend = battle! {
+        "Pidgey" 10 (max ivs, Adamant) vs "Hitmonchan" 10 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Gust", "Tackle");
+
+    assert_eq!(
+        get_messages(&backend, &events),
+        vec![
+            "Hitmonchan used Tackle!",
+            "10 damage!",
+            "Pidgey used Gust!",
+            "It's super effective!",
+        ],
+    );
+}
+
+#[test]
+fn describes_stat_changes() {
+    let mut backend = battle! {
+        "Charmander" 1 (max ivs, Serious) vs "Metapod" 1 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Growl", "Harden");
+
+    let messages = get_messages(&backend, &events);
+
+    assert!(messages.contains(&"Metapod's attack fell!".to_string()));
+    assert!(messages.contains(&"Metapod's defense rose!".to_string()));
+}
+
+#[test]
+fn describes_moves_that_do_nothing() {
+    let mut backend = battle! {
+        "Magikarp" 10 (max ivs, Serious) vs "Magikarp" 10 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Splash", "Splash");
+
+    assert_eq!(
+        get_messages(&backend, &events),
+        vec![
+            "Magikarp used Splash!",
+            "But nothing happened!",
+            "Magikarp used Splash!",
+            "But nothing happened!",
+        ],
+    );
+}
@@ -1,5 +1,5 @@
 use crate::battle::backend::{
-    rng::BattleRng,
+    rng::{BattleRng, BattleRngState},
     BattleBackend,
     BattleEvent,
     FrontendEvent,
@@ -13,7 +13,16 @@ use crate::battle::backend::{
 mod macros;
 
 mod core;
+mod damage_calc;
+mod link;
+mod log;
+mod messages;
 mod moves;
+mod replay;
+mod rules;
+mod scenarios;
+mod simulation;
+mod snapshot;
 
 pub mod prelude {
     // Modules required by the test macros
@@ -21,21 +30,27 @@ pub mod prelude {
         battle::{
             backend::{
                 event::{
+                    AddedSideCondition,
                     ChangeTurn,
                     Damage,
                     ExpiredNonVolatileStatusCondition,
+                    ExpiredSideCondition,
                     ExpiredVolatileStatusCondition,
                     FailedMove,
                     Faint,
+                    Heal,
                     InitialSwitchIn,
                     Miss,
                     NonVolatileStatusCondition,
                     StatChange,
+                    SubstituteDamage,
+                    Transformed,
+                    TypeChange,
                     UseMove,
                     VolatileStatusCondition,
                 },
+                rng::StandardBattleRng,
                 BattleBackend,
-                Flag,
             },
             tests::TestRng,
             types::{Battle, BattleCharacterTeam, BattleType, Party},
@@ -45,38 +60,60 @@ pub mod prelude {
             get_all_moves,
             get_all_pokemon_species,
             Nature,
+            PokemonType,
+            SideCondition,
+            SimpleVolatileStatus,
             Stat,
+            VolatileStatus,
         },
     };
 
     use crate::{
         overworld::entities::character::CharacterId,
-        pokemon::Pokemon,
+        pokemon::{movement::MoveId, Pokemon},
     };
 
-    pub fn create_simple_wild_battle(p1: Pokemon, p2: Pokemon) -> BattleBackend {
-        BattleBackend::new(
-            Battle::new(
-                BattleType::Single,
-                BattleCharacterTeam {
-                    active_pokemon: None,
-                    party: Party {
-                        pokemon: vec![p1].into(),
-                    },
-                    character_id: Some(CharacterId(1)),
+    /// Returns the id of a move, panicking if it doesn't exist.
+    pub fn move_id(name: &str) -> MoveId {
+        get_all_moves()
+            .get_move_id(name)
+            .unwrap_or_else(|| panic!("Unknown move {}", name))
+    }
+
+    pub fn create_wild_battle_data(p1: Pokemon, p2: Pokemon) -> Battle {
+        Battle::new(
+            BattleType::Single,
+            BattleCharacterTeam {
+                active_pokemon: None,
+                party: Party {
+                    pokemon: vec![p1].into(),
                 },
-                BattleCharacterTeam {
-                    active_pokemon: None,
-                    party: Party {
-                        pokemon: vec![p2].into(),
-                    },
-                    character_id: None,
+                character_id: Some(CharacterId(1)),
+            },
+            BattleCharacterTeam {
+                active_pokemon: None,
+                party: Party {
+                    pokemon: vec![p2].into(),
                 },
-            ),
+                character_id: None,
+            },
+        )
+    }
+
+    pub fn create_simple_wild_battle(p1: Pokemon, p2: Pokemon) -> BattleBackend {
+        BattleBackend::new(
+            create_wild_battle_data(p1, p2),
             Box::new(TestRng::default()),
         )
     }
 
+    pub fn create_seeded_wild_battle(p1: Pokemon, p2: Pokemon, seed: u64) -> BattleBackend {
+        let battle = create_wild_battle_data(p1, p2).with_seed(seed);
+        let rng = StandardBattleRng::new(battle.seed);
+
+        BattleBackend::new(battle, Box::new(rng))
+    }
+
     pub fn create_simple_trainer_battle(p1: Pokemon, p2: Pokemon) -> BattleBackend {
         BattleBackend::new(
             Battle::new(
@@ -126,27 +163,27 @@ impl TestMethods for BattleBackend {
         let p1_index = self.p1.active_pokemon.unwrap();
         let p2_index = self.p2.active_pokemon.unwrap();
 
-        let p1_move_index = self.pokemon_repository[&p1_index]
-            .moves
+        let p1_move_index = self
+            .get_moves(p1_index)
             .iter()
             .enumerate()
             .filter_map(|(i, mov)| match mov {
                 Some(mov) => Some((i, mov)),
                 None => None,
             })
-            .find(|(_, mov)| mov.as_str() == p1_move)
+            .find(|(_, mov)| mov.name() == p1_move)
             .map(|(i, _)| i)
             .unwrap_or_else(|| panic!("Move \"{}\" not found for player 1", p1_move));
 
-        let p2_move_index = self.pokemon_repository[&p2_index]
-            .moves
+        let p2_move_index = self
+            .get_moves(p2_index)
             .iter()
             .enumerate()
             .filter_map(|(i, mov)| match mov {
                 Some(mov) => Some((i, mov)),
                 None => None,
             })
-            .find(|(_, mov)| mov.as_str() == p2_move)
+            .find(|(_, mov)| mov.name() == p2_move)
             .map(|(i, _)| i)
             .unwrap_or_else(|| panic!("Move \"{}\" not found for player 2", p2_move));
 
@@ -163,12 +200,19 @@ pub struct TestRng {
     last_miss_check_chance: Option<usize>,
     last_secondary_effect_check_chance: Option<usize>,
     secondary_effect_counter: usize,
+    critical_hit_counter: usize,
     uniform_multi_hit_value: Option<usize>,
     custom_multi_hit_value: Option<isize>,
     confusion_duration: Option<usize>,
-    confusion_miss_counter: usize,
+    confusion_self_hit_counter: usize,
     paralysis_move_prevention_counter: usize,
     freeze_duration: usize,
+    infatuation_move_prevention_counter: usize,
+    rampage_duration: Option<usize>,
+    binding_duration: Option<usize>,
+    psywave_percentage: Option<usize>,
+    conversion_2_type_index: Option<usize>,
+    damage_roll: Option<usize>,
 }
 
 impl TestRng {
@@ -180,6 +224,10 @@ impl TestRng {
         self.last_miss_check_chance
     }
 
+    pub fn reset_last_miss_check_chance(&mut self) {
+        self.last_miss_check_chance = None;
+    }
+
     pub fn get_last_secondary_effect_check_chance(&self) -> Option<usize> {
         self.last_secondary_effect_check_chance
     }
@@ -188,10 +236,18 @@ impl TestRng {
         self.secondary_effect_counter = times;
     }
 
+    pub fn force_critical_hit(&mut self, times: usize) {
+        self.critical_hit_counter = times;
+    }
+
     pub fn force_uniform_multi_hit_value(&mut self, value: usize) {
         self.uniform_multi_hit_value = Some(value);
     }
 
+    pub fn clear_uniform_multi_hit_value(&mut self) {
+        self.uniform_multi_hit_value = None;
+    }
+
     pub fn force_custom_multi_hit_value(&mut self, value: isize) {
         self.custom_multi_hit_value = Some(value);
     }
@@ -200,8 +256,8 @@ impl TestRng {
         self.confusion_duration = Some(duration);
     }
 
-    pub fn force_confusion_miss(&mut self, times: usize) {
-        self.confusion_miss_counter = times;
+    pub fn force_confusion_self_hit(&mut self, times: usize) {
+        self.confusion_self_hit_counter = times;
     }
 
     pub fn force_paralysis_move_prevention(&mut self, times: usize) {
@@ -211,6 +267,34 @@ impl TestRng {
     pub fn force_freeze_duration(&mut self, duration: usize) {
         self.freeze_duration = duration;
     }
+
+    pub fn force_infatuation_move_prevention(&mut self, times: usize) {
+        self.infatuation_move_prevention_counter = times;
+    }
+
+    pub fn force_rampage_duration(&mut self, duration: usize) {
+        self.rampage_duration = Some(duration);
+    }
+
+    pub fn force_binding_duration(&mut self, duration: usize) {
+        self.binding_duration = Some(duration);
+    }
+
+    pub fn force_psywave_percentage(&mut self, percentage: usize) {
+        self.psywave_percentage = Some(percentage);
+    }
+
+    pub fn force_conversion_2_type_index(&mut self, index: usize) {
+        self.conversion_2_type_index = Some(index);
+    }
+
+    pub fn force_damage_roll(&mut self, percentage: usize) {
+        self.damage_roll = Some(percentage);
+    }
+
+    pub fn clear_damage_roll(&mut self) {
+        self.damage_roll = None;
+    }
 }
 
 impl BattleRng for TestRng {
@@ -218,8 +302,12 @@ impl BattleRng for TestRng {
         Box::new(self.clone())
     }
 
+    fn get_state(&self) -> Option<BattleRngState> {
+        None
+    }
+
     fn get_damage_modifier(&mut self) -> f32 {
-        1.
+        self.damage_roll.unwrap_or(100) as f32 / 100.
     }
 
     fn shuffle_moves<'a>(&mut self, _moves: &mut Vec<UsedMove<'a>>) {}
@@ -246,6 +334,15 @@ impl BattleRng for TestRng {
         }
     }
 
+    fn check_critical_hit(&mut self, denominator: usize) -> bool {
+        if self.critical_hit_counter > 0 {
+            self.critical_hit_counter -= 1;
+            true
+        } else {
+            denominator == 1
+        }
+    }
+
     fn check_uniform_multi_hit(&mut self, _lowest: usize, highest: usize) -> usize {
         match self.uniform_multi_hit_value {
             Some(value) => value,
@@ -264,9 +361,9 @@ impl BattleRng for TestRng {
         self.confusion_duration.unwrap_or(4)
     }
 
-    fn check_confusion_miss(&mut self) -> bool {
-        if self.confusion_miss_counter > 0 {
-            self.confusion_miss_counter -= 1;
+    fn check_confusion_self_hit(&mut self) -> bool {
+        if self.confusion_self_hit_counter > 0 {
+            self.confusion_self_hit_counter -= 1;
             true
         } else {
             false
@@ -290,4 +387,29 @@ impl BattleRng for TestRng {
             true
         }
     }
+
+    fn check_infatuation_move_prevention(&mut self) -> bool {
+        if self.infatuation_move_prevention_counter > 0 {
+            self.infatuation_move_prevention_counter -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn get_rampage_duration(&mut self) -> usize {
+        self.rampage_duration.unwrap_or(3)
+    }
+
+    fn get_binding_duration(&mut self) -> usize {
+        self.binding_duration.unwrap_or(5)
+    }
+
+    fn get_psywave_percentage(&mut self) -> usize {
+        self.psywave_percentage.unwrap_or(100)
+    }
+
+    fn get_conversion_2_type_index(&mut self, _options: usize) -> usize {
+        self.conversion_2_type_index.unwrap_or(0)
+    }
 }
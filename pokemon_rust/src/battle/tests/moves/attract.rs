@@ -0,0 +1,39 @@
+use crate::{battle::backend::BattleEvent, pokemon::Gender};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn attract_can_prevent_the_target_from_moving() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Attract"));
+    backend.get_pokemon_mut(0).gender = Gender::Male;
+    backend.get_pokemon_mut(1).gender = Gender::Female;
+    test_rng_mut!(backend.rng).force_infatuation_move_prevention(1);
+
+    let events = backend.process_turn("Attract", "Harden");
+
+    assert_event!(
+        events[1],
+        VolatileStatusCondition { target: 1, status: VolatileStatus::Infatuation { user: 0 } }
+    );
+    assert_event!(events[2], FailedMove { move_user: 1 });
+}
+
+#[test]
+fn attract_fails_if_both_pokemon_have_the_same_gender() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Attract"));
+    backend.get_pokemon_mut(0).gender = Gender::Female;
+    backend.get_pokemon_mut(1).gender = Gender::Female;
+
+    let events = backend.process_turn("Attract", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert!(!backend.has_volatile_status(1, SimpleVolatileStatus::Infatuation));
+}
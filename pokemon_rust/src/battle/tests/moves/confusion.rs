@@ -1,4 +1,4 @@
-use crate::battle::backend::BattleEvent;
+use crate::battle::backend::{BattleEvent, DamageCause, TypeEffectiveness};
 
 use super::super::{prelude::*, TestMethods};
 
@@ -12,9 +12,61 @@ fn confusion_deals_damage_and_might_cause_confusion() {
     test_rng_mut!(backend.rng).force_confusion_duration(1);
     let turn1 = backend.process_turn("Confusion", "Harden");
     assert_event!(turn1[1], Damage { target: 1, is_critical_hit: false, .. });
-    assert_event!(turn1[2], VolatileStatusCondition { target: 1, added_flag: Flag::Confusion { .. } });
+    assert_event!(turn1[2], VolatileStatusCondition { target: 1, status: VolatileStatus::Confusion });
 
     let turn2 = backend.process_turn("Confusion", "Harden");
     assert_event!(turn2[1], Damage { target: 1, is_critical_hit: false, .. });
     assert_event!(turn2[3], UseMove { move_user: 1, .. });
 }
+
+#[test]
+fn confused_pokemon_hit_themselves_with_a_40_power_physical_attack() {
+    let mut backend = battle! {
+        "Butterfree" 8 (max ivs, Serious) vs "Metapod" 8 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+    backend.process_turn("Confusion", "Harden");
+
+    test_rng_mut!(backend.rng).force_confusion_self_hit(1);
+    let events = backend.process_turn("Confusion", "Harden");
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(
+        events[3],
+        Damage {
+            target: 1,
+            amount: 3,
+            effectiveness: TypeEffectiveness::Normal,
+            cause: DamageCause::Confusion,
+            ..
+        }
+    );
+}
+
+#[test]
+fn burns_halve_confusion_damage() {
+    let mut backend = battle! {
+        "Hitmonchan" 25 (max ivs, Serious) vs "Hitmonchan" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+    test_rng_mut!(backend.rng).force_confusion_self_hit(1);
+    let turn1 = backend.process_turn("DizzyPunch", "IcePunch");
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+    test_rng_mut!(backend.rng).force_confusion_self_hit(1);
+    let turn2 = backend.process_turn("FirePunch", "IcePunch");
+    assert_event!(turn2[2], NonVolatileStatusCondition { target: 1, .. });
+
+    match (&turn1[4], &turn2[4]) {
+        (
+            BattleEvent::Damage(Damage { amount: a1, cause: DamageCause::Confusion, .. }),
+            BattleEvent::Damage(Damage { amount: a2, cause: DamageCause::Confusion, .. }),
+        ) => {
+            assert_eq!(*a2, *a1 / 2);
+        },
+        _ => panic!("Pattern mismatch"),
+    }
+}
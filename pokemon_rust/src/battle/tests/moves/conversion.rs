@@ -0,0 +1,37 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn conversion_changes_the_type_of_the_user_to_the_type_of_its_first_move() {
+    let mut backend = battle! {
+        "Porygon" 31 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("WaterGun"));
+
+    let events = backend.process_turn("Conversion", "Harden");
+
+    assert_event!(events[0], UseMove { move_user: 0, .. });
+    assert_eq!(
+        events[1],
+        BattleEvent::TypeChange(TypeChange {
+            target: 0,
+            types: vec![PokemonType::Water],
+        })
+    );
+    assert_eq!(backend.get_current_types(0), [PokemonType::Water]);
+    assert!(!backend.has_type(0, PokemonType::Normal));
+}
+
+#[test]
+fn conversion_fails_if_the_user_already_has_the_type_of_its_first_move() {
+    let mut backend = battle! {
+        "Porygon" 31 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("Conversion", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert_eq!(backend.get_current_types(0), [PokemonType::Normal]);
+}
@@ -0,0 +1,65 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn conversion_2_changes_the_type_of_the_user_to_one_that_resists_the_last_move_of_the_target() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Conversion2"));
+    backend.process_turn("Mist", "Harden");
+
+    let events = backend.process_turn("Conversion2", "Harden");
+
+    // Rock, Ghost and Steel resist Normal moves like Harden
+    assert_eq!(
+        events[1],
+        BattleEvent::TypeChange(TypeChange {
+            target: 0,
+            types: vec![PokemonType::Rock],
+        })
+    );
+
+    test_rng_mut!(backend.rng).force_conversion_2_type_index(1);
+    let events = backend.process_turn("Conversion2", "Harden");
+
+    assert_eq!(
+        events[1],
+        BattleEvent::TypeChange(TypeChange {
+            target: 0,
+            types: vec![PokemonType::Steel],
+        })
+    );
+}
+
+#[test]
+fn conversion_2_ignores_types_that_the_user_already_has() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Conversion2"));
+    backend.process_turn("Mist", "Harden");
+    backend.process_turn("Conversion2", "Harden");
+
+    let events = backend.process_turn("Conversion2", "Harden");
+
+    assert_event!(events[1], TypeChange { target: 0, .. });
+    assert_eq!(backend.get_current_types(0), [PokemonType::Ghost]);
+}
+
+#[test]
+fn conversion_2_fails_if_the_target_has_not_used_a_move() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Conversion2"));
+
+    let events = backend.process_turn("Conversion2", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert_eq!(backend.get_current_types(0), [PokemonType::Water, PokemonType::Ice]);
+}
@@ -0,0 +1,19 @@
+use crate::battle::backend::{BattleEvent, StatChangeKind};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn curse_trades_speed_for_attack_and_defense_if_the_user_is_not_a_ghost() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Curse"));
+
+    let events = backend.process_turn("Curse", "Harden");
+
+    assert_event!(events[1], StatChange { target: 0, kind: StatChangeKind::Fell, stat: Stat::Speed });
+    assert_event!(events[2], StatChange { target: 0, kind: StatChangeKind::Rose, stat: Stat::Attack });
+    assert_event!(events[3], StatChange { target: 0, kind: StatChangeKind::Rose, stat: Stat::Defense });
+    assert!(!backend.has_volatile_status(1, SimpleVolatileStatus::Curse));
+}
@@ -0,0 +1,35 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn dig_attacks_after_charging() {
+    let mut backend = battle! {
+        "Diglett" 32 (max ivs, Serious) vs "Metapod" 32 (max ivs, Serious)
+    };
+
+    let turn1 = backend.process_turn("Dig", "Harden");
+    assert_event!(
+        turn1[1],
+        VolatileStatusCondition {
+            target: 0,
+            status: VolatileStatus::Charging { semi_invulnerable: true, .. },
+        }
+    );
+
+    let turn2 = backend.process_turn("Dig", "Harden");
+    assert_event!(turn2[0], UseMove { move_user: 0, .. });
+    assert_event!(turn2[1], Damage { target: 1, .. });
+}
+
+#[test]
+fn dig_makes_the_user_semi_invulnerable_while_charging() {
+    let mut backend = battle! {
+        "Diglett" 32 (max ivs, Serious) vs "Metapod" 32 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Tackle"));
+
+    let events = backend.process_turn("Dig", "Tackle");
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Miss { target: 0, move_user: 1 });
+}
@@ -0,0 +1,34 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn disable_prevents_the_target_from_using_its_last_move() {
+    let mut backend = battle! {
+        "Slowpoke" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Disable"));
+
+    let turn1 = backend.process_turn("Disable", "Harden");
+    assert_event!(
+        turn1[3],
+        VolatileStatusCondition { target: 1, status: VolatileStatus::Disable { .. } }
+    );
+
+    let turn2 = backend.process_turn("Disable", "Harden");
+    assert_event!(turn2[0], FailedMove { move_user: 1 });
+}
+
+#[test]
+fn disable_fails_if_the_target_has_not_moved_yet() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Disable"));
+
+    let events = backend.process_turn("Disable", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
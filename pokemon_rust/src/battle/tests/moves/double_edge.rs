@@ -0,0 +1,34 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn double_edge_deals_a_third_of_the_damage_as_recoil() {
+    let mut backend = battle! {
+        "Bulbasaur" 33 (max ivs, Serious) vs "Metapod" 33 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    let events = backend.process_turn("DoubleEdge", "Harden");
+    assert_event!(events[1], Damage { target: 1, cause: DamageCause::Move, .. });
+    assert_event!(events[2], Damage { target: 0, cause: DamageCause::Recoil, .. });
+
+    if let (BattleEvent::Damage(damage), BattleEvent::Damage(recoil)) = (&events[1], &events[2]) {
+        assert_eq!(recoil.amount, (damage.amount as f32 / 3.).round() as usize);
+    }
+}
+
+#[test]
+fn double_edge_recoil_can_make_the_user_faint() {
+    let mut backend = battle! {
+        "Bulbasaur" 33 (max ivs, Serious) vs "Metapod" 33 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).current_hp = 1;
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    let events = backend.process_turn("DoubleEdge", "Harden");
+    assert_event!(events[2], Damage { target: 0, cause: DamageCause::Recoil, .. });
+    assert_event!(events[3], Faint { target: 0 });
+}
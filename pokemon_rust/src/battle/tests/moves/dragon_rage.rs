@@ -0,0 +1,15 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn dragon_rage_always_deals_40_damage() {
+    let mut backend = battle! {
+        "Charmander" 30 (max ivs, Serious) vs "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("DragonRage"));
+
+    let events = backend.process_turn("DragonRage", "Harden");
+    assert_event!(events[1], Damage { target: 1, amount: 40, .. });
+}
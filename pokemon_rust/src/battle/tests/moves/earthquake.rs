@@ -0,0 +1,42 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn earthquake_deals_damage() {
+    let mut backend = battle! {
+        "Diglett" 32 (max ivs, Serious) vs "Metapod" 32 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Earthquake"));
+
+    let events = backend.process_turn("Earthquake", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: false, .. });
+}
+
+#[test]
+fn earthquake_hits_pokemon_that_are_underground() {
+    let mut backend = battle! {
+        "Diglett" 32 (max ivs, Serious) vs "Metapod" 32 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Earthquake"));
+
+    let events = backend.process_turn("Dig", "Earthquake");
+
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Damage { target: 0, .. });
+}
+
+#[test]
+fn earthquake_does_not_hit_pokemon_that_are_flying() {
+    let mut backend = battle! {
+        "Pidgey" 32 (max ivs, Serious) vs "Metapod" 32 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Fly"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Earthquake"));
+
+    let events = backend.process_turn("Fly", "Earthquake");
+
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Miss { target: 0, move_user: 1 });
+}
@@ -0,0 +1,39 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn encore_locks_the_target_into_its_last_move() {
+    let mut backend = battle! {
+        "Slowpoke" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Encore"));
+
+    let events = backend.process_turn("Encore", "Harden");
+
+    assert_event!(
+        events[3],
+        VolatileStatusCondition { target: 1, status: VolatileStatus::Encore { .. } }
+    );
+    assert!(backend.is_locked(Team::P2));
+}
+
+#[test]
+fn encore_ends_after_three_turns() {
+    let mut backend = battle! {
+        "Slowpoke" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Encore"));
+
+    backend.process_turn("Encore", "Harden");
+    backend.process_turn("Encore", "Harden");
+    let turn3 = backend.process_turn("Encore", "Harden");
+
+    assert_event!(
+        turn3[turn3.len() - 2],
+        ExpiredVolatileStatusCondition { target: 1, status: VolatileStatus::Encore { .. } }
+    );
+    assert!(!backend.is_locked(Team::P2));
+}
@@ -0,0 +1,18 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn fire_spin_traps_the_target() {
+    let mut backend = battle! {
+        "Charmander" 32 (max ivs, Serious) vs "Metapod" 32 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("FireSpin", "Harden");
+
+    assert_event!(
+        events[2],
+        VolatileStatusCondition { target: 1, status: VolatileStatus::Binding { user: 0, .. } }
+    );
+    assert_event!(events[5], Damage { target: 1, cause: DamageCause::Binding { .. }, .. });
+}
@@ -0,0 +1,30 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn fly_makes_the_user_semi_invulnerable_while_charging() {
+    let mut backend = battle! {
+        "Pidgey" 20 (max ivs, Serious) vs "Rattata" 20 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Fly"));
+
+    let turn1 = backend.process_turn("Fly", "QuickAttack");
+    assert_event!(turn1[0], UseMove { move_user: 1, .. });
+    assert_event!(turn1[1], Damage { target: 0, .. });
+    assert_event!(
+        turn1[3],
+        VolatileStatusCondition {
+            target: 0,
+            status: VolatileStatus::Charging { semi_invulnerable: true, .. },
+        }
+    );
+
+    let turn2 = backend.process_turn("Fly", "QuickAttack");
+    assert_event!(turn2[1], Miss { target: 0, move_user: 1 });
+    assert_event!(turn2[2], UseMove { move_user: 0, .. });
+    assert_event!(turn2[3], Damage { target: 1, .. });
+
+    let turn3 = backend.process_turn("Fly", "QuickAttack");
+    assert_event!(turn3[1], Damage { target: 0, .. });
+}
@@ -0,0 +1,19 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn focus_energy_guarantees_critical_hits() {
+    let mut backend = battle! {
+        "Rattata" 10 (max ivs, Serious) vs "Metapod" 10 (max ivs, Serious)
+    };
+
+    let turn1 = backend.process_turn("FocusEnergy", "Harden");
+    assert_event!(
+        turn1[1],
+        VolatileStatusCondition { target: 0, status: VolatileStatus::FocusEnergy }
+    );
+
+    let turn2 = backend.process_turn("Tackle", "Harden");
+    assert_event!(turn2[1], Damage { target: 1, is_critical_hit: true, .. });
+}
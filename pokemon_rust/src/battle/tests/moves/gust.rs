@@ -12,3 +12,23 @@ fn gust_deals_damage() {
 
     assert_event!(events[1], Damage { target: 1, is_critical_hit: false, .. });
 }
+
+#[test]
+fn gust_hits_pokemon_that_are_flying() {
+    let mut backend = battle! {
+        "Pidgey" 20 (max ivs, Serious) vs "Metapod" 20 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Fly"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Gust"));
+
+    let events = backend.process_turn("Fly", "Gust");
+    assert_event!(
+        events[1],
+        VolatileStatusCondition {
+            target: 0,
+            status: VolatileStatus::Charging { semi_invulnerable: true, .. },
+        }
+    );
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Damage { target: 0, .. });
+}
@@ -0,0 +1,40 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn hyper_beam_requires_a_recharge_turn() {
+    let mut backend = battle! {
+        "Porygon" 40 (max ivs, Serious) vs "Metapod" 40 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    let turn1 = backend.process_turn("HyperBeam", "Harden");
+    assert_event!(turn1[1], Damage { target: 1, .. });
+    assert!(backend.is_locked(Team::P1));
+
+    let turn2 = backend.process_turn("HyperBeam", "Harden");
+    assert_event!(
+        turn2[0],
+        ExpiredVolatileStatusCondition { target: 0, status: VolatileStatus::Recharging { .. } }
+    );
+    assert_event!(turn2[1], UseMove { move_user: 1, .. });
+    assert!(!backend.is_locked(Team::P1));
+}
+
+#[test]
+fn hyper_beam_does_not_require_a_recharge_turn_if_it_misses() {
+    let mut backend = battle! {
+        "Porygon" 40 (max ivs, Serious) vs "Metapod" 40 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    test_rng_mut!(backend.rng).force_miss(1);
+    let turn1 = backend.process_turn("HyperBeam", "Harden");
+    assert_event!(turn1[1], Miss { target: 1, move_user: 0, .. });
+    assert!(!backend.is_locked(Team::P1));
+
+    let turn2 = backend.process_turn("HyperBeam", "Harden");
+    assert_event!(turn2[0], UseMove { move_user: 0, .. });
+    assert_event!(turn2[1], Damage { target: 1, .. });
+}
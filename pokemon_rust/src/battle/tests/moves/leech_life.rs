@@ -0,0 +1,39 @@
+use crate::battle::backend::{BattleEvent, HealCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn leech_life_drains_half_of_the_damage_dealt() {
+    let mut backend = battle! {
+        "Paras" 23 (max ivs, Serious) vs "Metapod" 23 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).current_hp = 1;
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    let events = backend.process_turn("LeechLife", "Harden");
+    assert_event!(events[3], Damage { target: 1, .. });
+    assert_event!(
+        events[4],
+        Heal { target: 0, cause: HealCause::Drain { drained: 1 }, .. }
+    );
+
+    if let (BattleEvent::Damage(damage), BattleEvent::Heal(heal)) = (&events[3], &events[4]) {
+        assert_eq!(heal.amount, (damage.amount as f32 / 2.).round() as usize);
+    }
+}
+
+#[test]
+fn leech_life_only_drains_the_hp_that_the_target_had() {
+    let mut backend = battle! {
+        "Paras" 23 (max ivs, Serious) vs "Metapod" 23 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).current_hp = 1;
+    backend.get_pokemon_mut(1).current_hp = 10;
+
+    let events = backend.process_turn("LeechLife", "Harden");
+    assert_event!(events[3], Damage { target: 1, .. });
+    assert_event!(events[4], Faint { target: 1 });
+    assert_event!(events[5], Heal { target: 0, amount: 5, .. });
+}
@@ -0,0 +1,42 @@
+use crate::battle::backend::{BattleEvent, DamageCause, HealCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn leech_seed_drains_the_target_at_the_end_of_each_turn() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("LeechSeed"));
+    backend.get_pokemon_mut(0).current_hp -= 10;
+    let max_hp = backend.get_pokemon(1).stats[0];
+    let expected_damage = (max_hp as f32 / 8.).ceil() as usize;
+
+    let events = backend.process_turn("LeechSeed", "Harden");
+
+    assert_event!(
+        events[1],
+        VolatileStatusCondition { target: 1, status: VolatileStatus::LeechSeed { user: 0 } }
+    );
+    assert_event!(events[4], Damage { target: 1, cause: DamageCause::LeechSeed, .. });
+    assert_event!(events[5], Heal { target: 0, cause: HealCause::LeechSeed { drained: 1 }, .. });
+
+    if let BattleEvent::Damage(event_data) = &events[4] {
+        assert_eq!(event_data.amount, expected_damage);
+    }
+}
+
+#[test]
+fn leech_seed_does_not_affect_grass_types() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Oddish" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("LeechSeed"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Harden"));
+
+    let events = backend.process_turn("LeechSeed", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,43 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn light_screen_halves_the_damage_of_special_moves() {
+    let mut backend = battle! {
+        "Pikachu" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("ThunderShock"));
+    backend.get_pokemon_mut(1).moves[1] = Some(move_id("LightScreen"));
+
+    let turn1 = backend.process_turn("ThunderShock", "Harden");
+    let turn2 = backend.process_turn("ThunderShock", "LightScreen");
+    let turn3 = backend.process_turn("ThunderShock", "Harden");
+
+    assert_event!(
+        turn2[turn2.len() - 2],
+        AddedSideCondition { team: Team::P2, condition: SideCondition::LightScreen }
+    );
+
+    match (&turn1[1], &turn3[1]) {
+        (BattleEvent::Damage(without_screen), BattleEvent::Damage(with_screen)) => {
+            assert_eq!(with_screen.amount, without_screen.amount / 2);
+        },
+        _ => panic!("Thunder Shock didn't deal damage"),
+    }
+}
+
+#[test]
+fn light_screen_fails_if_it_is_already_active() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[1] = Some(move_id("LightScreen"));
+
+    backend.process_turn("LightScreen", "Harden");
+    let events = backend.process_turn("LightScreen", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,20 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn mean_look_prevents_the_target_from_switching_out() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("MeanLook"));
+
+    let events = backend.process_turn("MeanLook", "Harden");
+
+    assert_event!(
+        events[1],
+        VolatileStatusCondition { target: 1, status: VolatileStatus::Trapped { user: 0 } }
+    );
+    assert!(!backend.can_switch_out(1));
+}
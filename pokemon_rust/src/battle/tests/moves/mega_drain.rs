@@ -0,0 +1,23 @@
+use crate::battle::backend::{BattleEvent, HealCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn mega_drain_drains_half_of_the_damage_dealt() {
+    let mut backend = battle! {
+        "Oddish" 14 (max ivs, Serious) vs "Metapod" 14 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).current_hp = 1;
+
+    let events = backend.process_turn("MegaDrain", "Harden");
+    assert_event!(events[1], Damage { target: 1, .. });
+    assert_event!(
+        events[2],
+        Heal { target: 0, cause: HealCause::Drain { drained: 1 }, .. }
+    );
+
+    if let (BattleEvent::Damage(damage), BattleEvent::Heal(heal)) = (&events[1], &events[2]) {
+        assert_eq!(heal.amount, (damage.amount as f32 / 2.).round() as usize);
+    }
+}
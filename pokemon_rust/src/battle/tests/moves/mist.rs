@@ -0,0 +1,41 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn mist_prevents_stat_reductions() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Mist"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("StringShot"));
+
+    let events = backend.process_turn("Mist", "StringShot");
+
+    assert_event!(events[1], AddedSideCondition { team: Team::P1, condition: SideCondition::Mist });
+    assert_event!(events[3], FailedMove { move_user: 1 });
+}
+
+#[test]
+fn mist_ends_after_five_turns() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Mist"));
+
+    backend.process_turn("Mist", "Harden");
+
+    for _ in 0..3 {
+        backend.process_turn("ConfuseRay", "Harden");
+    }
+
+    let turn5 = backend.process_turn("ConfuseRay", "Harden");
+
+    assert_event!(
+        turn5[turn5.len() - 2],
+        ExpiredSideCondition { team: Team::P1, condition: SideCondition::Mist }
+    );
+    assert!(!backend.has_side_condition(Team::P1, SideCondition::Mist));
+}
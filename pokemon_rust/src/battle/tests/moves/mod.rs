@@ -1,7 +1,9 @@
+mod absorb;
 mod acid;
 mod acid_armor;
 mod agility;
 mod amnesia;
+mod attract;
 mod aurora_beam;
 mod barrier;
 mod bite;
@@ -12,20 +14,32 @@ mod comet_punch;
 mod confuse_ray;
 mod confusion;
 mod constrict;
+mod conversion;
+mod conversion_2;
 mod crab_hammer;
+mod curse;
 mod cut;
+mod dig;
+mod disable;
 mod dizzy_punch;
+mod double_edge;
 mod double_kick;
 mod double_slap;
 mod double_team;
+mod dragon_rage;
 mod drill_peck;
+mod earthquake;
 mod egg_bomb;
 mod ember;
+mod encore;
 mod fire_blast;
 mod fire_punch;
+mod fire_spin;
 mod fissure;
 mod flamethrower;
 mod flash;
+mod fly;
+mod focus_energy;
 mod fury_attack;
 mod glare;
 mod growl;
@@ -36,34 +50,47 @@ mod headbutt;
 mod horn_attack;
 mod horn_drill;
 mod hydro_pump;
+mod hyper_beam;
 mod hyper_fang;
 mod hypnosis;
 mod ice_beam;
 mod ice_punch;
 mod karate_chop;
 mod kinesis;
+mod light_screen;
+mod leech_life;
+mod leech_seed;
 mod leer;
 mod lick;
 mod lovely_kiss;
 mod mach_punch;
+mod mean_look;
 mod meditate;
+mod mega_drain;
 mod mega_kick;
 mod mega_punch;
+mod mist;
+mod night_shade;
 mod peck;
+mod petal_dance;
 mod poison_gas;
 mod poison_powder;
 mod poison_sting;
 mod pound;
 mod psychic;
 mod psybeam;
+mod psywave;
 mod quick_attack;
 mod razor_leaf;
+mod reflect;
 mod rock_slide;
 mod rock_throw;
 mod rolling_kick;
+mod safeguard;
 mod sand_attack;
 mod scratch;
 mod screech;
+mod seismic_toss;
 mod sharpen;
 mod sing;
 mod slam;
@@ -72,24 +99,37 @@ mod sleep_powder;
 mod sludge;
 mod smog;
 mod smokescreen;
+mod soak;
+mod solar_beam;
+mod sonic_boom;
+mod spikes;
 mod splash;
 mod spore;
+mod stealth_rock;
 mod strength;
 mod string_shot;
+mod substitute;
 mod stun_spore;
+mod super_fang;
 mod supersonic;
 mod swift;
 mod swords_dance;
-mod tackle;
 mod tailwhip;
+mod tailwind;
+mod taunt;
+mod take_down;
+mod thrash;
+mod thunder;
 mod thunderbolt;
 mod thunder_punch;
 mod thunder_shock;
 mod thunder_wave;
-mod toxic;
+mod toxic_spikes;
+mod transform;
 mod vine_whip;
 mod vise_grip;
 mod waterfall;
 mod water_gun;
 mod wing_attack;
 mod withdraw;
+mod wrap;
@@ -0,0 +1,29 @@
+use crate::battle::backend::{BattleEvent, TypeEffectiveness};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn night_shade_deals_damage_equal_to_the_user_level() {
+    let mut backend = battle! {
+        "Kadabra" 19 (max ivs, Serious) vs "Metapod" 30 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("NightShade", "Harden");
+    assert_event!(
+        events[1],
+        Damage { target: 1, amount: 19, effectiveness: TypeEffectiveness::Normal, .. }
+    );
+}
+
+#[test]
+fn night_shade_does_not_affect_normal_types() {
+    let mut backend = battle! {
+        "Kadabra" 19 (max ivs, Serious) vs "Rattata" 19 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("NightShade", "FocusEnergy");
+    assert_event!(
+        events[1],
+        Damage { target: 1, amount: 0, effectiveness: TypeEffectiveness::Immune, .. }
+    );
+}
@@ -0,0 +1,22 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn petal_dance_lasts_up_to_three_turns() {
+    let mut backend = battle! {
+        "Oddish" 40 (max ivs, Serious) vs "Metapod" 40 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    backend.process_turn("PetalDance", "Harden");
+    backend.process_turn("PetalDance", "Harden");
+    assert!(backend.is_locked(Team::P1));
+
+    let turn3 = backend.process_turn("PetalDance", "Harden");
+    assert_event!(
+        turn3[2],
+        VolatileStatusCondition { target: 0, status: VolatileStatus::Confusion }
+    );
+    assert!(!backend.is_locked(Team::P1));
+}
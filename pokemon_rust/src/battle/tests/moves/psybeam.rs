@@ -12,7 +12,7 @@ fn psybeam_deals_damage_and_might_cause_confusion() {
     test_rng_mut!(backend.rng).force_confusion_duration(1);
     let turn1 = backend.process_turn("Psybeam", "Harden");
     assert_event!(turn1[1], Damage { target: 1, is_critical_hit: false, .. });
-    assert_event!(turn1[2], VolatileStatusCondition { target: 1, added_flag: Flag::Confusion { .. } });
+    assert_event!(turn1[2], VolatileStatusCondition { target: 1, status: VolatileStatus::Confusion });
 
     let turn2 = backend.process_turn("Psybeam", "Harden");
     assert_event!(turn2[1], Damage { target: 1, is_critical_hit: false, .. });
@@ -0,0 +1,21 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn psywave_deals_damage_based_on_the_user_level() {
+    let mut backend = battle! {
+        "Kadabra" 30 (max ivs, Serious) vs "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Psywave"));
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    test_rng_mut!(backend.rng).force_psywave_percentage(150);
+    let events = backend.process_turn("Psywave", "Harden");
+    assert_event!(events[1], Damage { target: 1, amount: 45, .. });
+
+    test_rng_mut!(backend.rng).force_psywave_percentage(50);
+    let events = backend.process_turn("Psywave", "Harden");
+    assert_event!(events[1], Damage { target: 1, amount: 15, .. });
+}
@@ -0,0 +1,28 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn reflect_halves_the_damage_of_physical_moves() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Reflect"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Tackle"));
+
+    let turn1 = backend.process_turn("Mist", "Tackle");
+    let turn2 = backend.process_turn("Reflect", "Tackle");
+
+    assert_event!(
+        turn2[1],
+        AddedSideCondition { team: Team::P1, condition: SideCondition::Reflect }
+    );
+
+    match (&turn1[3], &turn2[3]) {
+        (BattleEvent::Damage(without_reflect), BattleEvent::Damage(with_reflect)) => {
+            assert_eq!(with_reflect.amount, without_reflect.amount / 2);
+        },
+        _ => panic!("Tackle didn't deal damage"),
+    }
+}
@@ -0,0 +1,37 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn safeguard_prevents_status_conditions() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Safeguard"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("ThunderWave"));
+
+    let events = backend.process_turn("Safeguard", "ThunderWave");
+
+    assert_event!(
+        events[1],
+        AddedSideCondition { team: Team::P1, condition: SideCondition::Safeguard }
+    );
+    assert_event!(events[3], FailedMove { move_user: 1 });
+    assert!(!backend.has_non_volatile_status_condition(0));
+}
+
+#[test]
+fn safeguard_prevents_confusion() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Safeguard"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Supersonic"));
+
+    let events = backend.process_turn("Safeguard", "Supersonic");
+
+    assert_event!(events[3], FailedMove { move_user: 1 });
+    assert!(!backend.has_volatile_status(0, SimpleVolatileStatus::Confusion));
+}
@@ -0,0 +1,28 @@
+use crate::{
+    battle::backend::BattleEvent,
+    pokemon::StatusCondition,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn seismic_toss_deals_damage_equal_to_the_user_level() {
+    let mut backend = battle! {
+        "Machop" 40 (max ivs, Serious) vs "Metapod" 45 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("SeismicToss", "Harden");
+    assert_event!(events[1], Damage { target: 1, amount: 40, .. });
+}
+
+#[test]
+fn seismic_toss_is_not_weakened_by_burns() {
+    let mut backend = battle! {
+        "Machop" 40 (max ivs, Serious) vs "Metapod" 45 (max ivs, Serious)
+    };
+    backend.add_non_volatile_status_condition(0, StatusCondition::Burn);
+
+    let events = backend.process_turn("SeismicToss", "Harden");
+    assert_event!(events[0], NonVolatileStatusCondition { target: 0, .. });
+    assert_event!(events[2], Damage { target: 1, amount: 40, is_critical_hit: false, .. });
+}
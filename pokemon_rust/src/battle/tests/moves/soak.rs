@@ -0,0 +1,39 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn soak_turns_the_target_into_a_pure_water_type() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Soak"));
+
+    let events = backend.process_turn("Soak", "Harden");
+
+    assert_eq!(
+        events[1],
+        BattleEvent::TypeChange(TypeChange {
+            target: 1,
+            types: vec![PokemonType::Water],
+        })
+    );
+    assert!(backend.has_type(1, PokemonType::Water));
+    assert!(!backend.has_type(1, PokemonType::Bug));
+    assert_eq!(backend.get_pokemon(1).species_id.name(), "Metapod");
+}
+
+#[test]
+fn soak_fails_against_pure_water_types() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Soak"));
+    backend.process_turn("Soak", "Harden");
+
+    let events = backend.process_turn("Soak", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+}
@@ -0,0 +1,39 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn solar_beam_charges_in_the_first_turn() {
+    let mut backend = battle! {
+        "Bulbasaur" 36 (max ivs, Serious) vs "Metapod" 36 (max ivs, Serious)
+    };
+
+    let events = backend.process_turn("SolarBeam", "Harden");
+
+    assert_event!(events[0], UseMove { move_user: 0, .. });
+    assert_event!(
+        events[1],
+        VolatileStatusCondition {
+            target: 0,
+            status: VolatileStatus::Charging { semi_invulnerable: false, .. },
+        }
+    );
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert!(backend.is_locked(Team::P1));
+    assert!(!backend.is_locked(Team::P2));
+}
+
+#[test]
+fn solar_beam_attacks_in_the_second_turn() {
+    let mut backend = battle! {
+        "Bulbasaur" 36 (max ivs, Serious) vs "Metapod" 36 (max ivs, Serious)
+    };
+
+    backend.process_turn("SolarBeam", "Harden");
+    backend.move_p2(0);
+    let events: Vec<_> = backend.tick().collect();
+
+    assert_event!(events[0], UseMove { move_user: 0, .. });
+    assert_event!(events[1], Damage { target: 1, .. });
+    assert!(!backend.is_locked(Team::P1));
+}
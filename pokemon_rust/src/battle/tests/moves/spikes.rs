@@ -0,0 +1,71 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn spikes_can_be_stacked_three_times() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Spikes"));
+
+    for _ in 0..3 {
+        let events = backend.process_turn("Spikes", "Harden");
+        assert_event!(
+            events[1],
+            AddedSideCondition { team: Team::P2, condition: SideCondition::Spikes }
+        );
+    }
+
+    let events = backend.process_turn("Spikes", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert_eq!(backend.get_side_condition_layers(Team::P2, SideCondition::Spikes), 3);
+}
+
+#[test]
+fn spikes_hurt_pokemon_that_switch_in() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Spikes"));
+    backend.process_turn("Spikes", "Harden");
+    let max_hp = backend.get_stat(1, Stat::HP);
+
+    backend.process_switch_in(1);
+
+    assert_eq!(backend.get_pokemon(1).current_hp, max_hp - max_hp / 8);
+}
+
+#[test]
+fn more_layers_of_spikes_hurt_more() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    for _ in 0..3 {
+        backend.add_side_condition(Team::P2, SideCondition::Spikes);
+    }
+
+    let max_hp = backend.get_stat(1, Stat::HP);
+
+    backend.process_switch_in(1);
+
+    assert_eq!(backend.get_pokemon(1).current_hp, max_hp - max_hp / 4);
+}
+
+#[test]
+fn spikes_do_not_affect_flying_types() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Butterfree" 25 (max ivs, Serious)
+    };
+
+    backend.add_side_condition(Team::P2, SideCondition::Spikes);
+    let max_hp = backend.get_stat(1, Stat::HP);
+
+    backend.process_switch_in(1);
+
+    assert_eq!(backend.get_pokemon(1).current_hp, max_hp);
+}
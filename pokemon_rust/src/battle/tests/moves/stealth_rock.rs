@@ -0,0 +1,34 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn stealth_rock_is_set_on_the_opposing_side() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("StealthRock"));
+
+    let events = backend.process_turn("StealthRock", "Harden");
+
+    assert_event!(
+        events[1],
+        AddedSideCondition { team: Team::P2, condition: SideCondition::StealthRock }
+    );
+}
+
+#[test]
+fn stealth_rock_damage_depends_on_the_weakness_to_rock() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Butterfree" 25 (max ivs, Serious)
+    };
+
+    backend.add_side_condition(Team::P2, SideCondition::StealthRock);
+    let max_hp = backend.get_stat(1, Stat::HP);
+
+    backend.process_switch_in(1);
+
+    // Butterfree is weak to Rock twice, as a Bug and Flying type
+    assert_eq!(backend.get_pokemon(1).current_hp, max_hp - max_hp / 2);
+}
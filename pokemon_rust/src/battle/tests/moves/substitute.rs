@@ -0,0 +1,53 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn substitute_takes_damage_in_place_of_the_user() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Substitute"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Tackle"));
+    let max_hp = backend.get_pokemon(0).stats[0];
+
+    let events = backend.process_turn("Substitute", "Tackle");
+
+    assert_event!(events[1], Damage { target: 0, cause: DamageCause::HpCost, .. });
+    assert_event!(
+        events[2],
+        VolatileStatusCondition { target: 0, status: VolatileStatus::Substitute { .. } }
+    );
+    assert_event!(events[4], SubstituteDamage { target: 0, .. });
+    assert_eq!(backend.get_pokemon(0).current_hp, max_hp - max_hp / 4);
+}
+
+#[test]
+fn substitute_blocks_status_moves() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Substitute"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("StringShot"));
+
+    let events = backend.process_turn("Substitute", "StringShot");
+
+    assert_event!(events[4], FailedMove { move_user: 1 });
+}
+
+#[test]
+fn substitute_fails_if_the_user_does_not_have_enough_hp() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Substitute"));
+    backend.get_pokemon_mut(0).current_hp = 1;
+
+    let events = backend.process_turn("Substitute", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert!(!backend.has_volatile_status(0, SimpleVolatileStatus::Substitute));
+}
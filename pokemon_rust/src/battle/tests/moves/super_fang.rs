@@ -0,0 +1,28 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn super_fang_halves_the_hp_of_the_target() {
+    let mut backend = battle! {
+        "Rattata" 15 (max ivs, Serious) vs "Metapod" 15 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 31;
+
+    let events = backend.process_turn("SuperFang", "Harden");
+    assert_event!(events[1], Damage { target: 1, amount: 15, .. });
+}
+
+#[test]
+fn super_fang_deals_at_least_1_damage() {
+    let mut backend = battle! {
+        "Rattata" 15 (max ivs, Serious) vs "Metapod" 15 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 1;
+
+    let events = backend.process_turn("SuperFang", "Harden");
+    assert_event!(events[1], Damage { target: 1, amount: 1, .. });
+    assert_event!(events[2], Faint { target: 1 });
+}
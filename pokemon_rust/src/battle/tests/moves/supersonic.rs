@@ -10,8 +10,8 @@ fn supersonic_causes_confusion() {
 
     let events = backend.process_turn("Supersonic", "Harden");
 
-    assert_event!(events[1], VolatileStatusCondition { target: 1, added_flag: Flag::Confusion { .. } });
-    assert!(backend.has_flag(1, "confusion"));
+    assert_event!(events[1], VolatileStatusCondition { target: 1, status: VolatileStatus::Confusion });
+    assert!(backend.has_volatile_status(1, SimpleVolatileStatus::Confusion));
 }
 
 #[test]
@@ -0,0 +1,21 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn tailwind_doubles_the_speed_of_the_team() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Tailwind"));
+    let speed = backend.get_stat(0, Stat::Speed);
+
+    let events = backend.process_turn("Tailwind", "Harden");
+
+    assert_event!(
+        events[1],
+        AddedSideCondition { team: Team::P1, condition: SideCondition::Tailwind }
+    );
+    assert_eq!(backend.get_stat(0, Stat::Speed), speed * 2);
+}
@@ -0,0 +1,33 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn take_down_deals_a_quarter_of_the_damage_as_recoil() {
+    let mut backend = battle! {
+        "Bulbasaur" 33 (max ivs, Serious) vs "Metapod" 33 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(1).current_hp = 999;
+
+    let events = backend.process_turn("TakeDown", "Harden");
+    assert_event!(events[1], Damage { target: 1, cause: DamageCause::Move, .. });
+    assert_event!(events[2], Damage { target: 0, cause: DamageCause::Recoil, .. });
+
+    if let (BattleEvent::Damage(damage), BattleEvent::Damage(recoil)) = (&events[1], &events[2]) {
+        assert_eq!(recoil.amount, (damage.amount as f32 / 4.).round() as usize);
+    }
+}
+
+#[test]
+fn take_down_has_no_recoil_when_it_misses() {
+    let mut backend = battle! {
+        "Bulbasaur" 33 (max ivs, Serious) vs "Metapod" 33 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_miss(1);
+
+    let events = backend.process_turn("TakeDown", "Harden");
+    assert_event!(events[1], Miss { move_user: 0, .. });
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+}
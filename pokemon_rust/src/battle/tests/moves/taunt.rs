@@ -0,0 +1,17 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn taunt_prevents_the_target_from_using_status_moves() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Taunt"));
+
+    let events = backend.process_turn("Taunt", "Harden");
+
+    assert_event!(events[1], VolatileStatusCondition { target: 1, status: VolatileStatus::Taunt });
+    assert_event!(events[2], FailedMove { move_user: 1 });
+}
@@ -0,0 +1,40 @@
+use crate::battle::backend::{BattleEvent, Team};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn thrash_locks_the_user_and_confuses_it_afterwards() {
+    let mut backend = battle! {
+        "Cubone" 30 (max ivs, Serious) vs "Metapod" 30 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_rampage_duration(2);
+
+    let turn1 = backend.process_turn("Thrash", "Harden");
+    assert_event!(turn1[1], Damage { target: 1, .. });
+    assert!(backend.is_locked(Team::P1));
+
+    let turn2 = backend.process_turn("Thrash", "Harden");
+    assert_event!(turn2[0], UseMove { move_user: 0, .. });
+    assert_event!(turn2[1], Damage { target: 1, .. });
+    assert_event!(
+        turn2[2],
+        VolatileStatusCondition { target: 0, status: VolatileStatus::Confusion }
+    );
+    assert!(!backend.is_locked(Team::P1));
+}
+
+#[test]
+fn disrupted_thrash_ends_without_confusion() {
+    let mut backend = battle! {
+        "Cubone" 30 (max ivs, Serious) vs "Metapod" 30 (max ivs, Serious)
+    };
+
+    backend.process_turn("Thrash", "Harden");
+
+    test_rng_mut!(backend.rng).force_miss(1);
+    let turn2 = backend.process_turn("Thrash", "Harden");
+    assert_event!(turn2[1], Miss { target: 1, move_user: 0, .. });
+    assert!(!backend.is_locked(Team::P1));
+    assert!(!backend.has_volatile_status(0, SimpleVolatileStatus::Confusion));
+}
@@ -0,0 +1,37 @@
+use crate::{
+    battle::backend::BattleEvent,
+    pokemon::StatusCondition,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn thunder_deals_damage_and_might_paralyze() {
+    let mut backend = battle! {
+        "Pikachu" 36 (max ivs, Serious) vs "Metapod" 36 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Thunder"));
+
+    let turn1 = backend.process_turn("Thunder", "Harden");
+    assert_event!(turn1[1], Damage { target: 1, is_critical_hit: false, .. });
+    assert_eq!(backend.get_pokemon(1).status_condition, None);
+
+    test_rng_mut!(backend.rng).force_secondary_effect(1);
+    let turn2 = backend.process_turn("Thunder", "Harden");
+    assert_event!(turn2[1], Damage { target: 1, is_critical_hit: false, .. });
+    assert_eq!(backend.get_pokemon(1).status_condition, Some(StatusCondition::Paralysis));
+}
+
+#[test]
+fn thunder_hits_pokemon_that_are_flying() {
+    let mut backend = battle! {
+        "Pidgey" 30 (max ivs, Serious) vs "Metapod" 30 (max ivs, Serious)
+    };
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Fly"));
+    backend.get_pokemon_mut(1).moves[0] = Some(move_id("Thunder"));
+
+    let events = backend.process_turn("Fly", "Thunder");
+
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Damage { target: 0, .. });
+}
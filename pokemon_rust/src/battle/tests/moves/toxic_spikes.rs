@@ -0,0 +1,72 @@
+use crate::{
+    battle::backend::{BattleEvent, Team},
+    pokemon::StatusCondition,
+};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn toxic_spikes_can_be_stacked_twice() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("ToxicSpikes"));
+
+    backend.process_turn("ToxicSpikes", "Harden");
+    backend.process_turn("ToxicSpikes", "Harden");
+    let events = backend.process_turn("ToxicSpikes", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert_eq!(backend.get_side_condition_layers(Team::P2, SideCondition::ToxicSpikes), 2);
+}
+
+#[test]
+fn toxic_spikes_poison_pokemon_that_switch_in() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("ToxicSpikes"));
+    backend.process_turn("ToxicSpikes", "Harden");
+
+    backend.process_switch_in(1);
+
+    assert_eq!(backend.get_pokemon(1).status_condition, Some(StatusCondition::Poison));
+}
+
+#[test]
+fn toxic_spikes_badly_poison_pokemon_after_two_layers() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.add_side_condition(Team::P2, SideCondition::ToxicSpikes);
+    backend.add_side_condition(Team::P2, SideCondition::ToxicSpikes);
+
+    backend.process_switch_in(1);
+
+    assert_eq!(
+        backend.get_pokemon(1).status_condition,
+        Some(StatusCondition::Toxic { counter: 1 })
+    );
+}
+
+#[test]
+fn toxic_spikes_are_absorbed_by_poison_types() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Tentacool" 25 (max ivs, Serious)
+    };
+
+    backend.add_side_condition(Team::P2, SideCondition::ToxicSpikes);
+
+    backend.process_switch_in(1);
+    let events = backend.process_turn("WaterGun", "Supersonic");
+
+    assert_event!(
+        events[1],
+        ExpiredSideCondition { team: Team::P2, condition: SideCondition::ToxicSpikes }
+    );
+    assert!(!backend.has_side_condition(Team::P2, SideCondition::ToxicSpikes));
+    assert!(!backend.has_non_volatile_status_condition(1));
+}
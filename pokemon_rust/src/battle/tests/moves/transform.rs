@@ -0,0 +1,54 @@
+use crate::battle::backend::BattleEvent;
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn transform_copies_the_species_types_stats_stat_stages_and_moves_of_the_target() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[2] = Some(move_id("Harden"));
+    backend.get_pokemon_mut(1).moves[1] = Some(move_id("Transform"));
+
+    let events = backend.process_turn("Harden", "Transform");
+
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Transformed { target: 1, into: 0 });
+    assert!(backend.is_transformed(1));
+    assert_eq!(backend.get_species(1).name, "Lapras");
+    assert_eq!(backend.get_current_types(1), [PokemonType::Water, PokemonType::Ice]);
+    assert_eq!(backend.get_moves(1), backend.get_moves(0));
+    assert_eq!(backend.get_pp(1), &[5; 4]);
+
+    for stat in [Stat::Attack, Stat::Defense, Stat::SpecialAttack, Stat::Speed].iter() {
+        assert_eq!(backend.get_stat(1, *stat), backend.get_stat(0, *stat));
+    }
+
+    // HP and the Pokémon itself are left untouched
+    assert_eq!(backend.get_stat(1, Stat::HP), backend.get_pokemon(1).stats[0]);
+    assert_ne!(backend.get_stat(1, Stat::HP), backend.get_stat(0, Stat::HP));
+    assert_eq!(backend.get_pokemon(1).species_id.name(), "Metapod");
+    assert_eq!(backend.get_pokemon(1).moves[0], Some(move_id("Harden")));
+
+    let events = backend.process_turn("Harden", "WaterGun");
+
+    assert_event!(events[2], UseMove { move_user: 1, .. });
+    assert_event!(events[3], Damage { target: 0, .. });
+}
+
+#[test]
+fn transform_fails_if_the_target_is_transformed() {
+    let mut backend = battle! {
+        "Lapras" 25 (max ivs, Serious) vs "Metapod" 25 (max ivs, Serious)
+    };
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Transform"));
+    backend.get_pokemon_mut(1).moves[1] = Some(move_id("Transform"));
+
+    let events = backend.process_turn("Transform", "Transform");
+
+    assert_event!(events[1], Transformed { target: 0, into: 1 });
+    assert_event!(events[3], FailedMove { move_user: 1 });
+    assert!(!backend.is_transformed(1));
+}
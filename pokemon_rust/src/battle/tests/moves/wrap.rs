@@ -0,0 +1,43 @@
+use crate::battle::backend::{BattleEvent, DamageCause};
+
+use super::super::{prelude::*, TestMethods};
+
+#[test]
+fn wrap_damages_the_target_at_the_end_of_each_turn() {
+    let mut backend = battle! {
+        "Tentacool" 22 (max ivs, Serious) vs "Metapod" 22 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_binding_duration(3);
+    let max_hp = backend.get_pokemon(1).stats[0];
+    let expected_damage = (max_hp as f32 / 8.).ceil() as usize;
+
+    let turn1 = backend.process_turn("Wrap", "Harden");
+    assert_event!(
+        turn1[2],
+        VolatileStatusCondition { target: 1, status: VolatileStatus::Binding { user: 0, .. } }
+    );
+    assert_event!(turn1[5], Damage { target: 1, cause: DamageCause::Binding { .. }, .. });
+
+    if let BattleEvent::Damage(event_data) = &turn1[5] {
+        assert_eq!(event_data.amount, expected_damage);
+    }
+}
+
+#[test]
+fn wrap_frees_the_target_after_its_duration() {
+    let mut backend = battle! {
+        "Tentacool" 22 (max ivs, Serious) vs "Metapod" 22 (max ivs, Serious)
+    };
+
+    test_rng_mut!(backend.rng).force_binding_duration(2);
+
+    backend.process_turn("Wrap", "Harden");
+    let turn2 = backend.process_turn("Wrap", "Harden");
+
+    assert_event!(
+        turn2[turn2.len() - 2],
+        ExpiredVolatileStatusCondition { target: 1, status: VolatileStatus::Binding { .. } }
+    );
+    assert!(!backend.has_volatile_status(1, SimpleVolatileStatus::Binding));
+}
@@ -0,0 +1,78 @@
+use crate::battle::{
+    backend::{BattleEvent, FrontendEvent, FrontendEventKind, Team},
+    replay::{BattleReplay, ReplayWriter},
+};
+
+use super::prelude::*;
+
+fn record_battle(seed: u64, num_turns: usize) -> BattleReplay {
+    let p1 = pokemon_setup!("Hitmonchan" 24 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 24 (max ivs, Serious));
+    let mut replay = BattleReplay::new(create_wild_battle_data(p1, p2).with_seed(seed));
+    let mut backend = replay.create_backend();
+
+    let events: Vec<BattleEvent> = backend.tick().collect();
+    replay.record_turn(Vec::new(), &events);
+
+    for _ in 0..num_turns {
+        let frontend_events = vec![
+            FrontendEvent {
+                team: Team::P1,
+                event: FrontendEventKind::UseMove(0),
+            },
+            FrontendEvent {
+                team: Team::P2,
+                event: FrontendEventKind::UseMove(0),
+            },
+        ];
+
+        for event in &frontend_events {
+            backend.push_frontend_event(event.clone());
+        }
+
+        let events: Vec<BattleEvent> = backend.tick().collect();
+        replay.record_turn(frontend_events, &events);
+    }
+
+    replay
+}
+
+#[test]
+fn verifies_recorded_battles() {
+    let replay = record_battle(42, 3);
+
+    assert_eq!(replay.turns.len(), 4);
+    assert_eq!(replay.verify(), Ok(()));
+}
+
+#[test]
+fn verifies_deserialized_replays() {
+    let replay = record_battle(7, 3);
+    let mut writer = ReplayWriter::new(Vec::new(), &replay.battle).unwrap();
+
+    for turn in &replay.turns {
+        writer.write_turn(&turn.frontend_events, &turn.battle_events).unwrap();
+    }
+
+    let serialized = writer.into_inner();
+    let deserialized = BattleReplay::read(&serialized[..]).unwrap();
+
+    assert_eq!(serialized.iter().filter(|byte| **byte == b'\n').count(), 5);
+    assert_eq!(deserialized.battle.seed, 7);
+    assert_eq!(deserialized.turns, replay.turns);
+    assert_eq!(deserialized.verify(), Ok(()));
+}
+
+#[test]
+fn detects_diverging_replays() {
+    let mut replay = record_battle(42, 2);
+    let tampered_event = BattleEvent::ChangeTurn(ChangeTurn { new_turn: 99 });
+    replay.turns[1].battle_events[1] = tampered_event.clone();
+
+    let mismatch = replay.verify().unwrap_err();
+
+    assert_eq!(mismatch.turn, 1);
+    assert_eq!(mismatch.event_index, 1);
+    assert_eq!(mismatch.expected, Some(tampered_event));
+    assert!(mismatch.actual.is_some());
+}
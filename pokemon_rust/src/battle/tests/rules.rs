@@ -0,0 +1,171 @@
+use crate::{
+    battle::{
+        backend::BattleEvent,
+        rules::{Clause, CriticalHitModel, Ruleset, TypeChart},
+    },
+    pokemon::{Pokemon, StatusCondition},
+};
+
+use super::{prelude::*, TestMethods};
+
+fn create_battle_with_ruleset(p1: Pokemon, p2: Pokemon, ruleset: Ruleset) -> BattleBackend {
+    let battle = create_wild_battle_data(p1, p2).with_ruleset(ruleset);
+    let mut backend = BattleBackend::new(battle, Box::new(TestRng::default()));
+    let _ = backend.tick();
+
+    backend
+}
+
+#[test]
+fn uses_the_modern_type_chart_by_default() {
+    let backend = battle!("Lapras" 25 vs "Kadabra" 25);
+
+    assert_eq!(backend.get_type_effectiveness_of(PokemonType::Ghost, 1), 2.);
+    assert_eq!(backend.get_type_effectiveness_of(PokemonType::Bug, 1), 2.);
+}
+
+#[test]
+fn generation_one_type_chart_has_its_original_quirks() {
+    let backend = create_battle_with_ruleset(
+        pokemon_setup!("Lapras" 25),
+        pokemon_setup!("Kadabra" 25),
+        Ruleset::classic(),
+    );
+
+    assert_eq!(backend.get_type_effectiveness_of(PokemonType::Ghost, 1), 0.);
+    assert_eq!(backend.get_type_effectiveness_of(PokemonType::Dark, 1), 1.);
+    assert_eq!(backend.get_type_effectiveness_of(PokemonType::Bug, 1), 2.);
+    assert_eq!(
+        TypeChart::GenerationOne.get_effectiveness(PokemonType::Bug, PokemonType::Poison),
+        2.
+    );
+    assert_eq!(
+        TypeChart::GenerationOne.get_effectiveness(PokemonType::Ice, PokemonType::Fire),
+        1.
+    );
+}
+
+#[test]
+fn inverse_type_chart_swaps_weaknesses_and_resistances() {
+    let ruleset = Ruleset {
+        type_chart: TypeChart::Inverse,
+        ..Ruleset::default()
+    };
+    let backend =
+        create_battle_with_ruleset(pokemon_setup!("Lapras" 25), pokemon_setup!("Onix" 25), ruleset);
+
+    // Onix is Rock/Ground, so it's immune to Electric moves and takes 4x
+    // damage from Water moves in modern battles
+    assert_eq!(backend.get_type_effectiveness_of(PokemonType::Electric, 1), 2.);
+    assert_eq!(backend.get_type_effectiveness_of(PokemonType::Water, 1), 0.25);
+    assert_eq!(backend.get_type_effectiveness_of(PokemonType::Normal, 1), 2.);
+}
+
+#[test]
+fn deterministic_critical_hits_ignore_the_rng() {
+    let mut backend = battle!("Lapras" 25 vs "Metapod" 25);
+
+    test_rng_mut!(backend.rng).force_critical_hit(1);
+    let events = backend.process_turn("WaterGun", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: false, .. });
+}
+
+#[test]
+fn chance_based_critical_hits_depend_on_the_rng() {
+    let mut backend = create_battle_with_ruleset(
+        pokemon_setup!("Lapras" 25 (max ivs, Serious)),
+        pokemon_setup!("Metapod" 25 (max ivs, Serious)),
+        Ruleset::classic(),
+    );
+
+    test_rng_mut!(backend.rng).force_critical_hit(1);
+    let events = backend.process_turn("WaterGun", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: true, .. });
+
+    let events = backend.process_turn("WaterGun", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: false, .. });
+}
+
+#[test]
+fn high_critical_hit_moves_with_focus_energy_always_crit_with_chance_based_critical_hits() {
+    let ruleset = Ruleset {
+        critical_hits: CriticalHitModel::Chance,
+        ..Ruleset::default()
+    };
+    let mut backend = create_battle_with_ruleset(
+        pokemon_setup!("Lapras" 25),
+        pokemon_setup!("Metapod" 25),
+        ruleset,
+    );
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Slash"));
+    backend.get_pokemon_mut(0).moves[1] = Some(move_id("FocusEnergy"));
+
+    let events = backend.process_turn("Slash", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: false, .. });
+
+    backend.process_turn("FocusEnergy", "Harden");
+    let events = backend.process_turn("Slash", "Harden");
+
+    assert_event!(events[1], Damage { target: 1, is_critical_hit: true, .. });
+}
+
+#[test]
+fn ohko_and_evasion_clauses_ban_moves() {
+    let mut backend = create_battle_with_ruleset(
+        pokemon_setup!("Lapras" 25),
+        pokemon_setup!("Metapod" 25),
+        Ruleset::standard(),
+    );
+
+    backend.get_pokemon_mut(0).moves[0] = Some(move_id("Fissure"));
+    backend.get_pokemon_mut(0).moves[1] = Some(move_id("DoubleTeam"));
+
+    let movedex = get_all_moves();
+    assert!(!backend.can_use_move(0, movedex.get_move(move_id("Fissure"))));
+    assert!(!backend.can_use_move(0, movedex.get_move(move_id("DoubleTeam"))));
+    assert!(backend.can_use_move(0, movedex.get_move(move_id("Mist"))));
+
+    let events = backend.process_turn("Fissure", "Harden");
+
+    assert_event!(events[0], FailedMove { move_user: 0 });
+    assert_eq!(backend.get_pokemon(1).current_hp, backend.get_stat(1, Stat::HP));
+
+    let events = backend.process_turn("DoubleTeam", "Harden");
+
+    assert_event!(events[0], FailedMove { move_user: 0 });
+}
+
+#[test]
+fn sleep_clause_prevents_putting_a_second_pokemon_of_a_team_to_sleep() {
+    let build = |ruleset: Ruleset| {
+        let mut battle = create_wild_battle_data(
+            pokemon_setup!("Lapras" 25 (max ivs, Serious)),
+            pokemon_setup!("Metapod" 25 (max ivs, Serious)),
+        )
+        .with_ruleset(ruleset);
+        battle.p2.party.pokemon.push_back(pokemon_setup!("Caterpie" 25));
+
+        let mut backend = BattleBackend::new(battle, Box::new(TestRng::default()));
+        let _ = backend.tick();
+
+        backend.get_pokemon_mut(2).status_condition =
+            Some(StatusCondition::Sleep { remaining_turns: 2 });
+        backend
+    };
+
+    let mut backend = build(Ruleset::default().with_clause(Clause::Sleep));
+    let events = backend.process_turn("Sing", "Harden");
+
+    assert_event!(events[1], FailedMove { move_user: 0 });
+    assert!(!backend.has_non_volatile_status_condition(1));
+
+    let mut backend = build(Ruleset::default());
+    let events = backend.process_turn("Sing", "Harden");
+
+    assert_event!(events[1], NonVolatileStatusCondition { target: 1, condition: StatusCondition::Sleep { .. } });
+}
@@ -0,0 +1,257 @@
+//! Battle tests written as data. Every RON file in this directory contains a
+//! list of scenarios, each of which sets up a wild battle between two
+//! Pokémon, plays some turns and checks the events of each turn:
+//!
+//! ```ron
+//! [
+//!     (
+//!         name: "tackle deals damage",
+//!         p1: (species: "Rattata", level: 5, nature: Some(Adamant)),
+//!         p2: (species: "Pidgey", level: 5),
+//!         turns: [
+//!             (
+//!                 rng: Some((misses: 1)),
+//!                 moves: ("Tackle", "Tackle"),
+//!                 events: [
+//!                     "UseMove(move_user: 0, ..)",
+//!                     "Miss(target: 1, ..)",
+//!                     "_",
+//!                     "Damage(target: 0, amount: 7, ..)",
+//!                     "..",
+//!                 ],
+//!             ),
+//!         ],
+//!     ),
+//! ]
+//! ```
+//!
+//! The Pokémon are `PokemonSet`s, as in team files, except that their IVs
+//! default to 31 and their nature to Serious so that scenarios don't depend
+//! on chance. The expected events are patterns (see `pattern`), and a
+//! trailing `..` allows more events than the listed ones.
+
+mod pattern;
+
+use crate::{
+    battle::backend::BattleEvent,
+    pokemon::{team::PokemonSet, Nature, Pokemon},
+};
+
+use amethyst::utils::application_root_dir;
+
+use ron::{de::from_str, ser::to_string};
+
+use serde::Deserialize;
+
+use std::fs;
+
+use self::pattern::Pattern;
+
+use super::{prelude::*, TestMethods};
+
+#[derive(Debug, Deserialize)]
+struct Scenario {
+    name: String,
+    p1: PokemonSet,
+    p2: PokemonSet,
+    turns: Vec<ScenarioTurn>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ScenarioTurn {
+    /// The outcomes of the RNG during this turn and the following ones.
+    #[serde(default)]
+    rng: Option<ScriptedRng>,
+    /// The moves that each team uses.
+    moves: (String, String),
+    events: Vec<String>,
+    /// The expected chance of the last accuracy check of this turn, which is
+    /// `Some(None)` if there shouldn't be any. Not checked if missing.
+    #[serde(default)]
+    miss_chance: Option<Option<usize>>,
+}
+
+/// Forced outcomes of the RNG of a battle. Each one is kept until it's used
+/// up or replaced by the `rng` of a later turn, in which the outcomes that
+/// are missing or zero clear the previous ones.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ScriptedRng {
+    /// How many of the next accuracy checks fail.
+    misses: usize,
+    /// How many of the next secondary effect checks succeed.
+    secondary_effects: usize,
+    /// How many of the next moves land a critical hit.
+    critical_hits: usize,
+    /// The number of hits of moves that hit from 2 to 5 times.
+    multi_hits: Option<usize>,
+    /// The percentage of the maximum damage that moves deal, from 85 to 100.
+    damage_roll: Option<usize>,
+}
+
+impl ScriptedRng {
+    fn apply(&self, backend: &mut BattleBackend) {
+        let rng = test_rng_mut!(backend.rng);
+
+        rng.force_miss(self.misses);
+        rng.force_secondary_effect(self.secondary_effects);
+        rng.force_critical_hit(self.critical_hits);
+
+        match self.multi_hits {
+            Some(hits) => rng.force_uniform_multi_hit_value(hits),
+            None => rng.clear_uniform_multi_hit_value(),
+        }
+
+        match self.damage_roll {
+            Some(roll) => rng.force_damage_roll(roll),
+            None => rng.clear_damage_roll(),
+        }
+    }
+}
+
+fn build_pokemon(set: &PokemonSet) -> Result<Pokemon, String> {
+    let set = PokemonSet {
+        nature: set.nature.or(Some(Nature::Serious)),
+        ivs: set.ivs.or(Some([31; 6])),
+        ..set.clone()
+    };
+
+    set.build().map_err(|err| err.to_string())
+}
+
+fn parse_event(event: &BattleEvent) -> Pattern {
+    let text = to_string(event).unwrap();
+
+    Pattern::parse(&text).unwrap_or_else(|err| panic!("Unparseable event {}: {}", text, err))
+}
+
+/// Plays a scenario, returning a description of the first turn that didn't
+/// go as expected.
+fn run_scenario(scenario: &Scenario) -> Result<(), String> {
+    let p1 = build_pokemon(&scenario.p1)?;
+    let p2 = build_pokemon(&scenario.p2)?;
+    let mut backend = create_simple_wild_battle(p1, p2);
+    let _ = backend.tick();
+
+    for (index, turn) in scenario.turns.iter().enumerate() {
+        let turn_number = index + 1;
+        let expected = Pattern::parse_list(&turn.events)
+            .map_err(|err| format!("turn {}: invalid pattern: {}", turn_number, err))?;
+
+        if let Some(rng) = &turn.rng {
+            rng.apply(&mut backend);
+        }
+
+        test_rng_mut!(backend.rng).reset_last_miss_check_chance();
+        let events = backend.process_turn(&turn.moves.0, &turn.moves.1);
+
+        let actual = Pattern::Group {
+            name: None,
+            is_list: true,
+            items: events
+                .iter()
+                .map(|event| pattern::Item {
+                    field: None,
+                    pattern: parse_event(event),
+                })
+                .collect(),
+            has_rest: false,
+        };
+
+        if !expected.matches(&actual) {
+            let describe = |events: Vec<String>| events.join("\n\t\t");
+
+            return Err(format!(
+                "turn {}: events don't match\n\texpected:\n\t\t{}\n\tactual:\n\t\t{}",
+                turn_number,
+                describe(turn.events.clone()),
+                describe(events.iter().map(|event| to_string(event).unwrap()).collect()),
+            ));
+        }
+
+        if let Some(chance) = turn.miss_chance {
+            let actual_chance = test_rng!(backend.rng).get_last_miss_check_chance();
+
+            if actual_chance != chance {
+                let describe = |chance: Option<usize>| match chance {
+                    Some(chance) => format!("an accuracy check with chance {}", chance),
+                    None => "no accuracy check".to_string(),
+                };
+
+                return Err(format!(
+                    "turn {}: expected {}, got {}",
+                    turn_number,
+                    describe(chance),
+                    describe(actual_chance),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn runs_every_scenario() {
+    let directory = application_root_dir()
+        .unwrap()
+        .join("src")
+        .join("battle")
+        .join("tests")
+        .join("scenarios");
+
+    let mut paths: Vec<_> = fs::read_dir(&directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |extension| extension == "ron"))
+        .collect();
+    paths.sort();
+
+    assert!(!paths.is_empty(), "No scenarios found in {}", directory.display());
+
+    let mut failures = Vec::new();
+
+    for path in paths {
+        let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let content = fs::read_to_string(&path).unwrap();
+
+        let scenarios: Vec<Scenario> = match from_str(&content) {
+            Ok(scenarios) => scenarios,
+            Err(err) => {
+                failures.push(format!("{}: {}", file_name, err));
+                continue;
+            },
+        };
+
+        for scenario in &scenarios {
+            if let Err(err) = run_scenario(scenario) {
+                failures.push(format!("{}: \"{}\", {}", file_name, scenario.name, err));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n\n"));
+}
+
+#[test]
+fn patterns_match_fields_in_any_order() {
+    let pattern = Pattern::parse("Miss(move_user: 0, target: 1)").unwrap();
+
+    assert!(pattern.matches(&Pattern::parse("Miss((target: 1, move_user: 0))").unwrap()));
+    assert!(!pattern.matches(&Pattern::parse("Miss((target: 0, move_user: 1))").unwrap()));
+}
+
+#[test]
+fn patterns_need_rest_to_skip_fields() {
+    let value = Pattern::parse("Damage((target: 1, amount: 9, cause: Move))").unwrap();
+
+    assert!(!Pattern::parse("Damage(target: 1)").unwrap().matches(&value));
+    assert!(Pattern::parse("Damage(target: 1, ..)").unwrap().matches(&value));
+    assert!(Pattern::parse("Damage(target: _, amount: 9, cause: _)").unwrap().matches(&value));
+    assert!(!Pattern::parse("Heal(target: 1, ..)").unwrap().matches(&value));
+}
+
+#[test]
+fn rejects_rest_before_other_items() {
+    assert!(Pattern::parse("Damage(.., target: 1)").is_err());
+}
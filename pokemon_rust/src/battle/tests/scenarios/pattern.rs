@@ -0,0 +1,284 @@
+//! Patterns that match battle events in their RON representation, written
+//! like Rust patterns: `Damage(target: 1, amount: 9, ..)` matches any damage
+//! event against the Pokémon 1 that deals 9 damage. `_` matches any value and
+//! a trailing `..` matches the remaining fields or list items.
+//!
+//! Newtype variants don't need their inner parentheses, i.e `Damage(...)` is
+//! the same as `Damage((...))`.
+
+use std::{iter::Peekable, str::Chars};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pattern {
+    Wildcard,
+    /// A number, string or unit variant, compared by its text.
+    Atom(String),
+    /// A struct, tuple, enum variant or list.
+    Group {
+        name: Option<String>,
+        is_list: bool,
+        items: Vec<Item>,
+        has_rest: bool,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Item {
+    pub field: Option<String>,
+    pub pattern: Pattern,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Open(char),
+    Close(char),
+    Comma,
+    Colon,
+    Rest,
+    Word(String),
+}
+
+impl Pattern {
+    pub fn parse(text: &str) -> Result<Pattern, String> {
+        let tokens = tokenize(text)?;
+        let mut tokens = tokens.into_iter().peekable();
+        let pattern = parse_pattern(&mut tokens)?;
+
+        match tokens.next() {
+            None => Ok(pattern),
+            Some(token) => Err(format!("unexpected {:?} in `{}`", token, text)),
+        }
+    }
+
+    /// Parses a list of patterns, the last of which may be `..`.
+    pub fn parse_list(patterns: &[String]) -> Result<Pattern, String> {
+        let has_rest = patterns.last().map_or(false, |pattern| pattern.trim() == "..");
+        let num_items = patterns.len() - if has_rest { 1 } else { 0 };
+
+        let items = patterns[..num_items]
+            .iter()
+            .map(|pattern| {
+                Ok(Item {
+                    field: None,
+                    pattern: Pattern::parse(pattern)?,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Pattern::Group {
+            name: None,
+            is_list: true,
+            items,
+            has_rest,
+        })
+    }
+
+    pub fn matches(&self, value: &Pattern) -> bool {
+        match (self, value) {
+            (Pattern::Wildcard, _) => true,
+            (Pattern::Atom(expected), Pattern::Atom(actual)) => expected == actual,
+            (
+                Pattern::Group {
+                    name,
+                    is_list,
+                    items,
+                    has_rest,
+                },
+                Pattern::Group {
+                    name: actual_name,
+                    is_list: actual_is_list,
+                    items: actual_items,
+                    ..
+                },
+            ) => {
+                name == actual_name
+                    && is_list == actual_is_list
+                    && (*has_rest || items.len() == actual_items.len())
+                    && items_match(items, actual_items)
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Checks that every field of a pattern is present in a value, and that
+/// the positional items of the pattern match the first ones of the value.
+fn items_match(items: &[Item], actual_items: &[Item]) -> bool {
+    let mut positional_items = actual_items.iter().filter(|item| item.field.is_none());
+
+    items.iter().all(|item| {
+        let actual_item = match &item.field {
+            Some(field) => actual_items
+                .iter()
+                .find(|actual_item| actual_item.field.as_ref() == Some(field)),
+            None => positional_items.next(),
+        };
+
+        actual_item.map_or(false, |actual_item| item.pattern.matches(&actual_item.pattern))
+    })
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '(' | '[' => {
+                chars.next();
+                tokens.push(Token::Open(c));
+            },
+            ')' | ']' => {
+                chars.next();
+                tokens.push(Token::Close(c));
+            },
+            ',' => {
+                chars.next();
+                tokens.push(Token::Comma);
+            },
+            ':' => {
+                chars.next();
+                tokens.push(Token::Colon);
+            },
+            '"' => tokens.push(Token::Word(read_string(&mut chars)?)),
+            _ if c.is_whitespace() => {
+                chars.next();
+            },
+            _ => {
+                let mut word = String::new();
+
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "()[],:\"".contains(c) {
+                        break;
+                    }
+
+                    word.push(c);
+                    chars.next();
+                }
+
+                if word == ".." {
+                    tokens.push(Token::Rest);
+                } else {
+                    tokens.push(Token::Word(word));
+                }
+            },
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Reads a string literal, keeping its quotes and escapes so that it can be
+/// compared to other literals by its text.
+fn read_string(chars: &mut Peekable<Chars<'_>>) -> Result<String, String> {
+    let mut string = chars.next().unwrap().to_string();
+
+    while let Some(c) = chars.next() {
+        string.push(c);
+
+        match c {
+            '\\' => string.extend(chars.next()),
+            '"' => return Ok(string),
+            _ => {},
+        }
+    }
+
+    Err(format!("unterminated string {}", string))
+}
+
+fn parse_pattern(tokens: &mut Peekable<impl Iterator<Item = Token>>) -> Result<Pattern, String> {
+    match tokens.next() {
+        Some(Token::Word(word)) => {
+            if word == "_" {
+                Ok(Pattern::Wildcard)
+            } else if tokens.peek() == Some(&Token::Open('(')) {
+                tokens.next();
+                parse_group(tokens, Some(word), ')')
+            } else {
+                Ok(Pattern::Atom(word))
+            }
+        },
+        Some(Token::Open('(')) => parse_group(tokens, None, ')'),
+        Some(Token::Open('[')) => parse_group(tokens, None, ']'),
+        Some(token) => Err(format!("unexpected {:?}", token)),
+        None => Err("unexpected end of pattern".to_string()),
+    }
+}
+
+/// Parses the items of a group up to its closing delimiter, which was
+/// already opened.
+fn parse_group(
+    tokens: &mut Peekable<impl Iterator<Item = Token>>,
+    name: Option<String>,
+    delimiter: char,
+) -> Result<Pattern, String> {
+    let mut items = Vec::new();
+    let mut has_rest = false;
+
+    loop {
+        match tokens.peek() {
+            Some(Token::Close(c)) if *c == delimiter => {
+                tokens.next();
+                break;
+            },
+            Some(Token::Rest) => {
+                tokens.next();
+                has_rest = true;
+            },
+            _ if has_rest => return Err("`..` must be the last item".to_string()),
+            _ => {
+                let pattern = parse_pattern(tokens)?;
+                let is_field = tokens.peek() == Some(&Token::Colon);
+
+                let item = match (pattern, is_field) {
+                    (Pattern::Atom(field), true) => {
+                        tokens.next();
+
+                        Item {
+                            field: Some(field),
+                            pattern: parse_pattern(tokens)?,
+                        }
+                    },
+                    (pattern, _) => Item {
+                        field: None,
+                        pattern,
+                    },
+                };
+
+                items.push(item);
+            },
+        }
+
+        match tokens.next() {
+            Some(Token::Comma) => {},
+            Some(Token::Close(c)) if c == delimiter => break,
+            Some(token) => return Err(format!("unexpected {:?}", token)),
+            None => return Err(format!("missing `{}`", delimiter)),
+        }
+    }
+
+    // Newtype variants wrap their content in another pair of parentheses
+    if name.is_some() && !has_rest && items.len() == 1 && items[0].field.is_none() {
+        if let Pattern::Group {
+            name: None,
+            is_list: false,
+            items: inner_items,
+            has_rest: inner_has_rest,
+        } = &items[0].pattern
+        {
+            return Ok(Pattern::Group {
+                name,
+                is_list: false,
+                items: inner_items.clone(),
+                has_rest: *inner_has_rest,
+            });
+        }
+    }
+
+    Ok(Pattern::Group {
+        name,
+        is_list: delimiter == ']',
+        items,
+        has_rest,
+    })
+}
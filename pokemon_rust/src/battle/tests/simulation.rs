@@ -0,0 +1,142 @@
+use crate::{
+    battle::{
+        ai::{BackgroundAi, BattleAi, ExpertAi, FirstMoveAi, GreedyAi, RandomAi},
+        backend::Team,
+        simulation::{create_trainer_battle, simulate_battle},
+    },
+    pokemon::team::{build_team, PokemonSet},
+};
+
+use ron::de::from_str;
+
+use std::{thread, time::Duration};
+
+use super::prelude::*;
+
+#[test]
+fn simulates_battles_until_a_pokemon_faints() {
+    let p1 = pokemon_setup!("Hitmonchan" 30 (max ivs, Adamant));
+    let p2 = pokemon_setup!("Metapod" 5 (max ivs, Serious));
+    let battle = create_trainer_battle(vec![p1], vec![p2], 42);
+
+    let result = simulate_battle(battle, &mut GreedyAi, &mut FirstMoveAi, 100);
+
+    assert_eq!(result.winner, Some(Team::P1));
+    assert!(result.turns < 100);
+    assert!(result.used_moves.iter().any(|(team, _)| *team == Team::P1));
+}
+
+#[test]
+fn stops_simulations_at_the_turn_limit() {
+    let p1 = pokemon_setup!("Metapod" 20 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 20 (max ivs, Serious));
+    let battle = create_trainer_battle(vec![p1], vec![p2], 42);
+
+    // Metapod only knows Harden
+    let result = simulate_battle(battle, &mut FirstMoveAi, &mut FirstMoveAi, 5);
+
+    assert_eq!(result.turns, 5);
+}
+
+#[test]
+fn simulations_are_reproducible() {
+    let p1 = pokemon_setup!("Hitmonchan" 24 (max ivs, Serious));
+    let p2 = pokemon_setup!("Jynx" 24 (max ivs, Serious));
+
+    let simulate = |seed| {
+        let battle = create_trainer_battle(vec![p1.clone()], vec![p2.clone()], seed);
+        let result = simulate_battle(battle, &mut RandomAi::new(1), &mut RandomAi::new(2), 100);
+
+        (result.winner, result.turns, result.used_moves)
+    };
+
+    assert_eq!(simulate(7), simulate(7));
+}
+
+#[test]
+fn expert_ai_beats_random_ai() {
+    let team: Vec<PokemonSet> = from_str(r#"[
+        (
+            species: "Hitmonchan",
+            level: 30,
+            nature: Some(Serious),
+            moves: ["Agility", "FocusEnergy", "Tackle", "ThunderPunch"],
+            ivs: Some((31, 31, 31, 31, 31, 31)),
+        ),
+    ]"#).unwrap();
+    let pokemon = build_team(&team).unwrap();
+    let num_battles = 20;
+
+    let wins = (0..num_battles)
+        .filter(|seed| {
+            let battle = create_trainer_battle(pokemon.clone(), pokemon.clone(), *seed);
+            let mut expert = ExpertAi::new(*seed);
+            let mut random = RandomAi::new(*seed);
+
+            simulate_battle(battle, &mut expert, &mut random, 100).winner == Some(Team::P1)
+        })
+        .count();
+
+    assert!(wins >= num_battles as usize * 3 / 4, "The expert AI only won {} battles", wins);
+}
+
+#[test]
+fn expert_ai_searches_without_changing_the_battle() {
+    let p1 = pokemon_setup!("Hitmonchan" 24 (max ivs, Serious));
+    let p2 = pokemon_setup!("Jynx" 24 (max ivs, Serious));
+    let battle = create_trainer_battle(vec![p1], vec![p2], 3);
+    let mut backend = BattleBackend::new(battle, Box::new(StandardBattleRng::new(3)));
+    backend.tick().for_each(drop);
+
+    let mut ai = ExpertAi::new(3);
+    let event = ai.choose_event(&backend, Team::P1);
+
+    assert_eq!(event.team, Team::P1);
+    assert_eq!(backend.get_pokemon(0).current_hp, backend.get_stat(0, Stat::HP));
+    assert_eq!(backend.get_pokemon(1).current_hp, backend.get_stat(1, Stat::HP));
+}
+
+#[test]
+fn background_ais_think_on_another_thread() {
+    let p1 = pokemon_setup!("Lapras" 25);
+    let p2 = pokemon_setup!("Metapod" 25);
+    let mut backend = create_simple_trainer_battle(p1, p2);
+    backend.tick().for_each(drop);
+
+    let mut ai = BackgroundAi::new(Box::new(GreedyAi), Team::P2);
+    ai.start(&backend);
+
+    let event = loop {
+        match ai.poll() {
+            Some(event) => break event,
+            None => thread::sleep(Duration::from_millis(1)),
+        }
+    };
+
+    assert_eq!(event.team, Team::P2);
+    assert!(ai.poll().is_none());
+}
+
+#[test]
+fn builds_teams_from_pokemon_sets() {
+    let team: Vec<PokemonSet> = from_str(r#"[
+        (species: "Lapras", level: 30, nature: Some(Modest), moves: ["IceBeam", "Psychic"]),
+        (species: "Pidgey", level: 5),
+    ]"#).unwrap();
+
+    let pokemon = build_team(&team).unwrap();
+
+    assert_eq!(pokemon.len(), 2);
+    assert_eq!(pokemon[0].moves[0], Some(move_id("IceBeam")));
+    assert_eq!(pokemon[0].moves[2], None);
+    assert_eq!(pokemon[1].level, 5);
+}
+
+#[test]
+fn rejects_unknown_moves_in_pokemon_sets() {
+    let team: Vec<PokemonSet> = from_str(r#"[
+        (species: "Lapras", level: 30, moves: ["NotAMove"]),
+    ]"#).unwrap();
+
+    assert!(build_team(&team).is_err());
+}
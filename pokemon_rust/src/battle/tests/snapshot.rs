@@ -0,0 +1,126 @@
+use crate::{
+    battle::backend::{
+        rng::{BattleRng, BattleRngState},
+        snapshot::BattleSnapshot,
+        BattleEvent,
+    },
+    pokemon::StatusCondition,
+};
+
+use ron::{de::from_str, ser::to_string};
+
+use super::{prelude::*, TestMethods};
+
+fn create_battle(seed: u64) -> BattleBackend {
+    let p1 = pokemon_setup!("Hitmonchan" 24 (max ivs, Serious));
+    let p2 = pokemon_setup!("Metapod" 24 (max ivs, Serious));
+    let mut backend = create_seeded_wild_battle(p1, p2, seed);
+    let _ = backend.tick();
+
+    backend
+}
+
+fn process_turns(backend: &mut BattleBackend, num_turns: usize) -> Vec<BattleEvent> {
+    (0..num_turns)
+        .flat_map(|_| {
+            backend.move_p1(0);
+            backend.move_p2(0);
+            backend.tick().collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn restoring_a_snapshot_undoes_turns() {
+    let mut backend = create_battle(7);
+    process_turns(&mut backend, 2);
+
+    let snapshot = backend.snapshot().unwrap();
+    let events = process_turns(&mut backend, 3);
+
+    backend.restore(&snapshot);
+
+    assert_eq!(snapshot.get_turn(), 3);
+    assert_eq!(process_turns(&mut backend, 3), events);
+}
+
+#[test]
+fn cloned_backends_are_independent() {
+    let mut backend = create_battle(7);
+    let mut lookahead = backend.clone();
+
+    let lookahead_events = process_turns(&mut lookahead, 2);
+
+    assert_eq!(backend.get_pokemon(1).current_hp, backend.get_stat(1, Stat::HP));
+    assert_eq!(process_turns(&mut backend, 2), lookahead_events);
+}
+
+#[test]
+fn snapshots_survive_serialization() {
+    let mut backend = create_battle(11);
+    backend.add_non_volatile_status_condition(0, StatusCondition::Burn);
+    process_turns(&mut backend, 1);
+
+    let serialized = to_string(&backend.snapshot().unwrap()).unwrap();
+    let snapshot: BattleSnapshot = from_str(&serialized).unwrap();
+    let mut restored = BattleBackend::from_snapshot(snapshot);
+
+    assert_eq!(
+        restored.get_pokemon(0).status_condition,
+        Some(StatusCondition::Burn)
+    );
+    assert_eq!(process_turns(&mut restored, 3), process_turns(&mut backend, 3));
+}
+
+#[test]
+fn snapshots_keep_the_state_of_the_rng() {
+    let mut backend = create_battle(3);
+    process_turns(&mut backend, 2);
+
+    match backend.snapshot().unwrap().get_rng_state() {
+        BattleRngState::Standard { seed, words } => {
+            assert_eq!(*seed, 3);
+            assert!(*words > 0);
+        },
+    }
+}
+
+#[test]
+fn rng_states_continue_where_they_were_saved() {
+    // Covers saving before, inside and across the blocks of the generator
+    for num_draws in &[0, 1, 15, 16, 17, 63, 64, 100] {
+        let mut rng = StandardBattleRng::new(5);
+
+        for _ in 0..*num_draws {
+            rng.get_psywave_percentage();
+        }
+
+        let mut restored = rng.get_state().unwrap().create_rng();
+
+        for _ in 0..100 {
+            assert_eq!(restored.get_psywave_percentage(), rng.get_psywave_percentage());
+        }
+    }
+}
+
+#[test]
+fn battles_with_test_rngs_cannot_be_saved() {
+    let backend = battle!("Lapras" 25 vs "Metapod" 25);
+
+    assert!(backend.snapshot().is_none());
+}
+
+#[test]
+fn digests_identify_the_state_of_a_battle() {
+    let mut backend = create_battle(7);
+    let snapshot = backend.snapshot().unwrap();
+    let digest = backend.get_state_digest();
+
+    assert_eq!(backend.clone().get_state_digest(), digest);
+
+    process_turns(&mut backend, 1);
+    assert_ne!(backend.get_state_digest(), digest);
+
+    backend.restore(&snapshot);
+    assert_eq!(backend.get_state_digest(), digest);
+}
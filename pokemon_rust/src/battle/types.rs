@@ -1,11 +1,23 @@
 use amethyst::ecs::{Component, DenseVecStorage};
 
-use crate::{overworld::entities::character::CharacterId, pokemon::Pokemon};
+use crate::{
+    overworld::entities::character::CharacterId,
+    pokemon::{
+        validation::{validate_team_for_ruleset, LegalityViolation},
+        Pokemon,
+    },
+};
+
+use rand::random;
+
+use serde::{Deserialize, Serialize};
 
 use std::collections::VecDeque;
 
+use super::rules::Ruleset;
+
 /// Represents a Pokémon Battle.
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Battle {
     /// The type of battle that is happening.
     pub battle_type: BattleType,
@@ -16,6 +28,13 @@ pub struct Battle {
     pub p1: BattleCharacterTeam,
     /// The Pokémon that make up the second team.
     pub p2: BattleCharacterTeam,
+    /// The seed of the random number generator of this battle. Together with
+    /// the actions taken by each team, it fully determines the outcome of the
+    /// battle.
+    pub seed: u64,
+    /// The mechanics and clauses that this battle follows.
+    #[serde(default)]
+    pub ruleset: Ruleset,
 }
 
 impl Battle {
@@ -29,18 +48,32 @@ impl Battle {
             turn: 0,
             p1,
             p2,
+            seed: random(),
+            ruleset: Ruleset::default(),
         }
     }
+
+    /// Replaces the randomly generated seed of this battle.
+    pub fn with_seed(mut self, seed: u64) -> Battle {
+        self.seed = seed;
+        self
+    }
+
+    /// Replaces the default ruleset of this battle.
+    pub fn with_ruleset(mut self, ruleset: Ruleset) -> Battle {
+        self.ruleset = ruleset;
+        self
+    }
 }
 
 /// Represents the type of battle that is happening.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum BattleType {
     Single,
 }
 
 /// Represents which Pokémon make up a team.
-#[derive(Clone)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct BattleCharacterTeam {
     // TODO: change this to a Vec to implement doubles/triples
     /// The active Pokémon of this team.
@@ -51,7 +84,16 @@ pub struct BattleCharacterTeam {
     pub character_id: Option<CharacterId>,
 }
 
-#[derive(Clone)]
+impl BattleCharacterTeam {
+    /// Checks the legality of every Pokémon of this team under a ruleset. The
+    /// indices of the violations count the active Pokémon, if any, before the
+    /// party.
+    pub fn validate(&self, ruleset: &Ruleset) -> Vec<(usize, LegalityViolation)> {
+        validate_team_for_ruleset(self.active_pokemon.iter().chain(&self.party.pokemon), ruleset)
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Party {
     pub pokemon: VecDeque<Pokemon>,
 }
@@ -0,0 +1,190 @@
+//! Runs many battles between two teams without opening a window and prints
+//! statistics about them.
+//!
+//! Usage: `battle_sim <p1 team> <p2 team> [options]`, where each team is a
+//! RON file as described in `pokemon::team`. Options:
+//!   --battles N     the number of battles to run (default: 100)
+//!   --seed S        the seed of the first battle; battle i uses S + i
+//!   --p1-ai NAME    the AI of the first team: first, random, greedy or expert
+//!   --p2-ai NAME    the AI of the second team (default for both: random)
+//!   --max-turns T   turns after which a battle is a draw (default: 100)
+//!   --ruleset NAME  the ruleset of the battles: default, classic or standard
+//!   --allow-illegal run the battles even if a team has illegal Pokémon
+
+use pokemon_rust::{
+    battle::{
+        ai::create_ai,
+        backend::Team,
+        rules::create_ruleset,
+        simulation::{create_trainer_battle, simulate_battle},
+    },
+    pokemon::{
+        team::{build_team, load_team},
+        validation::validate_team_for_ruleset,
+    },
+};
+
+use std::{collections::BTreeMap, env, process, str::FromStr};
+
+struct Options {
+    p1_team_path: String,
+    p2_team_path: String,
+    num_battles: usize,
+    seed: u64,
+    p1_ai: String,
+    p2_ai: String,
+    max_turns: usize,
+    ruleset: String,
+    allow_illegal: bool,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut positional = Vec::new();
+    let mut options = Options {
+        p1_team_path: String::new(),
+        p2_team_path: String::new(),
+        num_battles: 100,
+        seed: 0,
+        p1_ai: "random".to_string(),
+        p2_ai: "random".to_string(),
+        max_turns: 100,
+        ruleset: "default".to_string(),
+        allow_illegal: false,
+    };
+
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--battles" => options.num_battles = parse_number(&next_value(&mut args, &arg)?)?,
+            "--seed" => options.seed = parse_number(&next_value(&mut args, &arg)?)?,
+            "--p1-ai" => options.p1_ai = next_value(&mut args, &arg)?,
+            "--p2-ai" => options.p2_ai = next_value(&mut args, &arg)?,
+            "--max-turns" => options.max_turns = parse_number(&next_value(&mut args, &arg)?)?,
+            "--ruleset" => options.ruleset = next_value(&mut args, &arg)?,
+            "--allow-illegal" => options.allow_illegal = true,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() != 2 {
+        return Err("Expected exactly two team files".to_string());
+    }
+
+    options.p2_team_path = positional.pop().unwrap();
+    options.p1_team_path = positional.pop().unwrap();
+
+    Ok(options)
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("Missing value for {}", option))
+}
+
+fn parse_number<T: FromStr>(value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Invalid number: {}", value))
+}
+
+fn percentage(value: usize, total: usize) -> f32 {
+    if total == 0 {
+        0.
+    } else {
+        100. * value as f32 / total as f32
+    }
+}
+
+fn main() {
+    let options = parse_options().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!("Usage: battle_sim <p1 team> <p2 team> [--battles N] [--seed S] \
+                   [--p1-ai NAME] [--p2-ai NAME] [--max-turns T] [--ruleset NAME] \
+                   [--allow-illegal]");
+        process::exit(2);
+    });
+
+    let ruleset = create_ruleset(&options.ruleset).unwrap_or_else(|| {
+        eprintln!("Unknown ruleset: {}", options.ruleset);
+        process::exit(2);
+    });
+
+    let load = |path: &str| {
+        let team = load_team(path)
+            .and_then(|team| build_team(&team))
+            .unwrap_or_else(|err| {
+                eprintln!("Failed loading team {}: {}", path, err);
+                process::exit(1);
+            });
+
+        let violations = validate_team_for_ruleset(&team, &ruleset);
+
+        for (index, violation) in &violations {
+            let species_id = &team[*index].species_id;
+            eprintln!("{}: {} (Pokémon {}): {}", path, species_id, index + 1, violation);
+        }
+
+        if !violations.is_empty() && !options.allow_illegal {
+            eprintln!("Illegal team {}. Use --allow-illegal to run it anyway.", path);
+            process::exit(1);
+        }
+
+        team
+    };
+
+    let p1_team = load(&options.p1_team_path);
+    let p2_team = load(&options.p2_team_path);
+
+    let mut wins = [0, 0];
+    let mut draws = 0;
+    let mut total_turns = 0;
+    let mut move_usage: [BTreeMap<&str, usize>; 2] = Default::default();
+
+    for index in 0..options.num_battles {
+        let seed = options.seed.wrapping_add(index as u64);
+        let create = |name: &str, ai_seed: u64| {
+            create_ai(name, ai_seed).unwrap_or_else(|| {
+                eprintln!("Unknown AI: {}", name);
+                process::exit(2);
+            })
+        };
+
+        let mut p1_ai = create(&options.p1_ai, seed.wrapping_mul(2));
+        let mut p2_ai = create(&options.p2_ai, seed.wrapping_mul(2).wrapping_add(1));
+
+        let battle = create_trainer_battle(p1_team.clone(), p2_team.clone(), seed)
+            .with_ruleset(ruleset.clone());
+        let result = simulate_battle(battle, &mut *p1_ai, &mut *p2_ai, options.max_turns);
+
+        match result.winner {
+            Some(Team::P1) => wins[0] += 1,
+            Some(Team::P2) => wins[1] += 1,
+            None => draws += 1,
+        }
+
+        total_turns += result.turns;
+
+        for (team, move_id) in result.used_moves {
+            *move_usage[team as usize].entry(move_id.name()).or_insert(0) += 1;
+        }
+    }
+
+    let num_battles = options.num_battles;
+
+    println!("Battles: {}", num_battles);
+    println!("P1 wins: {} ({:.1}%)", wins[0], percentage(wins[0], num_battles));
+    println!("P2 wins: {} ({:.1}%)", wins[1], percentage(wins[1], num_battles));
+    println!("Draws: {} ({:.1}%)", draws, percentage(draws, num_battles));
+    println!(
+        "Average turns: {:.2}",
+        total_turns as f32 / num_battles.max(1) as f32
+    );
+
+    for (team_name, usage) in ["P1", "P2"].iter().zip(&move_usage) {
+        let total: usize = usage.values().sum();
+        println!("{} move usage:", team_name);
+
+        for (move_id, count) in usage {
+            println!("  {}: {} ({:.1}%)", move_id, count, percentage(*count, total));
+        }
+    }
+}
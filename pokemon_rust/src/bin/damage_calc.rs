@@ -0,0 +1,159 @@
+//! Calculates the damage that a move of the first Pokémon of a team deals to
+//! the first Pokémon of another team, and prints every possible roll.
+//!
+//! Usage: `damage_calc <attacker team> <defender team> <move id> [options]`,
+//! where each team is a RON file as described in `pokemon::team`. Options:
+//!   --crit          assume the move lands a critical hit
+//!   --burned        assume the attacker is burned
+//!   --screens       assume Reflect or Light Screen protects the defender
+//!   --weather NAME  the weather of the battle: sun or rain
+//!   --ruleset NAME  the ruleset of the battle: default, classic or standard
+
+use pokemon_rust::{
+    battle::{
+        backend::{
+            damage_calc::{DamageCalcOptions, Weather},
+            rng::StandardBattleRng,
+            BattleBackend,
+            Team,
+        },
+        rules::create_ruleset,
+        simulation::create_trainer_battle,
+    },
+    pokemon::{
+        get_all_moves,
+        team::{build_team, load_team},
+    },
+};
+
+use std::{env, process};
+
+struct Options {
+    attacker_team_path: String,
+    defender_team_path: String,
+    move_id: String,
+    calc_options: DamageCalcOptions,
+    ruleset: String,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut positional = Vec::new();
+    let mut calc_options = DamageCalcOptions::default();
+    let mut ruleset = "default".to_string();
+
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--crit" => calc_options.is_critical_hit = Some(true),
+            "--burned" => calc_options.is_burned = Some(true),
+            "--screens" => calc_options.has_screens = Some(true),
+            "--weather" => {
+                let weather = args
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?;
+
+                calc_options.weather = match weather.as_str() {
+                    "sun" => Some(Weather::Sun),
+                    "rain" => Some(Weather::Rain),
+                    _ => return Err(format!("Unknown weather: {}", weather)),
+                };
+            },
+            "--ruleset" => {
+                ruleset = args
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?
+            },
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() != 3 {
+        return Err("Expected two team files and a move".to_string());
+    }
+
+    let move_id = positional.pop().unwrap();
+    let defender_team_path = positional.pop().unwrap();
+    let attacker_team_path = positional.pop().unwrap();
+
+    Ok(Options {
+        attacker_team_path,
+        defender_team_path,
+        move_id,
+        calc_options,
+        ruleset,
+    })
+}
+
+fn main() {
+    let options = parse_options().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!("Usage: damage_calc <attacker team> <defender team> <move id> [--crit] \
+                   [--burned] [--screens] [--weather NAME] [--ruleset NAME]");
+        process::exit(2);
+    });
+
+    let ruleset = create_ruleset(&options.ruleset).unwrap_or_else(|| {
+        eprintln!("Unknown ruleset: {}", options.ruleset);
+        process::exit(2);
+    });
+
+    let movedex = get_all_moves();
+    let movement = movedex
+        .get_move_id(&options.move_id)
+        .map(|move_id| movedex.get_move(move_id))
+        .unwrap_or_else(|| {
+            eprintln!("Unknown move: {}", options.move_id);
+            process::exit(2);
+        });
+
+    let load = |path: &str| {
+        load_team(path)
+            .and_then(|team| build_team(&team))
+            .unwrap_or_else(|err| {
+                eprintln!("Failed loading team {}: {}", path, err);
+                process::exit(1);
+            })
+    };
+
+    let attacker_team = load(&options.attacker_team_path);
+    let defender_team = load(&options.defender_team_path);
+    let attacker_name = attacker_team[0].species_id;
+    let defender_name = defender_team[0].species_id;
+
+    let battle = create_trainer_battle(attacker_team, defender_team, 0).with_ruleset(ruleset);
+    let rng = StandardBattleRng::new(battle.seed);
+    let mut backend = BattleBackend::new(battle, Box::new(rng));
+    backend.tick().for_each(drop);
+
+    let attacker = backend.get_active_pokemon_index(Team::P1).unwrap();
+    let defender = backend.get_active_pokemon_index(Team::P2).unwrap();
+
+    let range = backend
+        .calculate_damage(attacker, defender, movement, &options.calc_options)
+        .unwrap_or_else(|| {
+            eprintln!("{} doesn't deal damage", options.move_id);
+            process::exit(1);
+        });
+
+    println!("{} {} vs. {}", attacker_name, options.move_id, defender_name);
+    println!(
+        "Damage: {}-{} ({:.1}% - {:.1}%)",
+        range.get_min(),
+        range.get_max(),
+        range.get_min_percentage(),
+        range.get_max_percentage(),
+    );
+    println!(
+        "Rolls: {}",
+        range
+            .rolls
+            .iter()
+            .map(|damage| damage.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    println!("KO chance: {:.1}%", 100. * range.get_ko_chance());
+    println!("Factors: {:?}", range.factors);
+}
@@ -0,0 +1,271 @@
+//! Hosts or joins a link battle over the local network.
+//!
+//! Usage:
+//!   `link_battle host [options]` waits for two players and runs their
+//!   battle. Options:
+//!     --port P          the port to listen on (default: 7447)
+//!     --seed S          the seed of the battle (default: random)
+//!     --ruleset NAME    the ruleset of the battle: default, classic or standard
+//!     --turn-timeout T  seconds that players have to choose an action
+//!   `link_battle join <address> <team> [--locale NAME]` joins a battle with
+//!   a team, which is a RON file as described in `pokemon::team`, and asks
+//!   for the action of each turn on the standard input. Messages are shown
+//!   in the given language (default: en).
+
+use pokemon_rust::{
+    battle::{
+        backend::{FrontendEventKind, Team},
+        link::{host_battle, LinkClient, LinkConfig, LinkOutcome, ServerMessage, DEFAULT_PORT},
+        messages::get_event_messages,
+        rules::create_ruleset,
+    },
+    i18n::{Localization, DEFAULT_LOCALE},
+    pokemon::{
+        get_all_moves,
+        get_all_pokemon_species,
+        team::{build_team, load_team},
+    },
+};
+
+use std::{
+    env,
+    io::{self, BufRead, Write},
+    net::TcpListener,
+    process,
+    str::FromStr,
+    time::Duration,
+};
+
+const USAGE: &str = "Usage: link_battle host [--port P] [--seed S] [--ruleset NAME] \
+                     [--turn-timeout T]\n       link_battle join <address> <team> \
+                     [--locale NAME]";
+
+fn next_value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("Missing value for {}", option))
+}
+
+fn parse_number<T: FromStr>(value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Invalid number: {}", value))
+}
+
+fn parse_host_options(args: impl Iterator<Item = String>) -> Result<(u16, LinkConfig), String> {
+    let mut args = args;
+    let mut port = DEFAULT_PORT;
+    let mut config = LinkConfig {
+        seed: rand::random(),
+        ..LinkConfig::default()
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => port = parse_number(&next_value(&mut args, &arg)?)?,
+            "--seed" => config.seed = parse_number(&next_value(&mut args, &arg)?)?,
+            "--ruleset" => {
+                let name = next_value(&mut args, &arg)?;
+                config.ruleset = create_ruleset(&name)
+                    .ok_or_else(|| format!("Unknown ruleset: {}", name))?;
+            },
+            "--turn-timeout" => {
+                let seconds = parse_number(&next_value(&mut args, &arg)?)?;
+                config.turn_timeout = Duration::from_secs(seconds);
+            },
+            _ => return Err(format!("Unknown option {}", arg)),
+        }
+    }
+
+    Ok((port, config))
+}
+
+/// Returns the address, the team file and the locale of a player that joins
+/// a battle.
+fn parse_join_options(
+    args: impl Iterator<Item = String>,
+) -> Result<(String, String, String), String> {
+    let mut args = args;
+    let mut positional = Vec::new();
+    let mut locale = DEFAULT_LOCALE.to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--locale" => locale = next_value(&mut args, &arg)?,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() != 2 {
+        return Err("Expected an address and a team file".to_string());
+    }
+
+    let team_path = positional.pop().unwrap();
+    let address = positional.pop().unwrap();
+
+    Ok((address, team_path, locale))
+}
+
+fn host(port: u16, config: LinkConfig) {
+    let listener = TcpListener::bind(("0.0.0.0", port)).unwrap_or_else(|err| {
+        eprintln!("Failed listening on port {}: {}", port, err);
+        process::exit(1);
+    });
+
+    println!("Waiting for players on port {} (seed {})", port, config.seed);
+
+    match host_battle(&listener, &config) {
+        Ok(result) => {
+            let winner = match result.winner {
+                Some(Team::P1) => "P1",
+                Some(Team::P2) => "P2",
+                None => "nobody",
+            };
+            let reason = if result.forfeit { " by forfeit" } else { "" };
+
+            println!("Battle over after {} turns: {} won{}", result.turns, winner, reason);
+        },
+        Err(err) => {
+            eprintln!("Failed hosting the battle: {}", err);
+            process::exit(1);
+        },
+    }
+}
+
+/// Asks the player for the index of a move of its active Pokémon.
+fn read_move(client: &LinkClient, localization: &Localization) -> FrontendEventKind {
+    let view = client.get_view();
+    let active_pokemon = view.get_active_pokemon_index(Team::P1).unwrap();
+    let moves = view.get_moves(active_pokemon);
+    let movedex = get_all_moves();
+    let stdin = io::stdin();
+
+    loop {
+        for (index, move_id) in moves.iter().enumerate() {
+            if let Some(move_id) = move_id {
+                let name = localization.get_move_name(movedex.get_move(*move_id));
+                println!("  {}: {}", index + 1, name);
+            }
+        }
+
+        print!("{}: ", localization.tr("battle_menu.choose_move"));
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            process::exit(0);
+        }
+
+        match line.trim().parse::<usize>() {
+            Ok(choice) if choice >= 1 && moves.get(choice - 1).map_or(false, Option::is_some) => {
+                return FrontendEventKind::UseMove(choice - 1);
+            },
+            _ => println!(
+                "{}",
+                localization.tr_with("battle_menu.invalid_choice", &[(
+                    "max",
+                    &moves.len().to_string(),
+                )]),
+            ),
+        }
+    }
+}
+
+fn join(address: &str, team_path: &str, locale: &str) {
+    let team = load_team(team_path)
+        .and_then(|team| build_team(&team))
+        .unwrap_or_else(|err| {
+            eprintln!("Failed loading team {}: {}", team_path, err);
+            process::exit(1);
+        });
+
+    let localization = Localization::load(locale);
+
+    println!("{}", localization.tr("link.waiting_for_opponent"));
+
+    let mut client = LinkClient::connect(address, team, Duration::from_secs(600))
+        .unwrap_or_else(|err| {
+            eprintln!("Failed joining the battle at {}: {}", address, err);
+            process::exit(1);
+        });
+
+    let pokedex = get_all_pokemon_species();
+    let opponent: Vec<_> = client
+        .opponent_team
+        .iter()
+        .map(|pokemon| localization.get_pokemon_name(pokemon, &pokedex))
+        .collect();
+    println!(
+        "{}",
+        localization.tr_with("link.opponent_team", &[("pokemon", &opponent.join(", "))]),
+    );
+
+    loop {
+        let message = client.receive().unwrap_or_else(|err| {
+            eprintln!("Lost the connection to the host: {}", err);
+            process::exit(1);
+        });
+
+        match message {
+            ServerMessage::Events(events) => {
+                let view = client.get_view();
+
+                for (index, event) in events.iter().enumerate() {
+                    let next_event = events.get(index + 1);
+
+                    for message in get_event_messages(view, event, next_event, &localization) {
+                        println!("{}", message);
+                    }
+                }
+            },
+            ServerMessage::ChooseAction => {
+                let action = read_move(&client, &localization);
+
+                if let Err(err) = client.send_action(action) {
+                    eprintln!("Lost the connection to the host: {}", err);
+                    process::exit(1);
+                }
+            },
+            ServerMessage::BattleOver(outcome) => {
+                let key = match outcome {
+                    LinkOutcome::Won => "link.won",
+                    LinkOutcome::Lost => "link.lost",
+                    LinkOutcome::Draw => "link.draw",
+                    LinkOutcome::OpponentForfeited => "link.opponent_forfeited",
+                };
+
+                println!("{}", localization.tr(key));
+                break;
+            },
+            _ => {
+                eprintln!("Unexpected message from the host");
+                process::exit(1);
+            },
+        }
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    match args.next().as_ref().map(String::as_str) {
+        Some("host") => {
+            let (port, config) = parse_host_options(args).unwrap_or_else(|err| {
+                eprintln!("{}\n{}", err, USAGE);
+                process::exit(2);
+            });
+
+            host(port, config);
+        },
+        Some("join") => {
+            let (address, team_path, locale) = parse_join_options(args).unwrap_or_else(|err| {
+                eprintln!("{}\n{}", err, USAGE);
+                process::exit(2);
+            });
+
+            join(&address, &team_path, &locale);
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        },
+    }
+}
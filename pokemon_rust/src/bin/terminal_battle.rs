@@ -0,0 +1,265 @@
+//! Plays a battle against an AI in the terminal. The battle is described by
+//! the same messages that the game shows, and the player chooses each action
+//! on the standard input.
+//!
+//! Usage: `terminal_battle <player team> <opponent team> [options]`, where
+//! each team is a RON file as described in `pokemon::team`. Options:
+//!   --ai NAME       the AI of the opponent: first, random, greedy or expert
+//!                   (default: expert)
+//!   --seed S        the seed of the battle (default: random)
+//!   --ruleset NAME  the ruleset of the battle: default, classic or standard
+//!   --locale NAME   the language of the messages (default: en)
+//!   --log PATH      write a log of the battle, as described in `battle::log`
+
+use pokemon_rust::{
+    battle::{
+        ai::{create_ai, get_available_moves},
+        backend::{
+            rng::StandardBattleRng,
+            BattleBackend,
+            BattleEvent,
+            FrontendEvent,
+            FrontendEventKind,
+            Team,
+        },
+        log::BattleLogFile,
+        messages::{get_event_messages, get_pokemon_summary},
+        rules::create_ruleset,
+        simulation::create_trainer_battle,
+    },
+    i18n::{Localization, DEFAULT_LOCALE},
+    pokemon::team::{build_team, load_team},
+};
+
+use std::{
+    env,
+    io::{self, BufRead, Write},
+    process,
+};
+
+struct Options {
+    player_team_path: String,
+    opponent_team_path: String,
+    ai: String,
+    seed: u64,
+    ruleset: String,
+    locale: String,
+    log_path: Option<String>,
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("Missing value for {}", option))
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut positional = Vec::new();
+    let mut ai = "expert".to_string();
+    let mut seed = rand::random();
+    let mut ruleset = "default".to_string();
+    let mut locale = DEFAULT_LOCALE.to_string();
+    let mut log_path = None;
+
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ai" => ai = next_value(&mut args, &arg)?,
+            "--seed" => {
+                let value = next_value(&mut args, &arg)?;
+                seed = value.parse().map_err(|_| format!("Invalid seed: {}", value))?;
+            },
+            "--ruleset" => ruleset = next_value(&mut args, &arg)?,
+            "--locale" => locale = next_value(&mut args, &arg)?,
+            "--log" => log_path = Some(next_value(&mut args, &arg)?),
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() != 2 {
+        return Err("Expected two team files".to_string());
+    }
+
+    let opponent_team_path = positional.pop().unwrap();
+    let player_team_path = positional.pop().unwrap();
+
+    Ok(Options {
+        player_team_path,
+        opponent_team_path,
+        ai,
+        seed,
+        ruleset,
+        locale,
+        log_path,
+    })
+}
+
+/// Reads a number between 1 and `max` from the standard input, asking again
+/// until the player enters a valid one. Exits when the input ends.
+fn read_choice(prompt: &str, max: usize, localization: &Localization) -> usize {
+    let stdin = io::stdin();
+
+    loop {
+        print!("{}: ", prompt);
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            process::exit(0);
+        }
+
+        match line.trim().parse::<usize>() {
+            Ok(choice) if choice >= 1 && choice <= max => return choice,
+            _ => println!(
+                "{}",
+                localization.tr_with("battle_menu.invalid_choice", &[("max", &max.to_string())]),
+            ),
+        }
+    }
+}
+
+/// Asks the player what to do in the next turn. Returns `None` if they run
+/// from the battle.
+fn read_action(backend: &BattleBackend, localization: &Localization) -> Option<FrontendEvent> {
+    println!("  1: {}", localization.tr("battle_menu.fight"));
+    println!("  2: {}", localization.tr("battle_menu.run"));
+
+    if read_choice(&localization.tr("battle_menu.choose_action"), 2, localization) == 2 {
+        return None;
+    }
+
+    let moves = get_available_moves(backend, Team::P1);
+
+    for (choice, (_, movement)) in moves.iter().enumerate() {
+        println!("  {}: {}", choice + 1, localization.get_move_name(movement));
+    }
+
+    let prompt = localization.tr("battle_menu.choose_move");
+    let (index, _) = moves[read_choice(&prompt, moves.len(), localization) - 1];
+
+    Some(FrontendEvent {
+        team: Team::P1,
+        event: FrontendEventKind::UseMove(index),
+    })
+}
+
+fn print_events(backend: &BattleBackend, events: &[BattleEvent], localization: &Localization) {
+    for (index, event) in events.iter().enumerate() {
+        for message in get_event_messages(backend, event, events.get(index + 1), localization) {
+            println!("{}", message);
+        }
+    }
+}
+
+fn print_active_pokemon(backend: &BattleBackend, localization: &Localization) {
+    println!();
+
+    for team in &[Team::P2, Team::P1] {
+        if let Some(index) = backend.get_active_pokemon_index(*team) {
+            println!("{}", get_pokemon_summary(backend, index, localization));
+        }
+    }
+
+    println!();
+}
+
+fn write_log(
+    log: Option<&mut BattleLogFile>,
+    backend: &BattleBackend,
+    turn_events: &[FrontendEvent],
+    battle_events: &[BattleEvent],
+) {
+    if let Some(log) = log {
+        if let Err(err) = log.write_turn(backend, turn_events, battle_events) {
+            eprintln!("Failed writing the log: {}", err);
+        }
+    }
+}
+
+fn main() {
+    let options = parse_options().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!("Usage: terminal_battle <player team> <opponent team> [--ai NAME] \
+                   [--seed S] [--ruleset NAME] [--locale NAME] [--log PATH]");
+        process::exit(2);
+    });
+
+    let ruleset = create_ruleset(&options.ruleset).unwrap_or_else(|| {
+        eprintln!("Unknown ruleset: {}", options.ruleset);
+        process::exit(2);
+    });
+
+    let mut ai = create_ai(&options.ai, options.seed).unwrap_or_else(|| {
+        eprintln!("Unknown AI: {}", options.ai);
+        process::exit(2);
+    });
+
+    let load = |path: &str| {
+        load_team(path)
+            .and_then(|team| build_team(&team))
+            .unwrap_or_else(|err| {
+                eprintln!("Failed loading team {}: {}", path, err);
+                process::exit(1);
+            })
+    };
+
+    let player_team = load(&options.player_team_path);
+    let opponent_team = load(&options.opponent_team_path);
+    let localization = Localization::load(&options.locale);
+
+    let battle = create_trainer_battle(player_team, opponent_team, options.seed)
+        .with_ruleset(ruleset);
+    let rng = StandardBattleRng::new(battle.seed);
+    let mut backend = BattleBackend::new(battle, Box::new(rng));
+
+    let mut log = options.log_path.as_ref().map(|path| {
+        BattleLogFile::create(path).unwrap_or_else(|err| {
+            eprintln!("Failed creating log {}: {}", path, err);
+            process::exit(1);
+        })
+    });
+
+    let events: Vec<_> = backend.tick().collect();
+    write_log(log.as_mut(), &backend, &[], &events);
+    print_events(&backend, &events, &localization);
+
+    loop {
+        print_active_pokemon(&backend, &localization);
+
+        let mut turn_events = Vec::new();
+
+        // The backend picks the action of a Pokémon that is locked into a
+        // move, so there's nothing to choose
+        if !backend.is_locked(Team::P1) {
+            match read_action(&backend, &localization) {
+                Some(event) => turn_events.push(event),
+                None => {
+                    println!("{}", localization.tr("battle.got_away"));
+                    return;
+                },
+            }
+        }
+
+        turn_events.push(ai.choose_event(&backend, Team::P2));
+
+        for event in &turn_events {
+            backend.push_frontend_event(event.clone());
+        }
+
+        let events: Vec<_> = backend.tick().collect();
+        write_log(log.as_mut(), &backend, &turn_events, &events);
+        print_events(&backend, &events, &localization);
+
+        // Only the first Pokémon of each team takes part in the battle, since
+        // the backend doesn't support switching yet
+        let is_over = events.iter().any(|event| match event {
+            BattleEvent::Faint(_) => true,
+            _ => false,
+        });
+
+        if is_over {
+            break;
+        }
+    }
+}
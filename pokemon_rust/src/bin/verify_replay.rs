@@ -0,0 +1,40 @@
+//! Checks that battle replays still produce the same events with the current
+//! battle mechanics. Usage: `verify_replay <replay files...>`. Exits with a
+//! non-zero status code if any replay diverges or can't be loaded.
+
+use pokemon_rust::battle::replay::BattleReplay;
+
+use std::{env, process};
+
+fn main() {
+    let paths: Vec<String> = env::args().skip(1).collect();
+
+    if paths.is_empty() {
+        eprintln!("Usage: verify_replay <replay files...>");
+        process::exit(2);
+    }
+
+    let mut num_failures = 0;
+
+    for path in &paths {
+        let result = BattleReplay::load(path).map(|replay| replay.verify());
+
+        match result {
+            Ok(Ok(())) => println!("{}: OK", path),
+            Ok(Err(mismatch)) => {
+                println!("{}: MISMATCH at {}", path, mismatch);
+                num_failures += 1;
+            },
+            Err(err) => {
+                println!("{}: failed to load ({})", path, err);
+                num_failures += 1;
+            },
+        }
+    }
+
+    println!("{} of {} replays verified", paths.len() - num_failures, paths.len());
+
+    if num_failures > 0 {
+        process::exit(1);
+    }
+}
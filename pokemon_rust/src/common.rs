@@ -200,3 +200,21 @@ pub fn get_character_sprite_index_from_direction(direction: &Direction) -> usize
         Direction::Right => 9,
     }
 }
+
+/// Moves the focus of a list of options by a given offset, wrapping around
+/// both ends of the list. Used by every selection menu of the game.
+///
+/// # Examples
+///
+/// ```
+/// use pokemon_rust::common::cycle_option;
+///
+/// assert_eq!(1, cycle_option(0, 3, 1));
+/// assert_eq!(2, cycle_option(0, 3, -1));
+/// assert_eq!(0, cycle_option(2, 3, 1));
+/// ```
+pub fn cycle_option(focused_option: u8, num_options: u8, offset: i8) -> u8 {
+    let scaled_option = ((focused_option + num_options) as i8 + offset) as u8;
+
+    scaled_option % num_options
+}
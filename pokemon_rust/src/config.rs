@@ -1,5 +1,7 @@
 //! Types related to the main configuration file (`settings.ron`).
 
+use crate::i18n::DEFAULT_LOCALE;
+
 use serde::{Deserialize, Serialize};
 
 /// Describes the available fields of `settings.ron`.
@@ -25,4 +27,19 @@ pub struct GameConfig {
     pub play_sfx: bool,
     /// Decides whether the FPS should be printed.
     pub show_fps: bool,
+    /// Decides whether battles should be recorded to `replays/`, so that they
+    /// can be played back later.
+    #[serde(default)]
+    pub record_replays: bool,
+    /// Decides whether battles should write a structured log to `logs/`.
+    #[serde(default)]
+    pub write_battle_logs: bool,
+    /// The locale of the game, which selects the string table to be loaded
+    /// from `assets/i18n`. Defaults to `DEFAULT_LOCALE` if absent.
+    #[serde(default = "default_locale")]
+    pub locale: String,
+}
+
+fn default_locale() -> String {
+    DEFAULT_LOCALE.to_string()
 }
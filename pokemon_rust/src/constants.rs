@@ -23,6 +23,18 @@ pub const MAP_DECORATION_LAYER_Z: f32 = 0.5;
 /// The maximum number of moves that a Pokémon can have.
 pub const MOVE_LIMIT: usize = 4;
 
+/// The highest level that a Pokémon can reach.
+pub const MAX_LEVEL: usize = 100;
+
+/// The maximum amount of EVs that a Pokémon can have in a single stat.
+pub const MAX_EVS_PER_STAT: usize = 252;
+
+/// The maximum amount of EVs that a Pokémon can have across all stats.
+pub const MAX_TOTAL_EVS: usize = 510;
+
+/// The highest IV that a Pokémon can have in a stat.
+pub const MAX_IV: usize = 31;
+
 /// The coordinates of the battle camera.
 pub const BATTLE_CAMERA_POSITION: (f32, f32) = (-1000., -1000.);
 
@@ -0,0 +1,74 @@
+//! Persistent story state: boolean flags, integer variables and strings that
+//! outlive a single script execution. Stored in the World as a resource and
+//! meant to be written to save files alongside the rest of the game state.
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+/// A resource containing every story flag and variable of the game.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct GameVariables {
+    flags: HashMap<String, bool>,
+    variables: HashMap<String, i64>,
+    strings: HashMap<String, String>,
+}
+
+impl GameVariables {
+    /// Returns the value of a flag. Flags that were never set are `false`.
+    pub fn get_flag(&self, name: &str) -> bool {
+        self.flags.get(name).cloned().unwrap_or(false)
+    }
+
+    pub fn set_flag(&mut self, name: impl Into<String>, value: bool) {
+        self.flags.insert(name.into(), value);
+    }
+
+    /// Returns the value of a variable. Variables that were never set are `0`.
+    pub fn get_var(&self, name: &str) -> i64 {
+        self.variables.get(name).cloned().unwrap_or(0)
+    }
+
+    pub fn set_var(&mut self, name: impl Into<String>, value: i64) {
+        self.variables.insert(name.into(), value);
+    }
+
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        self.strings.get(name).map(String::as_str)
+    }
+
+    pub fn set_string(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.strings.insert(name.into(), value.into());
+    }
+
+    /// Checks if a given condition currently holds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pokemon_rust::game_variables::{GameCondition, GameVariables};
+    ///
+    /// let mut variables = GameVariables::default();
+    /// let condition = GameCondition::FlagSet("MET_RIVAL".to_string());
+    /// assert!(!variables.satisfies(&condition));
+    ///
+    /// variables.set_flag("MET_RIVAL", true);
+    /// assert!(variables.satisfies(&condition));
+    /// ```
+    pub fn satisfies(&self, condition: &GameCondition) -> bool {
+        match condition {
+            GameCondition::FlagSet(name) => self.get_flag(name),
+            GameCondition::FlagUnset(name) => !self.get_flag(name),
+        }
+    }
+}
+
+/// A condition on the story state. Used by map actions and map scripts so
+/// that they only exist while the condition holds.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum GameCondition {
+    /// Holds while the given flag is set.
+    FlagSet(String),
+    /// Holds while the given flag is not set.
+    FlagUnset(String),
+}
@@ -0,0 +1,157 @@
+//! Localization of player-facing strings. Every locale has a string table in
+//! `assets/i18n/<locale>.ron`, mapping string ids to translated strings.
+//! Strings may contain named placeholders such as `{pokemon}`, which are
+//! filled in by [`Localization::tr_with`](struct.Localization.html#method.tr_with).
+//!
+//! String ids follow a few conventions:
+//! * `move.<move id>`, `species.<species id>` and `map.<map id>` for the names
+//!   of moves, species and maps. These fall back to the names in the
+//!   respective data files if absent;
+//! * `battle.*` for battle messages and `stat.*` for stat names;
+//! * anything else for strings used by scripts (see `tr()` in Lua).
+
+use amethyst::utils::application_root_dir;
+
+use crate::pokemon::{movement::Move, PokeDex, Pokemon, PokemonSpeciesData};
+
+use ron::de::from_reader;
+
+use std::{collections::HashMap, error::Error, fs::File};
+
+/// The locale used for strings that are missing in the selected locale.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// A resource containing the string table of the selected locale.
+#[derive(Clone, Debug, Default)]
+pub struct Localization {
+    locale: String,
+    strings: HashMap<String, String>,
+    fallback_strings: HashMap<String, String>,
+}
+
+impl Localization {
+    /// Loads the string table of a locale from `assets/i18n`, along with the
+    /// one of the default locale.
+    /// If the string table of the locale can't be read, the default locale
+    /// is used instead.
+    pub fn load(locale: &str) -> Localization {
+        let fallback_strings =
+            read_string_table(DEFAULT_LOCALE).expect("Failed loading the default string table");
+
+        if locale == DEFAULT_LOCALE {
+            return Localization::from_tables(locale, HashMap::new(), fallback_strings);
+        }
+
+        match read_string_table(locale) {
+            Ok(strings) => Localization::from_tables(locale, strings, fallback_strings),
+            Err(err) => {
+                eprintln!("Failed loading the string table of locale \"{}\".", locale);
+                eprintln!("Error message: {}", err);
+                eprintln!("Falling back to locale \"{}\".", DEFAULT_LOCALE);
+
+                Localization::from_tables(DEFAULT_LOCALE, HashMap::new(), fallback_strings)
+            },
+        }
+    }
+
+    /// Creates a localization from already loaded string tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pokemon_rust::i18n::Localization;
+    ///
+    /// use std::collections::HashMap;
+    ///
+    /// let mut strings = HashMap::new();
+    /// strings.insert("battle.fainted".to_string(), "{pokemon} desmaiou!".to_string());
+    ///
+    /// let mut fallback_strings = HashMap::new();
+    /// fallback_strings.insert("battle.fainted".to_string(), "{pokemon} fainted!".to_string());
+    /// fallback_strings.insert("battle.failed".to_string(), "But it failed!".to_string());
+    ///
+    /// let localization = Localization::from_tables("pt-BR", strings, fallback_strings);
+    ///
+    /// assert_eq!(
+    ///     localization.tr_with("battle.fainted", &[("pokemon", "Pikachu")]),
+    ///     "Pikachu desmaiou!"
+    /// );
+    /// assert_eq!(localization.tr("battle.failed"), "But it failed!");
+    /// assert_eq!(localization.tr("unknown.key"), "unknown.key");
+    /// ```
+    pub fn from_tables(
+        locale: impl Into<String>,
+        strings: HashMap<String, String>,
+        fallback_strings: HashMap<String, String>,
+    ) -> Localization {
+        Localization {
+            locale: locale.into(),
+            strings,
+            fallback_strings,
+        }
+    }
+
+    pub fn get_locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Returns the string corresponding to a given id, if there's one either
+    /// in the selected locale or in the default locale.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.strings
+            .get(key)
+            .or_else(|| self.fallback_strings.get(key))
+            .map(String::as_str)
+    }
+
+    /// Returns the string corresponding to a given id. Missing strings are
+    /// replaced by their own id, making them easy to spot.
+    pub fn tr(&self, key: &str) -> String {
+        self.get(key).unwrap_or(key).to_string()
+    }
+
+    /// Returns the string corresponding to a given id, or a default value if
+    /// it's missing.
+    pub fn tr_or(&self, key: &str, default: &str) -> String {
+        self.get(key).unwrap_or(default).to_string()
+    }
+
+    /// Returns the string corresponding to a given id, replacing each
+    /// `{name}` placeholder by its value.
+    pub fn tr_with(&self, key: &str, args: &[(&str, &str)]) -> String {
+        args.iter().fold(self.tr(key), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+    }
+
+    pub fn get_move_name(&self, movement: &Move) -> String {
+        self.tr_or(&format!("move.{}", movement.name), &movement.display_name)
+    }
+
+    pub fn get_species_name(&self, species: &PokemonSpeciesData) -> String {
+        self.tr_or(&format!("species.{}", species.name), &species.display_name)
+    }
+
+    /// Returns the name of a Pokémon, which is either its nickname or the
+    /// name of its species.
+    pub fn get_pokemon_name(&self, pokemon: &Pokemon, pokedex: &PokeDex) -> String {
+        if let Some(name) = &pokemon.nickname {
+            name.clone()
+        } else {
+            let species = pokedex.get_species(pokemon.species_id);
+
+            self.get_species_name(species)
+        }
+    }
+}
+
+fn read_string_table(locale: &str) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let table_file = application_root_dir()?
+        .join("assets")
+        .join("i18n")
+        .join(format!("{}.ron", locale));
+
+    let file = File::open(table_file)?;
+
+    Ok(from_reader(file)?)
+}
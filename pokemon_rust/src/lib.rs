@@ -20,6 +20,8 @@ pub mod battle;
 pub mod common;
 pub mod config;
 pub mod constants;
+pub mod game_variables;
+pub mod i18n;
 pub mod loading_state;
 pub mod lua;
 pub mod map;
@@ -43,7 +45,7 @@ use amethyst::{
     Result as AmethystResult,
 };
 
-use crate::{config::GameConfig, loading_state::LoadingState};
+use crate::{battle::replay::BattleReplay, config::GameConfig, loading_state::LoadingState};
 
 use std::path::PathBuf;
 
@@ -52,6 +54,9 @@ pub struct PokemonRustParameters {
     pub game_config_path: PathBuf,
     pub keybindings_config_path: PathBuf,
     pub assets_path: PathBuf,
+    /// If present, the game plays back this replay file instead of starting
+    /// normally.
+    pub replay_path: Option<PathBuf>,
 }
 
 pub fn start_game(params: PokemonRustParameters) -> AmethystResult<()> {
@@ -60,6 +65,7 @@ pub fn start_game(params: PokemonRustParameters) -> AmethystResult<()> {
         game_config_path,
         keybindings_config_path,
         assets_path,
+        replay_path,
     } = params;
 
     amethyst_start_logger(LoggerConfig::default());
@@ -84,10 +90,17 @@ pub fn start_game(params: PokemonRustParameters) -> AmethystResult<()> {
         .with_bundle(UiBundle::<StringBindings>::new())?
         .with_bundle(AudioBundle::default())?;
 
-    Application::build(assets_path, LoadingState::default())?
-        .with_resource(game_config)
-        .build(game_data)?
-        .run();
+    let mut application_builder =
+        Application::build(assets_path, LoadingState::default())?.with_resource(game_config);
+
+    if let Some(replay_path) = replay_path {
+        let replay = BattleReplay::load(&replay_path)
+            .unwrap_or_else(|err| panic!("Failed loading replay {:?}: {}", replay_path, err));
+
+        application_builder = application_builder.with_resource(replay);
+    }
+
+    application_builder.build(game_data)?.run();
 
     Ok(())
 }
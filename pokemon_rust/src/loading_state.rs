@@ -10,6 +10,7 @@ use amethyst::{
 
 use crate::{
     audio::{initialise_audio, AudioSystem},
+    battle::{battle_state::BattleState, replay::BattleReplay},
     common::{
         load_full_texture_sprite_sheet,
         load_sprite_sheet_from_world,
@@ -18,6 +19,8 @@ use crate::{
     },
     config::GameConfig,
     constants::{HEALTH_BAR_WIDTH, OPPONENT_HEALTH_BAR_HEIGHT, WINDOW_HEIGHT, WINDOW_WIDTH},
+    game_variables::GameVariables,
+    i18n::Localization,
     map::{initialise_map, MapCoordinates},
     overworld::{
         entities::character::{initialise_player, PlayerEntity},
@@ -155,6 +158,10 @@ impl SimpleState for LoadingState<'_, '_> {
         self.dispatcher = Some(dispatcher);
 
         world.insert(EventQueue::default());
+        world.insert(GameVariables::default());
+
+        let localization = Localization::load(&world.read_resource::<GameConfig>().locale);
+        world.insert(localization);
 
         init_output(world);
         initialise_audio(world);
@@ -206,7 +213,16 @@ impl SimpleState for LoadingState<'_, '_> {
         }
 
         if progress_counter.is_complete() {
-            Trans::Switch(Box::new(OverworldState::default()))
+            drop(asset_tracker);
+
+            if data.world.has_value::<BattleReplay>() {
+                let battle = data.world.read_resource::<BattleReplay>().battle.clone();
+                data.world.insert(battle);
+
+                Trans::Switch(Box::new(BattleState::default()))
+            } else {
+                Trans::Switch(Box::new(OverworldState::default()))
+            }
         } else {
             Trans::None
         }
@@ -0,0 +1,160 @@
+//! Every script runs as a Lua coroutine, which allows it to suspend itself
+//! while waiting for an event to complete. Suspended scripts are kept here
+//! until they can be resumed.
+
+use rlua::{
+    Context,
+    MultiValue,
+    RegistryKey,
+    Thread,
+    ThreadStatus,
+    ToLuaMulti,
+    UserData,
+    Value,
+};
+
+use super::LuaScriptError;
+
+use std::{
+    cell::RefCell,
+    mem,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A flag shared between a suspended script and the event it is waiting for.
+/// It's passed to Lua as an opaque value, which the script then yields.
+#[derive(Clone, Default)]
+pub(super) struct EventCompletion {
+    completed: Arc<AtomicBool>,
+}
+
+impl EventCompletion {
+    fn finished() -> EventCompletion {
+        EventCompletion {
+            completed: Arc::new(AtomicBool::new(true)),
+        }
+    }
+
+    pub(super) fn flag(&self) -> Arc<AtomicBool> {
+        self.completed.clone()
+    }
+
+    fn is_complete(&self) -> bool {
+        self.completed.load(Ordering::SeqCst)
+    }
+
+    /// Checks if the event holding the other end of the flag was dropped
+    /// before completing, in which case the flag will never be set.
+    fn is_abandoned(&self) -> bool {
+        !self.is_complete() && Arc::strong_count(&self.completed) == 1
+    }
+}
+
+impl UserData for EventCompletion {}
+
+struct SuspendedScript {
+    thread: RegistryKey,
+    completion: EventCompletion,
+}
+
+thread_local! {
+    static SUSPENDED_SCRIPTS: RefCell<Vec<SuspendedScript>> = RefCell::new(Vec::new());
+}
+
+/// Checks if there's any suspended script that can either be resumed or
+/// discarded.
+pub(super) fn has_resumable_scripts() -> bool {
+    SUSPENDED_SCRIPTS.with(|scripts| {
+        scripts.borrow().iter().any(|script| {
+            script.completion.is_complete() || script.completion.is_abandoned()
+        })
+    })
+}
+
+/// Removes every suspended script whose awaited event is complete and
+/// returns their threads. Scripts waiting for an event that was dropped
+/// without completing are discarded, since nothing will ever resume them.
+/// Scripts that can't be retrieved are discarded as well, and reported.
+pub(super) fn take_resumable_threads<'lua>(context: Context<'lua>) -> Vec<Thread<'lua>> {
+    let finished_scripts: Vec<SuspendedScript> = SUSPENDED_SCRIPTS.with(|scripts| {
+        let mut scripts = scripts.borrow_mut();
+        let (finished, pending) = scripts.drain(..).partition(|script| {
+            script.completion.is_complete() || script.completion.is_abandoned()
+        });
+
+        *scripts = pending;
+        finished
+    });
+
+    finished_scripts
+        .into_iter()
+        .filter_map(|script| {
+            let is_complete = script.completion.is_complete();
+
+            match take_thread(context, script.thread) {
+                Ok(Some(thread)) if is_complete => Some(thread),
+                Ok(Some(_)) => {
+                    eprintln!("Discarding a Lua script waiting for an event that was dropped.");
+                    None
+                },
+                Ok(None) => None,
+                Err(err) => {
+                    eprintln!("Discarding a suspended Lua script that couldn't be retrieved.");
+                    eprintln!("Error message: {}", err);
+                    None
+                },
+            }
+        })
+        .collect()
+}
+
+/// Removes a thread from the registry, returning it only if it can still be
+/// resumed.
+fn take_thread<'lua>(
+    context: Context<'lua>,
+    key: RegistryKey,
+) -> Result<Option<Thread<'lua>>, LuaScriptError> {
+    let thread: Thread = context.registry_value(&key)?;
+    context.remove_registry_value(key)?;
+
+    if thread.status() == ThreadStatus::Resumable {
+        Ok(Some(thread))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Resumes (or starts) a Lua thread. If it yields, it's stored until the
+/// value it yielded signals that it can be resumed. Yielding anything other
+/// than an `EventCompletion` makes it resume on the next frame.
+pub(super) fn resume_thread<'lua>(
+    context: Context<'lua>,
+    thread: Thread<'lua>,
+    arguments: impl ToLuaMulti<'lua>,
+) -> Result<(), LuaScriptError> {
+    let yielded_values: MultiValue = thread.resume(arguments)?;
+
+    if thread.status() == ThreadStatus::Resumable {
+        // The flag is moved out of the yielded value, so that the only other
+        // owner is the awaited event, which allows detecting when it's dropped.
+        let completion = match yielded_values.into_iter().next() {
+            Some(Value::UserData(data)) => data
+                .borrow_mut::<EventCompletion>()
+                .map(|mut completion| mem::replace(&mut *completion, EventCompletion::finished()))?,
+            _ => EventCompletion::finished(),
+        };
+
+        let thread = context.create_registry_value(thread)?;
+
+        SUSPENDED_SCRIPTS.with(|scripts| {
+            scripts
+                .borrow_mut()
+                .push(SuspendedScript { thread, completion })
+        });
+    }
+
+    Ok(())
+}
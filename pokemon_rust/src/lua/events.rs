@@ -11,10 +11,13 @@ use crate::{
     overworld::{
         entities::character::CharacterId,
         events::{
+            AwaitableEvent,
             BgmChangeEvent,
             ChainedEvents,
             CharacterMoveEvent,
             CharacterRotateEvent,
+            ChoiceCallback,
+            ChoiceEvent,
             CyclicEvent,
             EventQueue,
             GameEvent,
@@ -24,7 +27,7 @@ use crate::{
     },
 };
 
-use super::ExecutionContext;
+use super::{coroutines::EventCompletion, ExecutionContext};
 
 pub(super) fn create_bgm_change_event(context: &mut ExecutionContext, filename: String) -> usize {
     let format = get_bgm_format(&filename);
@@ -65,6 +68,17 @@ pub(super) fn create_chained_event(context: &mut ExecutionContext) -> usize {
     context.store(event)
 }
 
+pub(super) fn create_choice_event(
+    context: &mut ExecutionContext,
+    text: String,
+    options: Vec<String>,
+    callback_key: usize,
+) -> usize {
+    let event = ChoiceEvent::new(text, options, ChoiceCallback::Lua(callback_key));
+
+    context.store(event)
+}
+
 pub(super) fn create_cyclic_event(context: &mut ExecutionContext, event_key: usize) -> usize {
     let event = CyclicEvent::new(remove_event(context, event_key));
 
@@ -134,6 +148,17 @@ pub(super) fn dispatch_event(context: &mut ExecutionContext, key: usize) {
         .push_boxed(event);
 }
 
+/// Dispatches an event, returning a value that the calling script should
+/// yield in order to be suspended until the event is complete.
+pub(super) fn await_event(context: &mut ExecutionContext, key: usize) -> EventCompletion {
+    let completion = EventCompletion::default();
+    let event = AwaitableEvent::new(remove_event(context, key), completion.flag());
+
+    context.world.write_resource::<EventQueue>().push(event);
+
+    completion
+}
+
 fn remove_event(context: &mut ExecutionContext, key: usize) -> Box<dyn GameEvent + Send + Sync> {
     let event = context.remove_boxed(key);
 
@@ -145,6 +170,8 @@ fn remove_event(context: &mut ExecutionContext, key: usize) -> Box<dyn GameEvent
         event.downcast::<CharacterMoveEvent>().unwrap()
     } else if event.is::<CharacterRotateEvent>() {
         event.downcast::<CharacterRotateEvent>().unwrap()
+    } else if event.is::<ChoiceEvent>() {
+        event.downcast::<ChoiceEvent>().unwrap()
     } else if event.is::<CyclicEvent>() {
         event.downcast::<CyclicEvent>().unwrap()
     } else if event.is::<TextEvent>() {
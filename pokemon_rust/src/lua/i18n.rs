@@ -0,0 +1,27 @@
+use amethyst::ecs::WorldExt;
+
+use crate::i18n::Localization;
+
+use super::ExecutionContext;
+
+use std::collections::HashMap;
+
+pub(super) fn tr(
+    context: &mut ExecutionContext,
+    key: String,
+    args: Option<HashMap<String, String>>,
+) -> String {
+    let localization = context.world.read_resource::<Localization>();
+
+    match args {
+        Some(args) => {
+            let args: Vec<(&str, &str)> = args
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect();
+
+            localization.tr_with(&key, &args)
+        },
+        None => localization.tr(&key),
+    }
+}
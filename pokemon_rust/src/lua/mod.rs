@@ -2,9 +2,12 @@
 //! called from Lua code and the required plumbing to make this communication
 //! work.
 
+mod coroutines;
 mod events;
+mod i18n;
 mod npc;
 mod polymorphic_container;
+mod variables;
 
 use amethyst::{ecs::World, utils::application_root_dir};
 
@@ -14,13 +17,25 @@ use crate::{
     overworld::entities::character::CharacterId,
 };
 
-use rlua::{Context, Error as LuaError, FromLua, Function, Lua, Result as LuaResult, Value};
+use rlua::{
+    Context,
+    Error as LuaError,
+    FromLua,
+    Function,
+    Lua,
+    Result as LuaResult,
+    Table,
+    Value,
+};
 
 use self::{
+    coroutines::{has_resumable_scripts, resume_thread, take_resumable_threads},
     events::{
         add_event,
+        await_event,
         create_bgm_change_event,
         create_chained_event,
+        create_choice_event,
         create_cyclic_event,
         create_npc_move_event,
         create_npc_rotate_event,
@@ -30,12 +45,15 @@ use self::{
         dispatch_event,
         preload_bgm,
     },
+    i18n::tr,
     npc::{add_npc, change_npc_direction, create_npc, rotate_npc_towards_player},
     polymorphic_container::PolymorphicContainer,
+    variables::{get_flag, get_string, get_var, set_flag, set_string, set_var},
 };
 
 use std::{
     cell::RefCell,
+    collections::HashMap,
     fmt::{self, Display, Formatter},
     fs::read_to_string,
     io::Error as IoError,
@@ -161,22 +179,68 @@ fn run_script(
         context.load(&content).exec()?;
 
         let function: Function = context.globals().get(function)?;
+        let thread = context.create_thread(function)?;
 
         match parameters {
-            None => function.call(())?,
+            None => resume_thread(*context, thread, ()),
             Some(GameScriptParameters::SourceTile(coordinates)) => {
-                function.call((coordinates.x(), coordinates.y()))?
+                resume_thread(*context, thread, (coordinates.x(), coordinates.y()))
             },
             Some(GameScriptParameters::TargetCharacter(character_id)) => {
-                function.call(character_id.0)?
+                resume_thread(*context, thread, character_id.0)
+            },
+            Some(GameScriptParameters::SourceMap(map_name)) => {
+                resume_thread(*context, thread, map_name.clone())
             },
-            Some(GameScriptParameters::SourceMap(map_name)) => function.call(map_name.clone())?,
         }
+    })
+}
 
-        Ok(())
+/// Calls a Lua function previously registered through `Callbacks.register`
+/// (see `lua/events.lua`), passing a single argument to it. Like scripts,
+/// callbacks run as coroutines and are allowed to suspend themselves.
+pub fn run_lua_callback(
+    world: &mut World,
+    callback_key: usize,
+    argument: usize,
+) -> Result<(), LuaScriptError> {
+    LUA.with(|lua| {
+        run_with_native_functions(world, lua, |context| {
+            let callbacks: Table = context.globals().get("Callbacks")?;
+            let function: Function = callbacks.get("run")?;
+            let thread = context.create_thread(function)?;
+
+            resume_thread(*context, thread, (callback_key, argument))
+        })
     })
 }
 
+/// Resumes every suspended script whose awaited event has been completed.
+/// Scripts suspend themselves through `Event:await()` (see `lua/events.lua`).
+pub fn resume_lua_scripts(world: &mut World) {
+    if !has_resumable_scripts() {
+        return;
+    }
+
+    let result = LUA.with(|lua| {
+        run_with_native_functions(world, lua, |context| {
+            for thread in take_resumable_threads(*context) {
+                if let Err(err) = resume_thread(*context, thread, ()) {
+                    eprintln!("An error occurred while resuming a Lua script.");
+                    eprintln!("Error message: {}", err);
+                }
+            }
+
+            Ok(())
+        })
+    });
+
+    if let Err(err) = result {
+        eprintln!("An error occurred while resuming Lua scripts.");
+        eprintln!("Error message: {}", err);
+    }
+}
+
 macro_rules! native_functions {
     (
         ($globals:ident, $scope:ident, $context:ident)
@@ -223,6 +287,8 @@ where
                 rust_create_bgm_change_event: create_bgm_change_event(filename: String),
                 rust_preload_bgm: preload_bgm(filename: String),
                 rust_create_chained_event: create_chained_event(),
+                rust_create_choice_event:
+                    create_choice_event(text: String, options: Vec<String>, callback_key: usize),
                 rust_create_cyclic_event: create_cyclic_event(event_key: usize),
                 rust_create_npc_move_event:
                     create_npc_move_event(character_id: CharacterId, num_tiles: usize),
@@ -233,13 +299,23 @@ where
                 rust_create_text_event: create_text_event(text: String),
                 rust_create_warp_event: create_warp_event(map: String, x: u32, y: u32),
                 rust_add_event: add_event(chain_key: usize, new_event: usize),
+                rust_await_event: await_event(key: usize),
                 rust_dispatch_event: dispatch_event(key: usize),
                 // NPC functions
                 rust_create_npc:
                     create_npc(map_id: String, x: u32, y: u32, kind: String, direction: Direction),
                 rust_change_npc_direction: change_npc_direction(npc_key: usize, direction: Direction),
                 rust_rotate_npc_towards_player: rotate_npc_towards_player(character_id: CharacterId),
-                rust_add_npc: add_npc(npc_key: usize)
+                rust_add_npc: add_npc(npc_key: usize),
+                // Variable functions
+                rust_get_flag: get_flag(name: String),
+                rust_set_flag: set_flag(name: String, value: bool),
+                rust_get_var: get_var(name: String),
+                rust_set_var: set_var(name: String, value: i64),
+                rust_get_string: get_string(name: String),
+                rust_set_string: set_string(name: String, value: String),
+                // Localization functions
+                rust_tr: tr(key: String, args: Option<HashMap<String, String>>)
             );
 
             callback(&context)
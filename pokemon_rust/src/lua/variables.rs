@@ -0,0 +1,42 @@
+use amethyst::ecs::WorldExt;
+
+use crate::game_variables::GameVariables;
+
+use super::ExecutionContext;
+
+pub(super) fn get_flag(context: &mut ExecutionContext, name: String) -> bool {
+    context.world.read_resource::<GameVariables>().get_flag(&name)
+}
+
+pub(super) fn set_flag(context: &mut ExecutionContext, name: String, value: bool) {
+    context
+        .world
+        .write_resource::<GameVariables>()
+        .set_flag(name, value);
+}
+
+pub(super) fn get_var(context: &mut ExecutionContext, name: String) -> i64 {
+    context.world.read_resource::<GameVariables>().get_var(&name)
+}
+
+pub(super) fn set_var(context: &mut ExecutionContext, name: String, value: i64) {
+    context
+        .world
+        .write_resource::<GameVariables>()
+        .set_var(name, value);
+}
+
+pub(super) fn get_string(context: &mut ExecutionContext, name: String) -> Option<String> {
+    context
+        .world
+        .read_resource::<GameVariables>()
+        .get_string(&name)
+        .map(str::to_string)
+}
+
+pub(super) fn set_string(context: &mut ExecutionContext, name: String, value: String) {
+    context
+        .world
+        .write_resource::<GameVariables>()
+        .set_string(name, value);
+}
@@ -2,15 +2,22 @@ use amethyst::{utils::application_root_dir, Result as AmethystResult};
 
 use pokemon_rust::{start_game, PokemonRustParameters};
 
+use std::{env, path::PathBuf};
+
 fn main() -> AmethystResult<()> {
     let app_root = application_root_dir()?;
     let config_path = app_root.join("config");
+    let replay_path = env::args()
+        .skip_while(|arg| arg != "--replay")
+        .nth(1)
+        .map(PathBuf::from);
 
     let params = PokemonRustParameters {
         display_config_path: config_path.join("display.ron"),
         game_config_path: config_path.join("settings.ron"),
         keybindings_config_path: config_path.join("keybindings.ron"),
         assets_path: app_root.join("assets"),
+        replay_path,
     };
 
     start_game(params)
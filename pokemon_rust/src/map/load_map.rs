@@ -8,6 +8,7 @@ use amethyst::{
 use crate::{
     common::{load_full_texture_sprite_sheet, AssetTracker},
     constants::{MAP_DECORATION_LAYER_Z, MAP_TERRAIN_LAYER_Z, TILE_SIZE},
+    i18n::Localization,
     overworld::{
         entities::character::{CharacterId, PendingInteraction, PlayerEntity},
         events::{EventQueue, MapChangeEvent, ScriptEvent},
@@ -297,9 +298,13 @@ fn load_map(
         progress_counter,
     );
 
+    let display_name = world
+        .read_resource::<Localization>()
+        .tr_or(&format!("map.{}", map_name), &map.map_name);
+
     let mut map = Map::from_initialized_map(InitializedMap {
         map_id: MapId(map_name.to_string()),
-        map_name: map.map_name,
+        map_name: display_name,
         reference_point,
         terrain_entity,
         solids: map.solids,
@@ -376,6 +381,7 @@ fn add_intrinsic_scripts(map: &mut Map) {
     map.map_scripts.push(MapScript {
         when: MapScriptKind::OnTileChange,
         script_index: map.script_repository.len() - 1,
+        condition: None,
     });
 
     map.script_repository.push(GameScript::Native {
@@ -395,5 +401,6 @@ fn add_intrinsic_scripts(map: &mut Map) {
     map.map_scripts.push(MapScript {
         when: MapScriptKind::OnMapEnter,
         script_index: map.script_repository.len() - 1,
+        condition: None,
     });
 }
@@ -2,6 +2,7 @@ use amethyst::ecs::{Component, DenseVecStorage, Entity, World};
 
 use crate::{
     common::Direction,
+    game_variables::GameCondition,
     overworld::{entities::character::CharacterId, events::ScriptEvent},
 };
 
@@ -104,7 +105,10 @@ impl Map {
         self.map_scripts
             .iter()
             .filter(move |script| script.when == kind)
-            .map(move |script| ScriptEvent::new(self.map_id.clone(), script.script_index))
+            .map(move |script| {
+                ScriptEvent::new(self.map_id.clone(), script.script_index)
+                    .with_condition(script.condition.clone())
+            })
     }
 }
 
@@ -156,6 +160,9 @@ impl Debug for GameScript {
 pub struct GameAction {
     pub when: GameActionKind,
     pub script_index: usize,
+    /// If present, this action only exists while the condition holds.
+    #[serde(default)]
+    pub condition: Option<GameCondition>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -179,6 +186,9 @@ pub enum GameActionKind {
 pub struct MapScript {
     pub when: MapScriptKind,
     pub script_index: usize,
+    /// If present, this script only runs while the condition holds.
+    #[serde(default)]
+    pub condition: Option<GameCondition>,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -81,7 +81,8 @@ impl MapHandler {
             .get(&tile_coordinates)
             .map(|game_action| ValidatedGameAction {
                 when: game_action.when.clone(),
-                script_event: ScriptEvent::new(tile_data.map_id.clone(), game_action.script_index),
+                script_event: ScriptEvent::new(tile_data.map_id.clone(), game_action.script_index)
+                    .with_condition(game_action.condition.clone()),
             })
     }
 
@@ -167,6 +168,7 @@ impl MapHandler {
             GameAction {
                 when: GameActionKind::OnInteraction,
                 script_index: map.script_repository.len() - 1,
+                condition: None,
             },
         );
 
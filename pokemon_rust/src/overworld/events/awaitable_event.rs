@@ -0,0 +1,59 @@
+//! Generic event. Wraps another event and raises a shared flag once it is
+//! complete. Used to resume Lua scripts that are waiting for an event.
+
+use amethyst::ecs::World;
+
+use super::{BoxedGameEvent, ExecutionConditions, GameEvent};
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+pub struct AwaitableEvent {
+    event: BoxedGameEvent,
+    completed: Arc<AtomicBool>,
+}
+
+impl AwaitableEvent {
+    pub fn new(event: BoxedGameEvent, completed: Arc<AtomicBool>) -> AwaitableEvent {
+        AwaitableEvent { event, completed }
+    }
+}
+
+impl Clone for AwaitableEvent {
+    fn clone(&self) -> AwaitableEvent {
+        AwaitableEvent {
+            event: self.event.boxed_clone(),
+            completed: self.completed.clone(),
+        }
+    }
+}
+
+impl GameEvent for AwaitableEvent {
+    fn boxed_clone(&self) -> BoxedGameEvent {
+        Box::new(self.clone())
+    }
+
+    fn get_execution_conditions(&self) -> ExecutionConditions {
+        self.event.get_execution_conditions()
+    }
+
+    fn start(&mut self, world: &mut World) {
+        self.event.start(world);
+    }
+
+    fn tick(&mut self, world: &mut World, disabled_inputs: bool) {
+        self.event.tick(world, disabled_inputs);
+    }
+
+    fn is_complete(&self, world: &mut World) -> bool {
+        let completed = self.event.is_complete(world);
+
+        if completed {
+            self.completed.store(true, Ordering::SeqCst);
+        }
+
+        completed
+    }
+}
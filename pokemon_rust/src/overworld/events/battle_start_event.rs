@@ -63,7 +63,8 @@ impl GameEvent for BattleStartEvent {
         let battle_type = self.battle_type.clone();
 
         let party = {
-            let rattata = generate_pokemon(&pokedex.get_species("Clefairy").unwrap(), &movedex, 10);
+            let species = pokedex.get_species_id("Clefairy").unwrap();
+            let rattata = generate_pokemon(pokedex.get_species(species), &movedex, 10);
 
             Party {
                 pokemon: vec![rattata].into(),
@@ -83,7 +84,8 @@ impl GameEvent for BattleStartEvent {
         };
 
         let p2 = {
-            let pidgey = generate_pokemon(&pokedex.get_species("Butterfree").unwrap(), &movedex, 9);
+            let species = pokedex.get_species_id("Butterfree").unwrap();
+            let pidgey = generate_pokemon(pokedex.get_species(species), &movedex, 9);
 
             BattleCharacterTeam {
                 active_pokemon: None,
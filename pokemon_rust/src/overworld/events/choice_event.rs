@@ -0,0 +1,176 @@
+//! Displays a text box followed by a list of options, and waits for the
+//! player to pick one of them. The index of the chosen option is then passed
+//! to a callback, allowing dialogues to branch. Affected by
+//! [`GameConfig::text_delay`](../config/struct.GameConfig.html#structfield.text_delay).
+
+use amethyst::{
+    ecs::{
+        world::Builder,
+        Entities,
+        Entity,
+        ReadExpect,
+        SystemData,
+        World,
+        WorldExt,
+        WriteStorage,
+    },
+    ui::{UiImage, UiText, UiTransform},
+};
+
+use crate::{
+    common::CommonResources,
+    i18n::Localization,
+    lua::run_lua_callback,
+    text::{create_choice_box, create_text_box, delete_choice_box, ChoiceBox, TextLayoutData},
+};
+
+use super::{BoxedGameEvent, ExecutionConditions, GameEvent};
+
+/// The function to be called once the player makes a choice.
+#[derive(Clone)]
+pub enum ChoiceCallback {
+    /// A Rust function receiving the index of the chosen option.
+    Native(fn(&mut World, usize)),
+    /// A Lua function stored on the Lua side under the given key. It receives
+    /// the 1-based index of the chosen option.
+    Lua(usize),
+}
+
+#[derive(Clone)]
+pub struct ChoiceEvent {
+    text: String,
+    options: Vec<String>,
+    /// Whether the options are string ids that must be localized.
+    localized_options: bool,
+    callback: ChoiceCallback,
+    choice_box_entity: Option<Entity>,
+    completed: bool,
+}
+
+impl ChoiceEvent {
+    pub fn new(
+        text: impl Into<String>,
+        options: Vec<String>,
+        callback: ChoiceCallback,
+    ) -> ChoiceEvent {
+        ChoiceEvent {
+            text: text.into(),
+            options,
+            localized_options: false,
+            callback,
+            choice_box_entity: None,
+            completed: false,
+        }
+    }
+
+    /// Creates a choice event whose options are "Yes" and "No", in that order.
+    pub fn yes_no(text: impl Into<String>, callback: ChoiceCallback) -> ChoiceEvent {
+        ChoiceEvent {
+            localized_options: true,
+            ..ChoiceEvent::new(
+                text,
+                vec!["choice.yes".to_string(), "choice.no".to_string()],
+                callback,
+            )
+        }
+    }
+}
+
+impl GameEvent for ChoiceEvent {
+    fn boxed_clone(&self) -> BoxedGameEvent {
+        Box::new(self.clone())
+    }
+
+    fn get_execution_conditions(&self) -> ExecutionConditions {
+        ExecutionConditions {
+            requires_disabled_input: true,
+            ..ExecutionConditions::default()
+        }
+    }
+
+    fn start(&mut self, world: &mut World) {
+        let choice_box = {
+            let (
+                mut ui_images,
+                mut ui_texts,
+                mut ui_transforms,
+                entities,
+                resources,
+                layout_data,
+                localization,
+            ) = <(
+                WriteStorage<UiImage>,
+                WriteStorage<UiText>,
+                WriteStorage<UiTransform>,
+                Entities,
+                ReadExpect<CommonResources>,
+                TextLayoutData,
+                ReadExpect<Localization>,
+            )>::fetch(world);
+
+            let text_box = create_text_box(
+                self.text.clone(),
+                &mut ui_images,
+                &mut ui_texts,
+                &mut ui_transforms,
+                &entities,
+                &resources,
+                &layout_data,
+            );
+
+            let options = if self.localized_options {
+                self.options
+                    .iter()
+                    .map(|option| localization.tr(option))
+                    .collect()
+            } else {
+                self.options.clone()
+            };
+
+            create_choice_box(text_box, options)
+        };
+
+        self.choice_box_entity = Some(world.create_entity().with(choice_box).build());
+    }
+
+    fn tick(&mut self, world: &mut World, _disabled_inputs: bool) {
+        let entity = self
+            .choice_box_entity
+            .expect("Called tick() before start()");
+
+        let selected_option = {
+            let (mut choice_boxes, entities) =
+                <(WriteStorage<ChoiceBox>, Entities)>::fetch(world);
+
+            let choice_box = choice_boxes
+                .get_mut(entity)
+                .expect("Failed to retrieve ChoiceBox");
+
+            match choice_box.selected_option {
+                Some(option) => {
+                    delete_choice_box(entity, choice_box, &entities);
+                    option
+                },
+                None => return,
+            }
+        };
+
+        self.completed = true;
+
+        match self.callback {
+            ChoiceCallback::Native(callback) => callback(world, selected_option),
+            ChoiceCallback::Lua(callback_key) => {
+                let result = run_lua_callback(world, callback_key, selected_option + 1);
+
+                if let Err(err) = result {
+                    eprintln!("An error occurred during the execution of a Lua callback.");
+                    eprintln!("Error message: {}", err);
+                }
+            },
+        }
+    }
+
+    fn is_complete(&self, _world: &mut World) -> bool {
+        self.completed
+    }
+}
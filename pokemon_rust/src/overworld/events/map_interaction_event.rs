@@ -51,7 +51,7 @@ impl GameEvent for MapInteractionEvent {
 
         match map.get_action_at(&interacted_position) {
             Some(ValidatedGameAction { when, script_event })
-                if when == GameActionKind::OnInteraction =>
+                if when == GameActionKind::OnInteraction && script_event.is_enabled(world) =>
             {
                 SoundKit::fetch(world).play_sound(Sound::SelectOption);
 
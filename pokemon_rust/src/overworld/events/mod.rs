@@ -1,9 +1,11 @@
 //! Contains every possible game event. Events can trigger animations,
 //! textboxes, warps and much more.
 
+pub mod awaitable_event;
 pub mod battle_start_event;
 pub mod bgm_change_event;
 pub mod chained_events;
+pub mod choice_event;
 pub mod character_move_event;
 pub mod character_rotate_event;
 pub mod character_single_move_event;
@@ -24,9 +26,11 @@ pub mod warp_event;
 use amethyst::ecs::World;
 
 pub use self::{
+    awaitable_event::AwaitableEvent,
     battle_start_event::BattleStartEvent,
     bgm_change_event::BgmChangeEvent,
     chained_events::ChainedEvents,
+    choice_event::{ChoiceCallback, ChoiceEvent},
     character_move_event::CharacterMoveEvent,
     character_rotate_event::CharacterRotateEvent,
     character_single_move_event::CharacterSingleMoveEvent,
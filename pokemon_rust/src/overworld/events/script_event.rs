@@ -1,11 +1,14 @@
 //! Runs a [`GameScript`](../map/map/enum.GameScript.html) from the
 //! [script repository](../map/map/struct.Map.html#structfield.script_repository)
 //! of a map, given its corresponding [`MapId`](../map/struct.MapId.html) and the
-//! index of the script.
+//! index of the script. Scripts with a
+//! [`GameCondition`](../game_variables/enum.GameCondition.html) are skipped
+//! while the condition doesn't hold.
 
 use amethyst::ecs::{World, WorldExt};
 
 use crate::{
+    game_variables::{GameCondition, GameVariables},
     lua::run_lua_script,
     map::{GameScript, MapHandler, MapId},
 };
@@ -15,6 +18,7 @@ use super::{BoxedGameEvent, ExecutionConditions, GameEvent};
 #[derive(Clone)]
 pub struct ScriptEvent {
     script: Script,
+    condition: Option<GameCondition>,
 }
 
 #[derive(Clone)]
@@ -27,12 +31,27 @@ impl ScriptEvent {
     pub fn new(map: MapId, script_index: usize) -> ScriptEvent {
         ScriptEvent {
             script: Script::Reference { map, script_index },
+            condition: None,
         }
     }
 
     pub fn from_script(script: GameScript) -> ScriptEvent {
         ScriptEvent {
             script: Script::Instance(script),
+            condition: None,
+        }
+    }
+
+    pub fn with_condition(mut self, condition: Option<GameCondition>) -> ScriptEvent {
+        self.condition = condition;
+        self
+    }
+
+    /// Checks if the condition of this script holds, if there's one.
+    pub fn is_enabled(&self, world: &World) -> bool {
+        match &self.condition {
+            Some(condition) => world.read_resource::<GameVariables>().satisfies(condition),
+            None => true,
         }
     }
 }
@@ -53,6 +72,10 @@ impl GameEvent for ScriptEvent {
     fn start(&mut self, _world: &mut World) {}
 
     fn tick<'a>(&mut self, world: &'a mut World, _disabled_inputs: bool) {
+        if !self.is_enabled(world) {
+            return;
+        }
+
         let game_script = match &self.script {
             Script::Reference { map, script_index } => world
                 .read_resource::<MapHandler>()
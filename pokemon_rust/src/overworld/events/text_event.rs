@@ -9,7 +9,7 @@ use amethyst::{
 
 use crate::{
     common::CommonResources,
-    text::{create_text_box, TextBox},
+    text::{create_text_box, TextBox, TextLayoutData},
 };
 
 use super::{BoxedGameEvent, ExecutionConditions, GameEvent};
@@ -39,13 +39,14 @@ impl GameEvent for TextEvent {
 
     fn start(&mut self, world: &mut World) {
         let text_box = {
-            let (mut ui_images, mut ui_texts, mut ui_transforms, entities, resources) =
+            let (mut ui_images, mut ui_texts, mut ui_transforms, entities, resources, layout_data) =
                 <(
                     WriteStorage<UiImage>,
                     WriteStorage<UiText>,
                     WriteStorage<UiTransform>,
                     Entities,
                     ReadExpect<CommonResources>,
+                    TextLayoutData,
                 )>::fetch(world);
 
             create_text_box(
@@ -55,6 +56,7 @@ impl GameEvent for TextEvent {
                 &mut ui_transforms,
                 &entities,
                 &resources,
+                &layout_data,
             )
         };
 
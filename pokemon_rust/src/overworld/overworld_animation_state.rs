@@ -6,13 +6,14 @@ use amethyst::{
 
 use crate::{
     animations::AnimationSystem,
+    lua::resume_lua_scripts,
     overworld::{
         entities::character::CharacterAnimation,
         events::{EventExecutor, EventQueue},
         overworld_state::OverworldState,
         systems::{CharacterMovementSystem, MapChangeAnnouncementSystem, NpcInteractionSystem},
     },
-    text::TextSystem,
+    text::{ChoiceSystem, TextSystem},
 };
 
 use std::{cell::RefCell, ops::Deref, rc::Rc};
@@ -48,6 +49,7 @@ impl SimpleState for OverworldAnimationState<'_, '_> {
             .with(NpcInteractionSystem, "npc_interaction_system", &[])
             .with(MapChangeAnnouncementSystem, "announcement_system", &[])
             .with(TextSystem::new(world), "text_system", &[])
+            .with(ChoiceSystem::new(world), "choice_system", &[])
             .with_pool(world.read_resource::<ArcThreadPool>().deref().clone())
             .build();
 
@@ -58,6 +60,8 @@ impl SimpleState for OverworldAnimationState<'_, '_> {
     fn update(&mut self, data: &mut StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
         let StateData { world, .. } = data;
 
+        resume_lua_scripts(world);
+
         if let Some(dispatcher) = &mut self.dispatcher {
             dispatcher.dispatch(world);
         }
@@ -11,6 +11,7 @@ use crate::{
     audio::AudioSystem,
     battle::battle_state::BattleState,
     config::GameConfig,
+    lua::resume_lua_scripts,
     overworld::{
         entities::character::CharacterAnimation,
         events::{EventExecutor, EventQueue},
@@ -22,7 +23,7 @@ use crate::{
             PlayerInputSystem,
         },
     },
-    text::TextSystem,
+    text::{ChoiceSystem, TextSystem},
 };
 
 use std::{cell::RefCell, ops::Deref, rc::Rc};
@@ -68,6 +69,7 @@ impl SimpleState for OverworldState<'_, '_> {
             )
             .with(MapChangeAnnouncementSystem, "announcement_system", &[])
             .with(TextSystem::new(world), "text_system", &[])
+            .with(ChoiceSystem::new(world), "choice_system", &[])
             .with(FpsCounterSystem, "fps_counter_system", &[])
             .with_pool(world.read_resource::<ArcThreadPool>().deref().clone())
             .build();
@@ -81,6 +83,8 @@ impl SimpleState for OverworldState<'_, '_> {
     fn update(&mut self, data: &mut StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
         let StateData { world, .. } = data;
 
+        resume_lua_scripts(world);
+
         if let Some(dispatcher) = &mut self.dispatcher {
             dispatcher.dispatch(world);
         }
@@ -1,3 +1,5 @@
 pub mod movement;
 pub mod pokemon;
+pub mod side_conditions;
 pub mod status_conditions;
+pub mod volatile_statuses;
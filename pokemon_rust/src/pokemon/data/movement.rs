@@ -1,27 +1,37 @@
-use crate::pokemon::{
-    movement::{
-        ModifiedAccuracy,
-        ModifiedUsageAttempt,
-        Move,
-        MoveCategory,
-        MoveDex,
-        MoveFlag,
-        MovePower,
-        MultiHit,
-        SecondaryEffect,
-        SimpleEffect,
-        SimpleEffectTarget,
-        TargetType,
+use crate::{
+    battle::backend::{BattleBackend, DamageCause, TypeEffectiveness},
+    pokemon::{
+        movement::{
+            DamageSideEffect,
+            FixedDamage,
+            ModifiedAccuracy,
+            ModifiedUsageAttempt,
+            Move,
+            MoveCategory,
+            MoveDex,
+            MoveFlag,
+            MoveId,
+            MovePower,
+            MultiHit,
+            MultiTurn,
+            SecondaryEffect,
+            SimpleEffect,
+            SimpleEffectTarget,
+            TargetType,
+        },
+        PokemonType,
+        SideCondition,
+        SimpleStatusCondition,
+        SimpleVolatileStatus,
+        Stat,
+        StatusCondition,
+        VolatileStatus,
     },
-    PokemonType,
-    SimpleStatusCondition,
-    Stat,
-    StatusCondition,
 };
 
 use lazy_static::lazy_static;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 macro_rules! flags {
     [$($value:expr),*] => {
@@ -40,7 +50,31 @@ lazy_static! {
         let mut result = Vec::new();
 
         result.push(Move {
-            id: "Acid".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Absorb".to_string(),
+            display_name: "Absorb".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(20),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 25,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: Some(DamageSideEffect::Drain(0.5)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Acid".to_string(),
             display_name: "Acid".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Poison,
@@ -55,6 +89,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -66,7 +102,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "AcidArmor".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "AcidArmor".to_string(),
             display_name: "Acid Armor".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Poison,
@@ -81,6 +118,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -92,7 +131,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Agility".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Agility".to_string(),
             display_name: "Agility".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -107,6 +147,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -118,7 +160,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Amnesia".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Amnesia".to_string(),
             display_name: "Amnesia".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -133,6 +176,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -144,7 +189,42 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "AuroraBeam".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Attract".to_string(),
+            display_name: "Attract".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, user, target, _mov| {
+                if backend.can_add_volatile_status(target, &VolatileStatus::Infatuation { user }) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, target, _mov| {
+                    backend.add_volatile_status(target, VolatileStatus::Infatuation { user });
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "AuroraBeam".to_string(),
             display_name: "Aurora Beam".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ice,
@@ -159,6 +239,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -170,7 +252,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Barrier".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Barrier".to_string(),
             display_name: "Barrier".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -185,6 +268,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -196,7 +281,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Bite".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Bite".to_string(),
             display_name: "Bite".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Dark,
@@ -211,6 +297,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::Flinch,
@@ -219,7 +307,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "BoneClub".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "BoneClub".to_string(),
             display_name: "Bone Club".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ground,
@@ -234,6 +323,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::Flinch,
@@ -242,7 +333,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Bubble".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Bubble".to_string(),
             display_name: "Bubble".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Water,
@@ -257,6 +349,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -268,7 +362,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "BubbleBeam".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "BubbleBeam".to_string(),
             display_name: "Bubble Beam".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Water,
@@ -283,6 +378,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -294,7 +391,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "CometPunch".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "CometPunch".to_string(),
             display_name: "Comet Punch".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -319,12 +417,15 @@ lazy_static! {
                     _ => unreachable!(),
                 }
             })),
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "ConfuseRay".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "ConfuseRay".to_string(),
             display_name: "Confuse Ray".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ghost,
@@ -335,7 +436,7 @@ lazy_static! {
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if backend.has_flag(target, "confusion") {
+                if !backend.can_add_volatile_status(target, &VolatileStatus::Confusion) {
                     return ModifiedUsageAttempt::Fail;
                 }
 
@@ -345,6 +446,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::Confusion,
@@ -353,7 +456,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Confusion".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Confusion".to_string(),
             display_name: "Confusion".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -368,6 +472,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::Confusion,
@@ -376,7 +482,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Constrict".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Constrict".to_string(),
             display_name: "Constrict".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -391,6 +498,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -402,7 +511,79 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "CrabHammer".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Conversion".to_string(),
+            display_name: "Conversion".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                if backend.has_type(user, get_conversion_type(backend, user)) {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let new_type = get_conversion_type(backend, user);
+                    backend.change_types(user, vec![new_type]);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Conversion2".to_string(),
+            display_name: "Conversion 2".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, user, target, _mov| {
+                if get_conversion_2_types(backend, user, target).is_empty() {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::SingleTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, target, _mov| {
+                    let types = get_conversion_2_types(backend, user, target);
+                    let new_type = backend.choose_conversion_2_type(&types);
+                    backend.change_types(user, vec![new_type]);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "CrabHammer".to_string(),
             display_name: "Crab Hammer".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Water,
@@ -417,12 +598,70 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: true,
         });
 
         result.push(Move {
-            id: "Cut".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Curse".to_string(),
+            display_name: "Curse".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ghost,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, user, target, _mov| {
+                let is_ghost = backend.has_type(user, PokemonType::Ghost);
+
+                if is_ghost && backend.has_volatile_status(target, SimpleVolatileStatus::Curse) {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, target, _mov| {
+                    // Ghost-type users sacrifice half of their HP to curse
+                    // the target, while others just change their own stats
+                    if backend.has_type(user, PokemonType::Ghost) {
+                        let cost = backend.get_stat(user, Stat::HP) / 2;
+
+                        backend.inflict_calculated_damage(
+                            user,
+                            cost,
+                            TypeEffectiveness::Normal,
+                            false,
+                            None,
+                            false,
+                            DamageCause::HpCost,
+                        );
+                        backend.add_volatile_status(target, VolatileStatus::Curse);
+                    } else {
+                        backend.change_stat_stage(user, Stat::Speed, -1);
+                        backend.change_stat_stage(user, Stat::Attack, 1);
+                        backend.change_stat_stage(user, Stat::Defense, 1);
+                    }
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Cut".to_string(),
             display_name: "Cut".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -437,12 +676,82 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Dig".to_string(),
+            display_name: "Dig".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ground,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(80),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::ChargesUnderground],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: Some(MultiTurn::Charge { semi_invulnerable: true }),
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "DizzyPunch".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Disable".to_string(),
+            display_name: "Disable".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, _user, target, _mov| {
+                let can_disable = backend
+                    .get_last_used_move(target)
+                    .map_or(false, |move_id| {
+                        backend.can_add_volatile_status(target, &VolatileStatus::Disable {
+                            move_id,
+                        })
+                    });
+
+                if can_disable {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, _user, target, _mov| {
+                    let move_id = backend.get_last_used_move(target).unwrap();
+
+                    backend.add_volatile_status(target, VolatileStatus::Disable { move_id });
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "DizzyPunch".to_string(),
             display_name: "Dizzy Punch".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -457,6 +766,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 20,
                 effect: SimpleEffect::Confusion,
@@ -465,7 +776,31 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "DoubleKick".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "DoubleEdge".to_string(),
+            display_name: "Double-Edge".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(120),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: Some(DamageSideEffect::Recoil(1. / 3.)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "DoubleKick".to_string(),
             display_name: "Double Kick".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Fight,
@@ -483,12 +818,15 @@ lazy_static! {
                 min_hits: 2,
                 max_hits: 2,
             }),
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "DoubleSlap".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "DoubleSlap".to_string(),
             display_name: "Double Slap".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -513,12 +851,15 @@ lazy_static! {
                     _ => unreachable!(),
                 }
             })),
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "DoubleTeam".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "DoubleTeam".to_string(),
             display_name: "Double Team".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -533,6 +874,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -544,7 +887,31 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "DrillPeck".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "DragonRage".to_string(),
+            display_name: "Dragon Rage".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Dragon,
+            category: MoveCategory::Special,
+            base_power: MovePower::Fixed(FixedDamage::Constant(40)),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "DrillPeck".to_string(),
             display_name: "Drill Peck".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Flying,
@@ -559,12 +926,38 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Earthquake".to_string(),
+            display_name: "Earthquake".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ground,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(100),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::HitsTargetsUnderground],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::AllAdjacent,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "EggBomb".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "EggBomb".to_string(),
             display_name: "Egg Bomb".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -579,12 +972,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Ember".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Ember".to_string(),
             display_name: "Ember".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Fire,
@@ -599,6 +995,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
@@ -607,7 +1005,51 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "FireBlast".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Encore".to_string(),
+            display_name: "Encore".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, _user, target, mov| {
+                let can_encore = backend
+                    .get_last_used_move(target)
+                    .filter(|move_id| *move_id != mov.id)
+                    .map_or(false, |move_id| {
+                        backend.can_add_volatile_status(target, &VolatileStatus::Encore { move_id })
+                    });
+
+                if can_encore {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, _user, target, _mov| {
+                    let move_id = backend.get_last_used_move(target).unwrap();
+
+                    backend.add_volatile_status(target, VolatileStatus::Encore { move_id });
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "FireBlast".to_string(),
             display_name: "Fire Blast".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Fire,
@@ -622,6 +1064,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
@@ -630,7 +1074,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "FirePunch".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "FirePunch".to_string(),
             display_name: "Fire Punch".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Fire,
@@ -645,6 +1090,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
@@ -653,7 +1100,31 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Fissure".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "FireSpin".to_string(),
+            display_name: "Fire Spin".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fire,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(35),
+            power_modifier: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: Some(MultiTurn::Binding),
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Fissure".to_string(),
             display_name: "Fissure".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ground,
@@ -677,12 +1148,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Flamethrower".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Flamethrower".to_string(),
             display_name: "Flamethrower".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Fire,
@@ -697,6 +1171,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Burn),
@@ -705,7 +1181,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Flash".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Flash".to_string(),
             display_name: "Flash".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -720,6 +1197,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -731,37 +1210,98 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "FuryAttack".to_string(),
-            display_name: "Fury Attack".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Fly".to_string(),
+            display_name: "Fly".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Normal,
+            move_type: PokemonType::Flying,
             category: MoveCategory::Physical,
-            base_power: MovePower::Constant(15),
+            base_power: MovePower::Constant(90),
             power_modifier: None,
-            accuracy: Some(85),
+            accuracy: Some(95),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::ChargesInTheAir],
             on_usage_attempt: None,
-            pp: 20,
+            pp: 15,
             priority: 0,
-            target_type: TargetType::SingleAdjacentTarget,
-            multi_hit: Some(MultiHit::Custom(|mut rng| {
-                let value = rng.check_custom_multi_hit(1, 6);
+            target_type: TargetType::SingleTarget,
+            multi_hit: None,
+            multi_turn: Some(MultiTurn::Charge { semi_invulnerable: true }),
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
 
-                match value {
-                    1 | 2 => 2,
-                    3 | 4 => 3,
-                    5 => 4,
-                    6 => 5,
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "FocusEnergy".to_string(),
+            display_name: "Focus Energy".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                if backend.has_volatile_status(user, SimpleVolatileStatus::FocusEnergy) {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    backend.add_volatile_status(user, VolatileStatus::FocusEnergy);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "FuryAttack".to_string(),
+            display_name: "Fury Attack".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(15),
+            power_modifier: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: Some(MultiHit::Custom(|mut rng| {
+                let value = rng.check_custom_multi_hit(1, 6);
+
+                match value {
+                    1 | 2 => 2,
+                    3 | 4 => 3,
+                    5 => 4,
+                    6 => 5,
                     _ => unreachable!(),
                 }
             })),
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Glare".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Glare".to_string(),
             display_name: "Glare".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -782,6 +1322,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -790,7 +1332,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Growl".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Growl".to_string(),
             display_name: "Growl".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -805,6 +1348,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -816,7 +1361,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Guillotine".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Guillotine".to_string(),
             display_name: "Guillotine".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -840,12 +1386,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Gust".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Gust".to_string(),
             display_name: "Gust".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Flying,
@@ -854,18 +1403,21 @@ lazy_static! {
             power_modifier: None,
             accuracy: Some(100),
             accuracy_modifier: None,
-            flags: HashSet::new(),
+            flags: flags![MoveFlag::HitsTargetsInTheAir],
             on_usage_attempt: None,
             pp: 35,
             priority: 0,
             target_type: TargetType::SingleTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Harden".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Harden".to_string(),
             display_name: "Harden".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -880,6 +1432,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -891,7 +1445,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Headbutt".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Headbutt".to_string(),
             display_name: "Headbutt".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -906,6 +1461,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::Flinch,
@@ -914,7 +1471,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "HornAttack".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "HornAttack".to_string(),
             display_name: "Horn Attack".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -929,12 +1487,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "HornDrill".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "HornDrill".to_string(),
             display_name: "Horn Drill".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -958,12 +1519,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "HydroPump".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "HydroPump".to_string(),
             display_name: "Hydro Pump".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Water,
@@ -978,12 +1542,38 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "HyperBeam".to_string(),
+            display_name: "Hyper Beam".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(150),
+            power_modifier: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: Some(MultiTurn::Recharge),
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "HyperFang".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "HyperFang".to_string(),
             display_name: "Hyper Fang".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -998,6 +1588,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::Flinch,
@@ -1006,7 +1598,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Hypnosis".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Hypnosis".to_string(),
             display_name: "Hypnosis".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -1027,6 +1620,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
@@ -1038,7 +1633,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "IceBeam".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "IceBeam".to_string(),
             display_name: "Ice Beam".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ice,
@@ -1053,6 +1649,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Freeze),
@@ -1061,7 +1659,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "IcePunch".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "IcePunch".to_string(),
             display_name: "Ice Punch".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ice,
@@ -1076,6 +1675,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Freeze),
@@ -1084,7 +1685,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "KarateChop".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "KarateChop".to_string(),
             display_name: "Karate Chop".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Fight,
@@ -1099,12 +1701,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: true,
         });
 
         result.push(Move {
-            id: "Kinesis".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Kinesis".to_string(),
             display_name: "Kinesis".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -1119,6 +1724,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -1130,7 +1737,65 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Leer".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "LeechLife".to_string(),
+            display_name: "Leech Life".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Bug,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(80),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: Some(DamageSideEffect::Drain(0.5)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "LeechSeed".to_string(),
+            display_name: "Leech Seed".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, target, _mov| {
+                if backend.can_add_volatile_status(target, &VolatileStatus::LeechSeed { user }) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, target, _mov| {
+                    backend.add_volatile_status(target, VolatileStatus::LeechSeed { user });
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Leer".to_string(),
             display_name: "Leer".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1145,6 +1810,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -1156,7 +1823,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Lick".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Lick".to_string(),
             display_name: "Lick".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ghost,
@@ -1171,6 +1839,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -1179,7 +1849,45 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "LovelyKiss".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "LightScreen".to_string(),
+            display_name: "Light Screen".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Psychic,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let team = backend.get_pokemon_team(user);
+
+                if backend.can_add_side_condition(team, SideCondition::LightScreen) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::AllyTeam,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let team = backend.get_pokemon_team(user);
+                    backend.add_side_condition(team, SideCondition::LightScreen);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "LovelyKiss".to_string(),
             display_name: "Lovely Kiss".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1200,6 +1908,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
@@ -1211,7 +1921,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "MachPunch".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "MachPunch".to_string(),
             display_name: "Mach Punch".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Fight,
@@ -1226,12 +1937,49 @@ lazy_static! {
             priority: 1,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Meditate".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "MeanLook".to_string(),
+            display_name: "Mean Look".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, target, _mov| {
+                if backend.can_add_volatile_status(target, &VolatileStatus::Trapped { user }) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 5,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, target, _mov| {
+                    backend.add_volatile_status(target, VolatileStatus::Trapped { user });
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Meditate".to_string(),
             display_name: "Meditate".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -1246,6 +1994,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -1257,7 +2007,31 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "MegaKick".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "MegaDrain".to_string(),
+            display_name: "Mega Drain".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(40),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: Some(DamageSideEffect::Drain(0.5)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "MegaKick".to_string(),
             display_name: "Mega Kick".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1272,12 +2046,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "MegaPunch".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "MegaPunch".to_string(),
             display_name: "Mega Punch".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1292,12 +2069,75 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Mist".to_string(),
+            display_name: "Mist".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ice,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let team = backend.get_pokemon_team(user);
+
+                if backend.can_add_side_condition(team, SideCondition::Mist) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 30,
+            priority: 0,
+            target_type: TargetType::AllyTeam,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let team = backend.get_pokemon_team(user);
+                    backend.add_side_condition(team, SideCondition::Mist);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "NightShade".to_string(),
+            display_name: "Night Shade".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ghost,
+            category: MoveCategory::Special,
+            base_power: MovePower::Fixed(FixedDamage::UserLevel),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Peck".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Peck".to_string(),
             display_name: "Peck".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Flying,
@@ -1312,12 +2152,38 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "PetalDance".to_string(),
+            display_name: "Petal Dance".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(120),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentFoe,
+            multi_hit: None,
+            multi_turn: Some(MultiTurn::Rampage),
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "PoisonGas".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "PoisonGas".to_string(),
             display_name: "Poison Gas".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Poison,
@@ -1338,6 +2204,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
@@ -1346,7 +2214,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "PoisonPowder".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "PoisonPowder".to_string(),
             display_name: "Poison Powder".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Poison,
@@ -1367,6 +2236,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
@@ -1375,7 +2246,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "PoisonSting".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "PoisonSting".to_string(),
             display_name: "Poison Sting".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Poison,
@@ -1390,6 +2262,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
@@ -1398,7 +2272,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Pound".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Pound".to_string(),
             display_name: "Pound".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1413,12 +2288,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Psychic".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Psychic".to_string(),
             display_name: "Psychic".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -1433,6 +2311,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatChange {
@@ -1444,7 +2324,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Psybeam".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Psybeam".to_string(),
             display_name: "Psybeam".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Psychic,
@@ -1459,6 +2340,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::Confusion,
@@ -1467,7 +2350,31 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "QuickAttack".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Psywave".to_string(),
+            display_name: "Psywave".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Psychic,
+            category: MoveCategory::Special,
+            base_power: MovePower::Fixed(FixedDamage::RandomUserLevel),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "QuickAttack".to_string(),
             display_name: "Quick Attack".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1482,12 +2389,15 @@ lazy_static! {
             priority: 1,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "RazorLeaf".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "RazorLeaf".to_string(),
             display_name: "Razor Leaf".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Grass,
@@ -1502,35 +2412,78 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: true,
         });
 
         result.push(Move {
-            id: "RockSlide".to_string(),
-            display_name: "Rock Slide".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Reflect".to_string(),
+            display_name: "Reflect".to_string(),
             description: "".to_string(), // TODO
-            move_type: PokemonType::Rock,
-            category: MoveCategory::Physical,
-            base_power: MovePower::Constant(75),
+            move_type: PokemonType::Psychic,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
             power_modifier: None,
-            accuracy: Some(90),
+            accuracy: None,
             accuracy_modifier: None,
             flags: HashSet::new(),
-            on_usage_attempt: None,
-            pp: 10,
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let team = backend.get_pokemon_team(user);
+
+                if backend.can_add_side_condition(team, SideCondition::Reflect) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 20,
             priority: 0,
-            target_type: TargetType::AllAdjacentFoes,
+            target_type: TargetType::AllyTeam,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
-                chance: 30,
-                effect: SimpleEffect::Flinch,
-            }),
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let team = backend.get_pokemon_team(user);
+                    backend.add_side_condition(team, SideCondition::Reflect);
+                }),
+            }),
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "RockThrow".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "RockSlide".to_string(),
+            display_name: "Rock Slide".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Rock,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(75),
+            power_modifier: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::AllAdjacentFoes,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 30,
+                effect: SimpleEffect::Flinch,
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "RockThrow".to_string(),
             display_name: "Rock Throw".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Rock,
@@ -1545,12 +2498,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "RollingKick".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "RollingKick".to_string(),
             display_name: "Rolling Kick".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Fight,
@@ -1565,6 +2521,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::Flinch,
@@ -1572,10 +2530,48 @@ lazy_static! {
             critical_hit: false,
         });
 
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Safeguard".to_string(),
+            display_name: "Safeguard".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let team = backend.get_pokemon_team(user);
+
+                if backend.can_add_side_condition(team, SideCondition::Safeguard) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 25,
+            priority: 0,
+            target_type: TargetType::AllyTeam,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let team = backend.get_pokemon_team(user);
+                    backend.add_side_condition(team, SideCondition::Safeguard);
+                }),
+            }),
+            critical_hit: false,
+        });
+
         result.push(Move {
             // TODO: Sand Attack should still affect flying-types and Pokémon
             // with Levitate
-            id: "SandAttack".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "SandAttack".to_string(),
             display_name: "Sand Attack".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Ground,
@@ -1590,6 +2586,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -1601,7 +2599,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Scratch".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Scratch".to_string(),
             display_name: "Scratch".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1616,12 +2615,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Screech".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Screech".to_string(),
             display_name: "Screech".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1636,6 +2638,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -1647,7 +2651,31 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Sharpen".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "SeismicToss".to_string(),
+            display_name: "Seismic Toss".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Fight,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Fixed(FixedDamage::UserLevel),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Sharpen".to_string(),
             display_name: "Sharpen".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1662,6 +2690,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -1673,7 +2703,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Sing".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Sing".to_string(),
             display_name: "Sing".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1694,6 +2725,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
@@ -1705,7 +2738,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Slam".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Slam".to_string(),
             display_name: "Slam".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1720,12 +2754,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Slash".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Slash".to_string(),
             display_name: "Slash".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1740,12 +2777,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: true,
         });
 
         result.push(Move {
-            id: "SleepPowder".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "SleepPowder".to_string(),
             display_name: "Sleep Powder".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Grass,
@@ -1766,6 +2806,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
@@ -1777,7 +2819,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Sludge".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Sludge".to_string(),
             display_name: "Sludge".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Poison,
@@ -1792,6 +2835,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 30,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
@@ -1800,7 +2845,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Smog".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Smog".to_string(),
             display_name: "Smog".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Poison,
@@ -1815,6 +2861,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 40,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Poison),
@@ -1823,7 +2871,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Smokescreen".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Smokescreen".to_string(),
             display_name: "Smokescreen".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1838,6 +2887,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -1849,7 +2900,125 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Splash".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Soak".to_string(),
+            display_name: "Soak".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Water,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, _user, target, _mov| {
+                if backend.get_current_types(target) == [PokemonType::Water] {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, _user, target, _mov| {
+                    backend.change_types(target, vec![PokemonType::Water]);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "SolarBeam".to_string(),
+            display_name: "Solar Beam".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Grass,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(120),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: Some(MultiTurn::Charge { semi_invulnerable: false }),
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "SonicBoom".to_string(),
+            display_name: "Sonic Boom".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Special,
+            base_power: MovePower::Fixed(FixedDamage::Constant(20)),
+            power_modifier: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Spikes".to_string(),
+            display_name: "Spikes".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Ground,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let team = backend.get_pokemon_team(user).get_opponent();
+
+                if backend.can_add_side_condition(team, SideCondition::Spikes) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::OpposingTeam,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let team = backend.get_pokemon_team(user).get_opponent();
+                    backend.add_side_condition(team, SideCondition::Spikes);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Splash".to_string(),
             display_name: "Splash".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1864,12 +3033,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Spore".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Spore".to_string(),
             display_name: "Spore".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Grass,
@@ -1890,6 +3062,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Sleep {
@@ -1901,7 +3075,45 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Strength".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "StealthRock".to_string(),
+            display_name: "Stealth Rock".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Rock,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let team = backend.get_pokemon_team(user).get_opponent();
+
+                if backend.can_add_side_condition(team, SideCondition::StealthRock) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::OpposingTeam,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let team = backend.get_pokemon_team(user).get_opponent();
+                    backend.add_side_condition(team, SideCondition::StealthRock);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Strength".to_string(),
             display_name: "Strength".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1916,12 +3128,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "StringShot".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "StringShot".to_string(),
             display_name: "String Shot".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Bug,
@@ -1936,6 +3151,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -1947,7 +3164,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "StunSpore".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "StunSpore".to_string(),
             display_name: "Stun Spore".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Grass,
@@ -1968,6 +3186,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -1976,7 +3196,80 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Supersonic".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Substitute".to_string(),
+            display_name: "Substitute".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let max_hp = backend.get_stat(user, Stat::HP);
+                let has_substitute =
+                    backend.has_volatile_status(user, SimpleVolatileStatus::Substitute);
+
+                if has_substitute || backend.get_pokemon(user).current_hp <= max_hp / 4 {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::User,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let cost = backend.get_stat(user, Stat::HP) / 4;
+
+                    backend.inflict_calculated_damage(
+                        user,
+                        cost,
+                        TypeEffectiveness::Normal,
+                        false,
+                        None,
+                        false,
+                        DamageCause::HpCost,
+                    );
+                    backend.add_volatile_status(user, VolatileStatus::Substitute { hp: cost });
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "SuperFang".to_string(),
+            display_name: "Super Fang".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Fixed(FixedDamage::HalfTargetHp),
+            power_modifier: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Supersonic".to_string(),
             display_name: "Supersonic".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -1987,7 +3280,7 @@ lazy_static! {
             accuracy_modifier: None,
             flags: HashSet::new(),
             on_usage_attempt: Some(|backend, _user, target, _mov| {
-                if backend.has_flag(target, "confusion") {
+                if !backend.can_add_volatile_status(target, &VolatileStatus::Confusion) {
                     return ModifiedUsageAttempt::Fail;
                 }
 
@@ -1997,6 +3290,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::Confusion,
@@ -2005,7 +3300,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Swift".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Swift".to_string(),
             display_name: "Swift".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -2020,12 +3316,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "SwordsDance".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "SwordsDance".to_string(),
             display_name: "Swords Dance".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -2040,6 +3339,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -2051,7 +3352,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Tackle".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Tackle".to_string(),
             display_name: "Tackle".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -2066,12 +3368,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "TailWhip".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "TailWhip".to_string(),
             display_name: "Tail Whip".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -2086,6 +3391,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::AllAdjacentFoes,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -2097,7 +3404,151 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Thunderbolt".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Tailwind".to_string(),
+            display_name: "Tailwind".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Flying,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let team = backend.get_pokemon_team(user);
+
+                if backend.can_add_side_condition(team, SideCondition::Tailwind) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 15,
+            priority: 0,
+            target_type: TargetType::AllyTeam,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let team = backend.get_pokemon_team(user);
+                    backend.add_side_condition(team, SideCondition::Tailwind);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "TakeDown".to_string(),
+            display_name: "Take Down".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(90),
+            power_modifier: None,
+            accuracy: Some(85),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: Some(DamageSideEffect::Recoil(0.25)),
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Taunt".to_string(),
+            display_name: "Taunt".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Dark,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, _user, target, _mov| {
+                if backend.can_add_volatile_status(target, &VolatileStatus::Taunt) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, _user, target, _mov| {
+                    backend.add_volatile_status(target, VolatileStatus::Taunt);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Thrash".to_string(),
+            display_name: "Thrash".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(120),
+            power_modifier: None,
+            accuracy: Some(100),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentFoe,
+            multi_hit: None,
+            multi_turn: Some(MultiTurn::Rampage),
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Thunder".to_string(),
+            display_name: "Thunder".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Electric,
+            category: MoveCategory::Special,
+            base_power: MovePower::Constant(110),
+            power_modifier: None,
+            accuracy: Some(70),
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::HitsTargetsInTheAir],
+            on_usage_attempt: None,
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 30,
+                effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Thunderbolt".to_string(),
             display_name: "Thunderbolt".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Electric,
@@ -2112,6 +3563,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -2120,7 +3573,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "ThunderPunch".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "ThunderPunch".to_string(),
             display_name: "Thunder Punch".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Electric,
@@ -2135,6 +3589,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -2143,7 +3599,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "ThunderShock".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "ThunderShock".to_string(),
             display_name: "Thunder Shock".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Electric,
@@ -2158,6 +3615,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 10,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -2166,7 +3625,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "ThunderWave".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "ThunderWave".to_string(),
             display_name: "Thunder Wave".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Electric,
@@ -2187,6 +3647,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Paralysis),
@@ -2195,7 +3657,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "Toxic".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Toxic".to_string(),
             display_name: "Toxic".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Poison,
@@ -2222,6 +3685,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatusCondition(StatusCondition::Toxic { counter: 1 }),
@@ -2230,7 +3695,79 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "VineWhip".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "ToxicSpikes".to_string(),
+            display_name: "Toxic Spikes".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Poison,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, user, _target, _mov| {
+                let team = backend.get_pokemon_team(user).get_opponent();
+
+                if backend.can_add_side_condition(team, SideCondition::ToxicSpikes) {
+                    ModifiedUsageAttempt::Continue
+                } else {
+                    ModifiedUsageAttempt::Fail
+                }
+            }),
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::OpposingTeam,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, _target, _mov| {
+                    let team = backend.get_pokemon_team(user).get_opponent();
+                    backend.add_side_condition(team, SideCondition::ToxicSpikes);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Transform".to_string(),
+            display_name: "Transform".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            base_power: MovePower::Special,
+            power_modifier: None,
+            accuracy: None,
+            accuracy_modifier: None,
+            flags: flags![MoveFlag::BypassesSubstitute],
+            on_usage_attempt: Some(|backend, user, target, _mov| {
+                if backend.is_transformed(user) || backend.is_transformed(target) {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            pp: 10,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
+            secondary_effect: Some(SecondaryEffect {
+                chance: 100,
+                effect: SimpleEffect::OnHit(|backend, user, target, _mov| {
+                    backend.transform(user, target);
+                }),
+            }),
+            critical_hit: false,
+        });
+
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "VineWhip".to_string(),
             display_name: "Vine Whip".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Grass,
@@ -2245,12 +3782,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "ViseGrip".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "ViseGrip".to_string(),
             display_name: "Vise Grip".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Normal,
@@ -2265,12 +3805,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Waterfall".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Waterfall".to_string(),
             display_name: "Waterfall".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Water,
@@ -2285,6 +3828,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 20,
                 effect: SimpleEffect::Flinch,
@@ -2293,7 +3838,8 @@ lazy_static! {
         });
 
         result.push(Move {
-            id: "WaterGun".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "WaterGun".to_string(),
             display_name: "Water Gun".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Water,
@@ -2308,12 +3854,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleAdjacentTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "WingAttack".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "WingAttack".to_string(),
             display_name: "Wing Attack".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Flying,
@@ -2328,12 +3877,15 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::SingleTarget,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: None,
             critical_hit: false,
         });
 
         result.push(Move {
-            id: "Withdraw".to_string(),
+            id: MoveId::UNASSIGNED,
+            name: "Withdraw".to_string(),
             display_name: "Withdraw".to_string(),
             description: "".to_string(), // TODO
             move_type: PokemonType::Water,
@@ -2348,6 +3900,8 @@ lazy_static! {
             priority: 0,
             target_type: TargetType::User,
             multi_hit: None,
+            multi_turn: None,
+            damage_side_effect: None,
             secondary_effect: Some(SecondaryEffect {
                 chance: 100,
                 effect: SimpleEffect::StatChange {
@@ -2358,15 +3912,88 @@ lazy_static! {
             critical_hit: false,
         });
 
-        MoveDex::new(
-            result
-                .into_iter()
-                .map(|data| (data.id.clone(), data))
-                .collect::<HashMap<_, _>>()
-        )
+        result.push(Move {
+            id: MoveId::UNASSIGNED,
+            name: "Wrap".to_string(),
+            display_name: "Wrap".to_string(),
+            description: "".to_string(), // TODO
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Physical,
+            base_power: MovePower::Constant(15),
+            power_modifier: None,
+            accuracy: Some(90),
+            accuracy_modifier: None,
+            flags: HashSet::new(),
+            on_usage_attempt: None,
+            pp: 20,
+            priority: 0,
+            target_type: TargetType::SingleAdjacentTarget,
+            multi_hit: None,
+            multi_turn: Some(MultiTurn::Binding),
+            damage_side_effect: None,
+            secondary_effect: None,
+            critical_hit: false,
+        });
+
+        MoveDex::new(result)
+    };
+
+    static ref CONFUSION_SELF_HIT: Move = Move {
+        id: MoveId::UNASSIGNED,
+        name: "ConfusionSelfHit".to_string(),
+        display_name: "Confusion Self-Hit".to_string(),
+        description: "".to_string(),
+        // The self-hit is typeless, so its type is never used
+        move_type: PokemonType::Normal,
+        category: MoveCategory::Physical,
+        base_power: MovePower::Constant(40),
+        power_modifier: None,
+        accuracy: None,
+        accuracy_modifier: None,
+        flags: HashSet::new(),
+        on_usage_attempt: None,
+        pp: 0,
+        priority: 0,
+        target_type: TargetType::User,
+        multi_hit: None,
+        multi_turn: None,
+        damage_side_effect: None,
+        secondary_effect: None,
+        critical_hit: false,
     };
 }
 
 pub fn get_all_moves() -> &'static MoveDex {
     &MOVEDEX
 }
+
+/// Returns the attack that a confused Pokémon uses against itself. It isn't
+/// part of the movedex, so it can't be learned or selected.
+pub fn get_confusion_self_hit() -> &'static Move {
+    &CONFUSION_SELF_HIT
+}
+
+/// Returns the type that Conversion turns the user into, which is the type of
+/// its first move.
+fn get_conversion_type(backend: &BattleBackend, user: usize) -> PokemonType {
+    let move_id = backend.get_moves(user)[0].unwrap();
+
+    get_all_moves().get_move(move_id).move_type
+}
+
+/// Returns the types that Conversion 2 can turn the user into, i.e the ones
+/// that resist the last move used by the target, except the current types of
+/// the user.
+fn get_conversion_2_types(backend: &BattleBackend, user: usize, target: usize) -> Vec<PokemonType> {
+    let move_type = match backend.get_last_used_move(target) {
+        Some(move_id) => get_all_moves().get_move(move_id).move_type,
+        None => return Vec::new(),
+    };
+
+    PokemonType::ALL
+        .iter()
+        .copied()
+        .filter(|t| backend.get_ruleset().type_chart.get_effectiveness(move_type, *t) < 1.)
+        .filter(|t| !backend.has_type(user, *t))
+        .collect()
+}
@@ -0,0 +1,190 @@
+use crate::{
+    battle::backend::{BattleBackend, DamageCause, TypeEffectiveness},
+    pokemon::{
+        movement::{Move, MoveCategory},
+        PokemonType,
+        SideCondition,
+        Stat,
+        StatusCondition,
+    },
+};
+
+use std::fmt::{Debug, Error, Formatter};
+
+#[derive(Clone)]
+pub struct SideConditionEffect {
+    /// The localization key of the name of this side condition.
+    pub display_name: &'static str,
+
+    /// The number of turns that this side condition lasts, counting the turn
+    /// in which it started. Side conditions without a duration (e.g entry
+    /// hazards) last until something removes them.
+    pub duration: Option<usize>,
+
+    /// How many times this side condition can be stacked, e.g Spikes can be
+    /// set up three times, hurting more with each layer.
+    pub max_layers: usize,
+
+    /// Called when an opposing move is about to deal damage to a Pokémon of
+    /// the team, returning a multiplier for that damage.
+    pub on_damage_calculation: Option<fn(mov: &Move, is_critical_hit: bool) -> f32>,
+
+    /// Called when backend.get_stat() is called for a Pokémon of the team,
+    /// receiving the value that it is about to return.
+    pub on_stat_calculation: Option<fn(
+        backend: &BattleBackend,
+        target: usize,
+        stat: Stat,
+        value: usize,
+    ) -> usize>,
+
+    /// Called when a Pokémon of the team switches in.
+    pub on_switch_in: Option<fn(backend: &mut BattleBackend, target: usize, layers: usize)>,
+}
+
+impl Debug for SideConditionEffect {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        f.write_str("SideConditionEffect")
+    }
+}
+
+fn is_grounded(backend: &BattleBackend, target: usize) -> bool {
+    // TODO: take Levitate, Air Balloon and the like into account
+    !backend.has_type(target, PokemonType::Flying)
+}
+
+fn inflict_hazard_damage(
+    backend: &mut BattleBackend,
+    target: usize,
+    fraction: f32,
+    cause: DamageCause,
+) {
+    let max_hp = backend.get_stat(target, Stat::HP) as f32;
+    let damage = (max_hp * fraction).floor().max(1.) as usize;
+
+    backend.inflict_calculated_damage(
+        target,
+        damage,
+        TypeEffectiveness::Normal,
+        false,
+        None,
+        false,
+        cause,
+    );
+}
+
+pub fn get_side_condition_effect(condition: SideCondition) -> SideConditionEffect {
+    let default = SideConditionEffect {
+        display_name: "",
+        duration: None,
+        max_layers: 1,
+        on_damage_calculation: None,
+        on_stat_calculation: None,
+        on_switch_in: None,
+    };
+
+    match condition {
+        SideCondition::Reflect => SideConditionEffect {
+            display_name: "side_condition.reflect",
+            duration: Some(5),
+            on_damage_calculation: Some(|mov, is_critical_hit| {
+                if mov.category == MoveCategory::Physical && !is_critical_hit {
+                    0.5
+                } else {
+                    1.
+                }
+            }),
+            ..default
+        },
+        SideCondition::LightScreen => SideConditionEffect {
+            display_name: "side_condition.light_screen",
+            duration: Some(5),
+            on_damage_calculation: Some(|mov, is_critical_hit| {
+                if mov.category == MoveCategory::Special && !is_critical_hit {
+                    0.5
+                } else {
+                    1.
+                }
+            }),
+            ..default
+        },
+        SideCondition::Safeguard => SideConditionEffect {
+            display_name: "side_condition.safeguard",
+            duration: Some(5),
+            ..default
+        },
+        SideCondition::Mist => SideConditionEffect {
+            display_name: "side_condition.mist",
+            duration: Some(5),
+            ..default
+        },
+        SideCondition::Tailwind => SideConditionEffect {
+            display_name: "side_condition.tailwind",
+            duration: Some(4),
+            on_stat_calculation: Some(|_backend, _target, stat, value| {
+                if stat == Stat::Speed {
+                    value * 2
+                } else {
+                    value
+                }
+            }),
+            ..default
+        },
+        SideCondition::Spikes => SideConditionEffect {
+            display_name: "side_condition.spikes",
+            max_layers: 3,
+            on_switch_in: Some(|backend, target, layers| {
+                if !is_grounded(backend, target) {
+                    return;
+                }
+
+                let fraction = match layers {
+                    1 => 1. / 8.,
+                    2 => 1. / 6.,
+                    _ => 1. / 4.,
+                };
+
+                inflict_hazard_damage(backend, target, fraction, DamageCause::Spikes);
+            }),
+            ..default
+        },
+        SideCondition::ToxicSpikes => SideConditionEffect {
+            display_name: "side_condition.toxic_spikes",
+            max_layers: 2,
+            on_switch_in: Some(|backend, target, layers| {
+                if !is_grounded(backend, target) {
+                    return;
+                }
+
+                // Grounded Poison types absorb the spikes
+                if backend.has_type(target, PokemonType::Poison) {
+                    let team = backend.get_pokemon_team(target);
+                    backend.expire_side_condition(team, SideCondition::ToxicSpikes);
+                    return;
+                }
+
+                let condition = match layers {
+                    1 => StatusCondition::Poison,
+                    _ => StatusCondition::Toxic { counter: 1 },
+                };
+
+                backend.add_non_volatile_status_condition(target, condition);
+            }),
+            ..default
+        },
+        SideCondition::StealthRock => SideConditionEffect {
+            display_name: "side_condition.stealth_rock",
+            on_switch_in: Some(|backend, target, _layers| {
+                let effectiveness = backend.get_type_effectiveness_of(PokemonType::Rock, target);
+
+                inflict_hazard_damage(
+                    backend,
+                    target,
+                    effectiveness / 8.,
+                    DamageCause::StealthRock,
+                );
+            }),
+            ..default
+        },
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{
+    battle::backend::{rng::BattleRng, BattleBackend, DamageCause, HealCause, TypeEffectiveness},
+    pokemon::{
+        movement::{ModifiedUsageAttempt, Move, MoveCategory},
+        Gender,
+        PokemonType,
+        SideCondition,
+        SimpleVolatileStatus,
+        Stat,
+        VolatileStatus,
+    },
+};
+
+use std::fmt::{Debug, Error, Formatter};
+
+#[derive(Clone)]
+pub struct VolatileStatusEffect {
+    /// The localization key of the name of this volatile status.
+    pub display_name: &'static str,
+
+    /// Returns the number of turns that this volatile status lasts. Volatile
+    /// statuses without a duration last until something removes them.
+    pub duration: Option<fn(rng: &mut dyn BattleRng) -> usize>,
+
+    /// Determines if this volatile status can affect a given Pokémon.
+    pub can_affect: Option<fn(
+        backend: &BattleBackend,
+        target: usize,
+        status: &VolatileStatus,
+    ) -> bool>,
+
+    /// Called when the target is about to use a move, _before_ displaying "X used Y!".
+    pub on_before_use_move: Option<fn(
+        backend: &mut BattleBackend,
+        target: usize,
+        mov: &Move,
+    ) -> ModifiedUsageAttempt>,
+
+    /// Called when the target tries to use a move.
+    pub on_try_use_move: Option<fn(
+        backend: &mut BattleBackend,
+        target: usize,
+        mov: &Move,
+    ) -> ModifiedUsageAttempt>,
+
+    /// Called when the target is about to take damage from the move of
+    /// another Pokémon. Returns the damage that reaches the target, or `None`
+    /// if the damage was absorbed entirely.
+    pub on_damage: Option<fn(
+        backend: &mut BattleBackend,
+        target: usize,
+        damage: usize,
+    ) -> Option<usize>>,
+
+    /// Called when the turn ends.
+    pub on_turn_end: Option<fn(backend: &mut BattleBackend, target: usize)>,
+
+    /// Called when another Pokémon leaves the field while the target is
+    /// still on it.
+    pub on_switch_out: Option<fn(backend: &mut BattleBackend, target: usize, leaving: usize)>,
+}
+
+impl Debug for VolatileStatusEffect {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        f.write_str("VolatileStatusEffect")
+    }
+}
+
+/// Ends a volatile status that depends on the Pokémon that caused it (e.g
+/// Infatuation) when that Pokémon leaves the field.
+fn expire_if_source_left(
+    backend: &mut BattleBackend,
+    target: usize,
+    leaving: usize,
+    kind: SimpleVolatileStatus,
+) {
+    let source = match backend.get_volatile_status(target, kind) {
+        Some(VolatileStatus::Binding { user, .. })
+        | Some(VolatileStatus::Infatuation { user })
+        | Some(VolatileStatus::Trapped { user }) => *user,
+        _ => unreachable!(),
+    };
+
+    if source == leaving {
+        backend.expire_volatile_status(target, kind);
+    }
+}
+
+fn inflict_residual_damage(
+    backend: &mut BattleBackend,
+    target: usize,
+    fraction: f32,
+    cause: DamageCause,
+) -> usize {
+    let max_hp = backend.get_stat(target, Stat::HP) as f32;
+    let damage = (max_hp * fraction).ceil() as usize;
+    let dealt_damage = damage.min(backend.get_pokemon(target).current_hp);
+
+    backend.inflict_calculated_damage(
+        target,
+        damage,
+        TypeEffectiveness::Normal,
+        false,
+        None,
+        false,
+        cause,
+    );
+
+    dealt_damage
+}
+
+pub fn get_volatile_status_effect(status: SimpleVolatileStatus) -> VolatileStatusEffect {
+    let default = VolatileStatusEffect {
+        display_name: "",
+        duration: None,
+        can_affect: None,
+        on_before_use_move: None,
+        on_try_use_move: None,
+        on_damage: None,
+        on_turn_end: None,
+        on_switch_out: None,
+    };
+
+    match status {
+        SimpleVolatileStatus::Flinch => VolatileStatusEffect {
+            display_name: "volatile_status.flinch",
+            on_before_use_move: Some(|_backend, _target, _mov| ModifiedUsageAttempt::Interrupt),
+            on_turn_end: Some(|backend, target| {
+                backend.remove_volatile_status(target, SimpleVolatileStatus::Flinch);
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::Confusion => VolatileStatusEffect {
+            display_name: "volatile_status.confusion",
+            duration: Some(|rng| rng.get_confusion_duration()),
+            can_affect: Some(|backend, target, _status| {
+                let team = backend.get_pokemon_team(target);
+
+                !backend.has_side_condition(team, SideCondition::Safeguard)
+            }),
+            on_before_use_move: Some(|backend, target, _mov| {
+                let kind = SimpleVolatileStatus::Confusion;
+
+                match backend.get_remaining_turns_mut(target, kind) {
+                    Some(remaining_turns) if *remaining_turns > 0 => *remaining_turns -= 1,
+                    _ => backend.expire_volatile_status(target, kind),
+                }
+
+                ModifiedUsageAttempt::Continue
+            }),
+            on_try_use_move: Some(|backend, target, _mov| {
+                if backend.check_confusion_self_hit() {
+                    backend.inflict_confusion_damage(target);
+                    ModifiedUsageAttempt::Interrupt
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::Charging => VolatileStatusEffect {
+            display_name: "volatile_status.charging",
+            ..default
+        },
+        SimpleVolatileStatus::Recharging => VolatileStatusEffect {
+            display_name: "volatile_status.recharging",
+            ..default
+        },
+        SimpleVolatileStatus::Rampage => VolatileStatusEffect {
+            display_name: "volatile_status.rampage",
+            duration: Some(|rng| rng.get_rampage_duration()),
+            ..default
+        },
+        SimpleVolatileStatus::Binding => VolatileStatusEffect {
+            display_name: "volatile_status.binding",
+            duration: Some(|rng| rng.get_binding_duration()),
+            on_turn_end: Some(|backend, target| {
+                let kind = SimpleVolatileStatus::Binding;
+
+                if backend.count_down_volatile_status(target, kind) {
+                    return;
+                }
+
+                let move_id = match backend.get_volatile_status(target, kind) {
+                    Some(VolatileStatus::Binding { move_id, .. }) => *move_id,
+                    _ => unreachable!(),
+                };
+
+                inflict_residual_damage(backend, target, 1. / 8., DamageCause::Binding {
+                    move_id,
+                });
+            }),
+            on_switch_out: Some(|backend, target, leaving| {
+                expire_if_source_left(backend, target, leaving, SimpleVolatileStatus::Binding);
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::LeechSeed => VolatileStatusEffect {
+            display_name: "volatile_status.leech_seed",
+            can_affect: Some(|backend, target, _status| {
+                !backend.has_type(target, PokemonType::Grass)
+            }),
+            on_turn_end: Some(|backend, target| {
+                let kind = SimpleVolatileStatus::LeechSeed;
+
+                let user = match backend.get_volatile_status(target, kind) {
+                    Some(VolatileStatus::LeechSeed { user }) => *user,
+                    _ => unreachable!(),
+                };
+
+                let dealt_damage =
+                    inflict_residual_damage(backend, target, 1. / 8., DamageCause::LeechSeed);
+                let team = backend.get_pokemon_team(user);
+
+                if let Some(receiver) = backend.get_active_pokemon_index(team) {
+                    backend.heal(receiver, dealt_damage, HealCause::LeechSeed { drained: target });
+                }
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::Disable => VolatileStatusEffect {
+            display_name: "volatile_status.disable",
+            duration: Some(|_rng| 4),
+            on_before_use_move: Some(|backend, target, mov| {
+                match backend.get_volatile_status(target, SimpleVolatileStatus::Disable) {
+                    Some(VolatileStatus::Disable { move_id }) if *move_id == mov.id => {
+                        ModifiedUsageAttempt::Fail
+                    },
+                    _ => ModifiedUsageAttempt::Continue,
+                }
+            }),
+            on_turn_end: Some(|backend, target| {
+                backend.count_down_volatile_status(target, SimpleVolatileStatus::Disable);
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::Encore => VolatileStatusEffect {
+            display_name: "volatile_status.encore",
+            duration: Some(|_rng| 3),
+            on_turn_end: Some(|backend, target| {
+                backend.count_down_volatile_status(target, SimpleVolatileStatus::Encore);
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::Taunt => VolatileStatusEffect {
+            display_name: "volatile_status.taunt",
+            duration: Some(|_rng| 3),
+            on_before_use_move: Some(|_backend, _target, mov| {
+                if mov.category == MoveCategory::Status {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            on_turn_end: Some(|backend, target| {
+                backend.count_down_volatile_status(target, SimpleVolatileStatus::Taunt);
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::Infatuation => VolatileStatusEffect {
+            display_name: "volatile_status.infatuation",
+            can_affect: Some(|backend, target, status| {
+                let user = match status {
+                    VolatileStatus::Infatuation { user } => *user,
+                    _ => unreachable!(),
+                };
+
+                match (&backend.get_pokemon(user).gender, &backend.get_pokemon(target).gender) {
+                    (Gender::Male, Gender::Female) | (Gender::Female, Gender::Male) => true,
+                    _ => false,
+                }
+            }),
+            on_before_use_move: Some(|backend, _target, _mov| {
+                if backend.check_infatuation_move_prevention() {
+                    ModifiedUsageAttempt::Fail
+                } else {
+                    ModifiedUsageAttempt::Continue
+                }
+            }),
+            on_switch_out: Some(|backend, target, leaving| {
+                expire_if_source_left(backend, target, leaving, SimpleVolatileStatus::Infatuation);
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::Substitute => VolatileStatusEffect {
+            display_name: "volatile_status.substitute",
+            on_damage: Some(|backend, target, damage| {
+                backend.damage_substitute(target, damage);
+                None
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::FocusEnergy => VolatileStatusEffect {
+            display_name: "volatile_status.focus_energy",
+            ..default
+        },
+        SimpleVolatileStatus::Trapped => VolatileStatusEffect {
+            display_name: "volatile_status.trapped",
+            on_switch_out: Some(|backend, target, leaving| {
+                expire_if_source_left(backend, target, leaving, SimpleVolatileStatus::Trapped);
+            }),
+            ..default
+        },
+        SimpleVolatileStatus::Curse => VolatileStatusEffect {
+            display_name: "volatile_status.curse",
+            on_turn_end: Some(|backend, target| {
+                inflict_residual_damage(backend, target, 1. / 4., DamageCause::Curse);
+            }),
+            ..default
+        },
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     constants::MOVE_LIMIT,
     pokemon::{
-        movement::MoveDex,
+        movement::{MoveDex, MoveId},
         Gender,
         LearningCondition,
         Nature,
@@ -28,7 +28,7 @@ pub struct PokemonBuilder {
     ability: Option<String>,
     evs: Option<[usize; 6]>,
     natural_ivs: Option<[usize; 6]>,
-    moves: Option<[Option<String>; MOVE_LIMIT]>,
+    moves: Option<[Option<MoveId>; MOVE_LIMIT]>,
     pp: Option<[usize; MOVE_LIMIT]>,
     gender: Option<Gender>,
     // shiny: bool,
@@ -63,7 +63,7 @@ impl PokemonBuilder {
         self
     }
 
-    pub fn with_moves(mut self, moves: [Option<String>; MOVE_LIMIT]) -> Self {
+    pub fn with_moves(mut self, moves: [Option<MoveId>; MOVE_LIMIT]) -> Self {
         self.moves = Some(moves);
         self
     }
@@ -101,7 +101,7 @@ impl PokemonBuilder {
             .unwrap_or_else(|| pick_stats(&species_data.base_stats, &evs, &ivs, nature, level));
 
         Pokemon {
-            species_id: species_data.id.clone(),
+            species_id: species_data.id,
             nature,
             held_item: self.held_item,
             experience_points: 0,
@@ -166,9 +166,9 @@ pub fn pick_ivs() -> [usize; 6] {
 }
 
 pub fn pick_moves(
-    move_table: &Vec<(LearningCondition, String)>,
+    move_table: &Vec<(LearningCondition, MoveId)>,
     level: usize,
-) -> [Option<String>; MOVE_LIMIT] {
+) -> [Option<MoveId>; MOVE_LIMIT] {
     let mut move_list = Vec::with_capacity(MOVE_LIMIT);
 
     move_table
@@ -179,9 +179,9 @@ pub fn pick_moves(
             _ => false,
         })
         .take(MOVE_LIMIT)
-        .for_each(|(_, move_id)| move_list.push(move_id.clone()));
+        .for_each(|(_, move_id)| move_list.push(*move_id));
 
-    let mut result: [Option<String>; MOVE_LIMIT] = Default::default();
+    let mut result: [Option<MoveId>; MOVE_LIMIT] = Default::default();
     for (i, move_id) in move_list.into_iter().rev().enumerate() {
         result[i] = Some(move_id);
     }
@@ -216,19 +216,13 @@ pub fn pick_stats(
     result
 }
 
-pub fn pick_pps(dex: &MoveDex, moves: &[Option<String>; MOVE_LIMIT]) -> [usize; MOVE_LIMIT] {
+pub fn pick_pps(dex: &MoveDex, moves: &[Option<MoveId>; MOVE_LIMIT]) -> [usize; MOVE_LIMIT] {
     let mut result: [usize; MOVE_LIMIT] = Default::default();
 
     moves
         .iter()
         .map(|mov| match mov {
-            Some(mov) => {
-                dex.get_move(mov)
-                    .unwrap_or_else(|| {
-                        panic!("Invalid move \"{}\"", mov);
-                    })
-                    .pp
-            },
+            Some(mov) => dex.get_move(*mov).pp,
             None => 0,
         })
         .enumerate()
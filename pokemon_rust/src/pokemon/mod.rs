@@ -1,21 +1,34 @@
 mod data;
 pub mod generator;
 pub mod movement;
+pub mod showdown;
+pub mod team;
+pub mod validation;
+
+#[cfg(test)]
+mod tests;
 
 use amethyst::ecs::World;
 
 use crate::constants::MOVE_LIMIT;
 
+use serde::{Deserialize, Serialize};
+
 use std::{
     collections::HashMap,
-    fmt::{Debug, Error, Formatter},
+    convert::TryFrom,
+    fmt::{Debug, Display, Error, Formatter},
     time::SystemTime,
 };
 
+use self::movement::MoveId;
+
 pub use self::data::{
-    movement::get_all_moves,
+    movement::{get_all_moves, get_confusion_self_hit},
     pokemon::get_all_pokemon_species,
+    side_conditions::{get_side_condition_effect, SideConditionEffect},
     status_conditions::{get_status_condition_effect, StatusConditionEffect},
+    volatile_statuses::{get_volatile_status_effect, VolatileStatusEffect},
 };
 
 /// Type effectiveness table. Every number is doubled (e.g 0.5x effectiveness
@@ -41,23 +54,80 @@ const TYPE_TABLE: [[u8; 18]; 18] = [
     [2, 4, 2, 1, 2, 2, 2, 2, 1, 1, 2, 2, 2, 2, 2, 4, 4, 2],
 ];
 
+/// A handle to a species of the pokedex, which is its index there. Like
+/// `MoveId`, it's serialized as the name of the species.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SpeciesId(u16);
+
+impl SpeciesId {
+    /// The id of species that aren't part of the pokedex yet. The pokedex
+    /// replaces it with the actual id of each species.
+    pub const UNASSIGNED: SpeciesId = SpeciesId(u16::max_value());
+
+    pub fn name(self) -> &'static str {
+        &get_all_pokemon_species().get_species(self).name
+    }
+}
+
+impl Display for SpeciesId {
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), Error> {
+        write!(formatter, "{}", self.name())
+    }
+}
+
+impl TryFrom<String> for SpeciesId {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<SpeciesId, String> {
+        get_all_pokemon_species()
+            .get_species_id(&name)
+            .ok_or_else(|| format!("Unknown species {}", name))
+    }
+}
+
+impl From<SpeciesId> for String {
+    fn from(id: SpeciesId) -> String {
+        id.name().to_string()
+    }
+}
+
 pub struct PokeDex {
-    data: HashMap<String, PokemonSpeciesData>,
+    data: Vec<PokemonSpeciesData>,
+    ids: HashMap<String, SpeciesId>,
 }
 
 impl PokeDex {
-    pub fn new(data: HashMap<String, PokemonSpeciesData>) -> PokeDex {
-        PokeDex { data }
+    /// Creates a pokedex, assigning ids to the species in order. Panics if
+    /// two species have the same name.
+    pub fn new(mut data: Vec<PokemonSpeciesData>) -> PokeDex {
+        assert!(data.len() < usize::from(u16::max_value()), "Too many species");
+
+        let mut ids = HashMap::new();
+
+        for (index, species) in data.iter_mut().enumerate() {
+            species.id = SpeciesId(index as u16);
+
+            if ids.insert(species.name.clone(), species.id).is_some() {
+                panic!("Duplicate species {}", species.name);
+            }
+        }
+
+        PokeDex { data, ids }
+    }
+
+    pub fn get_species_id(&self, name: &str) -> Option<SpeciesId> {
+        self.ids.get(name).copied()
     }
 
-    pub fn get_species(&self, id: &str) -> Option<&PokemonSpeciesData> {
-        self.data.get(id)
+    pub fn get_species(&self, id: SpeciesId) -> &PokemonSpeciesData {
+        &self.data[usize::from(id.0)]
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Pokemon {
-    pub species_id: String,
+    pub species_id: SpeciesId,
     pub nature: Nature,
     pub held_item: Option<String>,
     pub experience_points: usize,
@@ -65,7 +135,7 @@ pub struct Pokemon {
     pub evs: [usize; 6],
     pub natural_ivs: [usize; 6],
     pub obtained_ivs: [usize; 6],
-    pub moves: [Option<String>; MOVE_LIMIT],
+    pub moves: [Option<MoveId>; MOVE_LIMIT],
     pub pp: [usize; MOVE_LIMIT],
     pub pp_ups: [usize; MOVE_LIMIT],
     pub egg_steps_to_hatch: Option<usize>,
@@ -85,20 +155,12 @@ pub struct Pokemon {
     pub current_hp: usize,
 }
 
-pub fn get_pokemon_display_name<'a>(pokemon: &'a Pokemon, pokedex: &'a PokeDex) -> &'a str {
-    if let Some(name) = &pokemon.nickname {
-        name
-    } else {
-        let species_id = &pokemon.species_id;
-        let species = pokedex.get_species(species_id).unwrap();
-
-        &species.display_name
-    }
-}
-
 #[allow(unused)]
 pub struct PokemonSpeciesData {
-    pub id: String,
+    pub id: SpeciesId,
+    /// The name of the species in data files and translation keys, e.g
+    /// "MrMime". Unlike the id, it doesn't depend on the pokedex.
+    pub name: String,
     pub display_name: String,
     pub national_number: usize,
     pub types: Vec<PokemonType>,
@@ -111,8 +173,8 @@ pub struct PokemonSpeciesData {
     // pub base_friendship: usize,
     pub abilities: Vec<String>,
     pub hidden_abilities: Vec<String>,
-    pub move_table: Vec<(LearningCondition, String)>,
-    pub egg_moves: Vec<String>,
+    pub move_table: Vec<(LearningCondition, MoveId)>,
+    pub egg_moves: Vec<MoveId>,
     pub egg_groups: Vec<String>,
     pub egg_steps: usize,
     pub height: f32,
@@ -125,7 +187,7 @@ pub struct PokemonSpeciesData {
     pub evolution_data: Vec<EvolutionData>,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum Nature {
     Hardy,
     Lonely,
@@ -191,14 +253,14 @@ impl Nature {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum Gender {
     Male,
     Female,
     Genderless,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum StatusCondition {
     Burn,
     Freeze,
@@ -208,7 +270,7 @@ pub enum StatusCondition {
     Sleep { remaining_turns: usize },
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum SimpleStatusCondition {
     Burn,
     Freeze,
@@ -231,7 +293,111 @@ impl From<StatusCondition> for SimpleStatusCondition {
     }
 }
 
-#[derive(Clone, Debug)]
+/// A condition that only lasts while a Pokémon is in battle, unlike a
+/// `StatusCondition`. Pokémon are referred to by their index in the battle.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum VolatileStatus {
+    Flinch,
+    Confusion,
+    /// The Pokémon is charging a two-turn move, which it will use in the
+    /// next turn.
+    Charging { move_id: MoveId, semi_invulnerable: bool },
+    /// The Pokémon can't act in the next turn because it used a move like
+    /// Hyper Beam.
+    Recharging { move_id: MoveId },
+    /// The Pokémon is locked into a move like Thrash.
+    Rampage { move_id: MoveId },
+    /// The Pokémon is trapped by a move like Wrap, used by `user`.
+    Binding { user: usize, move_id: MoveId },
+    /// The Pokémon loses HP every turn, which goes to the active Pokémon of
+    /// the team of `user`.
+    LeechSeed { user: usize },
+    /// The Pokémon can't use the move that it used last.
+    Disable { move_id: MoveId },
+    /// The Pokémon is locked into the move that it used last.
+    Encore { move_id: MoveId },
+    /// The Pokémon can't use status moves.
+    Taunt,
+    /// The Pokémon is in love with `user`, and might not attack because of it.
+    Infatuation { user: usize },
+    /// A decoy that takes the damage of opposing moves until its HP run out.
+    Substitute { hp: usize },
+    /// Every damaging move of the Pokémon is a critical hit.
+    FocusEnergy,
+    /// The Pokémon can't switch out while `user` is on the field.
+    Trapped { user: usize },
+    /// The Pokémon loses 1/4 of its maximum HP every turn.
+    Curse,
+}
+
+/// The kind of a `VolatileStatus`, without its data. A Pokémon can have at
+/// most one volatile status of each kind, and their effects are processed in
+/// the order in which they're declared.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum SimpleVolatileStatus {
+    Flinch,
+    Confusion,
+    Charging,
+    Recharging,
+    Rampage,
+    Binding,
+    LeechSeed,
+    Disable,
+    Encore,
+    Taunt,
+    Infatuation,
+    Substitute,
+    FocusEnergy,
+    Trapped,
+    Curse,
+}
+
+impl From<&VolatileStatus> for SimpleVolatileStatus {
+    fn from(status: &VolatileStatus) -> SimpleVolatileStatus {
+        match status {
+            VolatileStatus::Flinch => SimpleVolatileStatus::Flinch,
+            VolatileStatus::Confusion => SimpleVolatileStatus::Confusion,
+            VolatileStatus::Charging { .. } => SimpleVolatileStatus::Charging,
+            VolatileStatus::Recharging { .. } => SimpleVolatileStatus::Recharging,
+            VolatileStatus::Rampage { .. } => SimpleVolatileStatus::Rampage,
+            VolatileStatus::Binding { .. } => SimpleVolatileStatus::Binding,
+            VolatileStatus::LeechSeed { .. } => SimpleVolatileStatus::LeechSeed,
+            VolatileStatus::Disable { .. } => SimpleVolatileStatus::Disable,
+            VolatileStatus::Encore { .. } => SimpleVolatileStatus::Encore,
+            VolatileStatus::Taunt => SimpleVolatileStatus::Taunt,
+            VolatileStatus::Infatuation { .. } => SimpleVolatileStatus::Infatuation,
+            VolatileStatus::Substitute { .. } => SimpleVolatileStatus::Substitute,
+            VolatileStatus::FocusEnergy => SimpleVolatileStatus::FocusEnergy,
+            VolatileStatus::Trapped { .. } => SimpleVolatileStatus::Trapped,
+            VolatileStatus::Curse => SimpleVolatileStatus::Curse,
+        }
+    }
+}
+
+/// A condition that affects a whole team rather than a single Pokémon, so it
+/// stays in effect when the active Pokémon of the team changes. The effects
+/// of side conditions are processed in the order in which they're declared.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum SideCondition {
+    /// Halves the damage of physical moves against the team.
+    Reflect,
+    /// Halves the damage of special moves against the team.
+    LightScreen,
+    /// Protects the team from status conditions and confusion.
+    Safeguard,
+    /// Prevents the opponents from lowering the stats of the team.
+    Mist,
+    /// Doubles the Speed of the team.
+    Tailwind,
+    /// Hurts the Pokémon that switch in, unless they're Flying types.
+    Spikes,
+    /// Poisons the Pokémon that switch in, unless they're Flying types.
+    ToxicSpikes,
+    /// Hurts the Pokémon that switch in, depending on their weakness to Rock.
+    StealthRock,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum PokerusData {
     Unaffected,
     Cured,
@@ -241,7 +407,7 @@ pub enum PokerusData {
     },
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum PokemonType {
     Normal,
     Fight,
@@ -264,6 +430,28 @@ pub enum PokemonType {
 }
 
 impl PokemonType {
+    /// Every type, in the order of the type table.
+    pub const ALL: [PokemonType; 18] = [
+        PokemonType::Normal,
+        PokemonType::Fight,
+        PokemonType::Flying,
+        PokemonType::Poison,
+        PokemonType::Ground,
+        PokemonType::Rock,
+        PokemonType::Bug,
+        PokemonType::Ghost,
+        PokemonType::Steel,
+        PokemonType::Fire,
+        PokemonType::Water,
+        PokemonType::Grass,
+        PokemonType::Electric,
+        PokemonType::Psychic,
+        PokemonType::Ice,
+        PokemonType::Dragon,
+        PokemonType::Dark,
+        PokemonType::Fairy,
+    ];
+
     /// Returns the type effectiveness between two types.
     pub fn get_effectiveness(attacking_type: PokemonType, defending_type: PokemonType) -> f32 {
         let attacking_type = attacking_type as usize;
@@ -370,7 +558,7 @@ pub enum TimeOfDay {
     Night,
 }
 
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Hash, Eq, PartialEq, Serialize)]
 pub enum Stat {
     HP,
     Attack,
@@ -1,25 +1,93 @@
 use crate::battle::backend::{rng::BattleRng, BattleBackend};
 
-use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
 
-use super::{Pokemon, PokemonType, Stat, StatusCondition};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    fmt::{Display, Error, Formatter},
+};
+
+use super::{get_all_moves, Pokemon, PokemonType, Stat, StatusCondition};
+
+/// A handle to a move of the movedex, which is its index there. Ids are
+/// assigned when the movedex is built, so they're only checked once, and are
+/// serialized as the names of their moves so that they don't depend on the
+/// order of the movedex.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MoveId(u16);
+
+impl MoveId {
+    /// The id of moves that aren't part of the movedex, e.g the confusion
+    /// self-hit. The movedex replaces it with the actual id of each move.
+    pub const UNASSIGNED: MoveId = MoveId(u16::max_value());
+
+    pub fn name(self) -> &'static str {
+        &get_all_moves().get_move(self).name
+    }
+}
+
+impl Display for MoveId {
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), Error> {
+        write!(formatter, "{}", self.name())
+    }
+}
+
+impl TryFrom<String> for MoveId {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<MoveId, String> {
+        get_all_moves()
+            .get_move_id(&name)
+            .ok_or_else(|| format!("Unknown move {}", name))
+    }
+}
+
+impl From<MoveId> for String {
+    fn from(id: MoveId) -> String {
+        id.name().to_string()
+    }
+}
 
 pub struct MoveDex {
-    data: HashMap<String, Move>,
+    data: Vec<Move>,
+    ids: HashMap<String, MoveId>,
 }
 
 impl MoveDex {
-    pub fn new(data: HashMap<String, Move>) -> MoveDex {
-        MoveDex { data }
+    /// Creates a movedex, assigning ids to the moves in order. Panics if two
+    /// moves have the same name.
+    pub fn new(mut data: Vec<Move>) -> MoveDex {
+        assert!(data.len() < usize::from(u16::max_value()), "Too many moves");
+
+        let mut ids = HashMap::new();
+
+        for (index, movement) in data.iter_mut().enumerate() {
+            movement.id = MoveId(index as u16);
+
+            if ids.insert(movement.name.clone(), movement.id).is_some() {
+                panic!("Duplicate move {}", movement.name);
+            }
+        }
+
+        MoveDex { data, ids }
+    }
+
+    pub fn get_move_id(&self, name: &str) -> Option<MoveId> {
+        self.ids.get(name).copied()
     }
 
-    pub fn get_move(&self, id: &str) -> Option<&Move> {
-        self.data.get(id)
+    pub fn get_move(&self, id: MoveId) -> &Move {
+        &self.data[usize::from(id.0)]
     }
 }
 
 pub struct Move {
-    pub id: String,
+    pub id: MoveId,
+    /// The name of the move in data files and translation keys, e.g
+    /// "HyperBeam". Unlike the id, it doesn't depend on the movedex.
+    pub name: String,
     pub display_name: String,
     pub description: String,
     pub move_type: PokemonType,
@@ -36,13 +104,21 @@ pub struct Move {
     pub priority: i8,
     pub target_type: TargetType,
     pub multi_hit: Option<MultiHit>,
+    /// Makes this move lock its user into future turns.
+    pub multi_turn: Option<MultiTurn>,
+    /// Makes the user lose or recover HP proportionally to the damage dealt
+    /// by this move.
+    pub damage_side_effect: Option<DamageSideEffect>,
     /// A "shortcut" for moves with a simple secondary effect
     pub secondary_effect: Option<SecondaryEffect>,
-    /// In this game, there's no RNG in critical hits: a move either always
+    /// By default, there's no RNG in critical hits: a move either always
     /// crits or never crits. Moves that originally mention "high critical
     /// chance" in their description or possess some other kind of critical hit
     /// buff always crit, dealing 125% damage and ignoring both offensive stat
     /// debuffs and defensive stat buffs. Every other move never crits.
+    ///
+    /// Rulesets with chance-based critical hits (see `CriticalHitModel`)
+    /// treat this as a raised critical hit stage instead.
     pub critical_hit: bool,
 }
 
@@ -60,10 +136,28 @@ pub enum MovePower {
     /// Used for moves that have no meaningful value to display as their power,
     /// e.g Fissure.
     Special,
+    /// Used for moves that deal a predetermined amount of damage regardless
+    /// of stats, e.g Seismic Toss. Like `Special`, there's no power to
+    /// display.
+    Fixed(FixedDamage),
+}
+
+pub enum FixedDamage {
+    /// Always deals the same amount of damage, e.g Dragon Rage.
+    Constant(usize),
+    /// Deals damage equal to the level of the user, e.g Night Shade.
+    UserLevel,
+    /// Deals damage equal to half of the current HP of the target, e.g Super
+    /// Fang.
+    HalfTargetHp,
+    /// Deals a random amount of damage between 50% and 150% of the level of
+    /// the user, e.g Psywave.
+    RandomUserLevel,
 }
 
 pub type MoveCallback<T = ()> = fn(user: &Pokemon, target: &Pokemon, movement: &Move) -> T;
 pub type ExtendedMoveCallback<T = ()> = fn(backend: &BattleBackend, user: usize, target: usize, movement: &Move) -> T;
+pub type MutableMoveCallback = fn(backend: &mut BattleBackend, user: usize, target: usize, movement: &Move);
 
 #[derive(Eq, PartialEq)]
 pub enum ModifiedAccuracy {
@@ -76,12 +170,26 @@ pub enum ModifiedAccuracy {
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
 pub enum MoveFlag {
     OneHitKO,
+    /// The move affects its target even if it's behind a substitute, e.g
+    /// Disable.
+    BypassesSubstitute,
+    /// The user is in the air while charging this move, e.g Fly.
+    ChargesInTheAir,
+    /// The user is underground while charging this move, e.g Dig.
+    ChargesUnderground,
+    /// The move can hit targets that are in the air, e.g Gust.
+    HitsTargetsInTheAir,
+    /// The move can hit targets that are underground, e.g Earthquake.
+    HitsTargetsUnderground,
 }
 
 #[derive(Eq, PartialEq)]
 pub enum ModifiedUsageAttempt {
     Fail,
     Continue,
+    /// Stops the move without making it fail, e.g because the user flinched.
+    /// The effect that interrupted the move is responsible for reporting it.
+    Interrupt,
 }
 
 pub enum TargetType {
@@ -119,6 +227,30 @@ pub enum MultiHit {
     Custom(fn(rng: Box<dyn BattleRng>) -> usize),
 }
 
+/// Moves that take more than one turn to complete. While a Pokémon is locked
+/// into one of them, the backend picks its action instead of the frontend.
+pub enum MultiTurn {
+    /// The user spends the first turn charging and attacks in the second one,
+    /// e.g Solar Beam. If `semi_invulnerable` is true, the user can't be hit
+    /// while charging, e.g Fly and Dig.
+    Charge { semi_invulnerable: bool },
+    /// The user can't act in the turn after using this move, e.g Hyper Beam.
+    Recharge,
+    /// The user keeps using this move for 2-3 turns and becomes confused
+    /// afterwards, e.g Thrash.
+    Rampage,
+    /// The target takes 1/8 of its maximum HP as damage at the end of each
+    /// turn, for 4-5 turns, e.g Wrap.
+    Binding,
+}
+
+pub enum DamageSideEffect {
+    /// The user takes this fraction of the damage dealt, e.g Double-Edge.
+    Recoil(f32),
+    /// The user recovers this fraction of the damage dealt, e.g Absorb.
+    Drain(f32),
+}
+
 pub struct SecondaryEffect {
     pub chance: usize,
     pub effect: SimpleEffect,
@@ -132,7 +264,8 @@ pub enum SimpleEffect {
         target: SimpleEffectTarget,
     },
     StatusCondition(StatusCondition),
-    OnHit(MoveCallback),
+    /// Used by moves with custom effects, e.g Leech Seed.
+    OnHit(MutableMoveCallback),
 }
 
 pub enum SimpleEffectTarget {
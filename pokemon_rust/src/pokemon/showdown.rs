@@ -0,0 +1,410 @@
+//! Import and export of teams in the plain-text format used by Pokémon
+//! Showdown. Each Pokémon is a block of lines separated from the others by
+//! blank lines:
+//!
+//! ```text
+//! Punchy (Hitmonchan) (M) @ Leftovers
+//! Ability: Iron Fist
+//! Level: 50
+//! EVs: 252 Atk / 4 SpD / 252 Spe
+//! Adamant Nature
+//! IVs: 0 SpA
+//! - Mach Punch
+//! - Ice Punch
+//! ```
+//!
+//! Names are written with their display names and converted to ids by
+//! removing every character that isn't alphanumeric, e.g "Ice Punch" becomes
+//! "IcePunch". Like in Showdown, omitted levels default to 100, omitted EVs
+//! to 0 and omitted IVs to 31.
+
+use crate::constants::MOVE_LIMIT;
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use super::{
+    generator::PokemonBuilder,
+    get_all_moves,
+    get_all_pokemon_species,
+    movement::MoveId,
+    Gender,
+    Nature,
+    Pokemon,
+};
+
+const DEFAULT_LEVEL: usize = 100;
+const DEFAULT_EV: usize = 0;
+const DEFAULT_IV: usize = 31;
+const STAT_NAMES: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+/// Lines that Showdown may export but that have no equivalent in this game.
+const IGNORED_KEYS: [&str; 6] = [
+    "Shiny",
+    "Happiness",
+    "Hidden Power",
+    "Dynamax Level",
+    "Gigantamax",
+    "Tera Type",
+];
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ShowdownErrorKind {
+    UnknownSpecies(String),
+    UnknownMove(String),
+    UnknownAbility(String),
+    UnknownNature(String),
+    InvalidLevel(String),
+    InvalidStats(String),
+    TooManyMoves,
+    InvalidLine(String),
+}
+
+/// An error found while parsing a team, along with the line where it
+/// happened (starting at 1).
+#[derive(Debug, Eq, PartialEq)]
+pub struct ShowdownError {
+    pub line: usize,
+    pub kind: ShowdownErrorKind,
+}
+
+impl Display for ShowdownError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match &self.kind {
+            ShowdownErrorKind::UnknownSpecies(species) => {
+                write!(f, "unknown species \"{}\"", species)
+            },
+            ShowdownErrorKind::UnknownMove(movement) => write!(f, "unknown move \"{}\"", movement),
+            ShowdownErrorKind::UnknownAbility(ability) => {
+                write!(f, "unknown ability \"{}\"", ability)
+            },
+            ShowdownErrorKind::UnknownNature(nature) => write!(f, "unknown nature \"{}\"", nature),
+            ShowdownErrorKind::InvalidLevel(level) => write!(f, "invalid level \"{}\"", level),
+            ShowdownErrorKind::InvalidStats(stats) => write!(f, "invalid stats \"{}\"", stats),
+            ShowdownErrorKind::TooManyMoves => {
+                write!(f, "a Pokémon can't know more than {} moves", MOVE_LIMIT)
+            },
+            ShowdownErrorKind::InvalidLine(line) => write!(f, "unexpected line \"{}\"", line),
+        }
+    }
+}
+
+/// Converts a display name to an id, e.g "Ice Punch" to "IcePunch".
+fn to_id(name: &str) -> String {
+    name.chars().filter(char::is_ascii_alphanumeric).collect()
+}
+
+/// Converts an id that has no display name available to a readable name, e.g
+/// "IronFist" to "Iron Fist".
+fn from_id(id: &str) -> String {
+    let mut result = String::with_capacity(id.len() + 4);
+
+    for (index, character) in id.chars().enumerate() {
+        if index > 0 && character.is_ascii_uppercase() {
+            result.push(' ');
+        }
+
+        result.push(character);
+    }
+
+    result
+}
+
+fn parse_nature(name: &str) -> Option<Nature> {
+    (0..Nature::count())
+        .filter_map(Nature::by_index)
+        .find(|nature| format!("{:?}", nature).eq_ignore_ascii_case(name))
+}
+
+/// Parses a list of stats such as "252 Atk / 4 SpD / 252 Spe". Stats that
+/// are not mentioned keep their value in `stats`.
+fn parse_stats(text: &str, stats: &mut [usize; 6]) -> Option<()> {
+    for entry in text.split('/') {
+        let mut parts = entry.split_whitespace();
+        let value = parts.next()?.parse().ok()?;
+        let name = parts.next()?;
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let index = STAT_NAMES
+            .iter()
+            .position(|stat_name| stat_name.eq_ignore_ascii_case(name))?;
+
+        stats[index] = value;
+    }
+
+    Some(())
+}
+
+fn write_stats(label: &str, stats: &[usize; 6], default: usize, output: &mut String) {
+    let entries: Vec<String> = stats
+        .iter()
+        .zip(&STAT_NAMES)
+        .filter(|(value, _)| **value != default)
+        .map(|(value, name)| format!("{} {}", value, name))
+        .collect();
+
+    if !entries.is_empty() {
+        writeln!(output, "{}: {}", label, entries.join(" / ")).unwrap();
+    }
+}
+
+/// The fields of a Pokémon that have been read so far from a block.
+struct PokemonEntry {
+    first_line: usize,
+    nickname: Option<String>,
+    species: String,
+    gender: Option<Gender>,
+    held_item: Option<String>,
+    ability: Option<(usize, String)>,
+    level: usize,
+    evs: [usize; 6],
+    ivs: [usize; 6],
+    nature: Option<Nature>,
+    moves: Vec<(usize, String)>,
+}
+
+impl PokemonEntry {
+    /// Parses the first line of a block, which has the format
+    /// "Nickname (Species) (Gender) @ Item", where everything but the
+    /// species is optional.
+    fn from_header(line_number: usize, line: &str) -> PokemonEntry {
+        let (mut name, held_item) = match line.rfind(" @ ") {
+            Some(index) => (&line[..index], Some(to_id(&line[index + 3..]))),
+            None => (line, None),
+        };
+
+        name = name.trim();
+
+        let gender = if name.ends_with("(M)") {
+            Some(Gender::Male)
+        } else if name.ends_with("(F)") {
+            Some(Gender::Female)
+        } else {
+            None
+        };
+
+        if gender.is_some() {
+            name = name[..name.len() - 3].trim_end();
+        }
+
+        let (nickname, species) = match (name.rfind(" ("), name.ends_with(')')) {
+            (Some(index), true) => (
+                Some(name[..index].to_string()),
+                &name[index + 2..name.len() - 1],
+            ),
+            _ => (None, name),
+        };
+
+        PokemonEntry {
+            first_line: line_number,
+            nickname,
+            species: species.to_string(),
+            gender,
+            held_item,
+            ability: None,
+            level: DEFAULT_LEVEL,
+            evs: [DEFAULT_EV; 6],
+            ivs: [DEFAULT_IV; 6],
+            nature: None,
+            moves: Vec::new(),
+        }
+    }
+
+    fn parse_line(&mut self, line_number: usize, line: &str) -> Result<(), ShowdownError> {
+        let error = |kind| ShowdownError {
+            line: line_number,
+            kind,
+        };
+
+        if line.starts_with('-') {
+            let move_name = line[1..].trim();
+
+            if self.moves.len() == MOVE_LIMIT {
+                return Err(error(ShowdownErrorKind::TooManyMoves));
+            }
+
+            self.moves.push((line_number, move_name.to_string()));
+        } else if line.ends_with(" Nature") {
+            let name = line.trim_end_matches(" Nature").trim();
+            let nature = parse_nature(name)
+                .ok_or_else(|| error(ShowdownErrorKind::UnknownNature(name.to_string())))?;
+
+            self.nature = Some(nature);
+        } else if let Some(index) = line.find(':') {
+            let key = line[..index].trim();
+            let value = line[index + 1..].trim();
+
+            match key {
+                "Ability" => self.ability = Some((line_number, value.to_string())),
+                "Level" => {
+                    self.level = value
+                        .parse()
+                        .ok()
+                        .filter(|level| (1..=100).contains(level))
+                        .ok_or_else(|| error(ShowdownErrorKind::InvalidLevel(value.to_string())))?;
+                },
+                "EVs" => parse_stats(value, &mut self.evs)
+                    .ok_or_else(|| error(ShowdownErrorKind::InvalidStats(value.to_string())))?,
+                "IVs" => parse_stats(value, &mut self.ivs)
+                    .ok_or_else(|| error(ShowdownErrorKind::InvalidStats(value.to_string())))?,
+                _ if IGNORED_KEYS.contains(&key) => {},
+                _ => return Err(error(ShowdownErrorKind::InvalidLine(line.to_string()))),
+            }
+        } else {
+            return Err(error(ShowdownErrorKind::InvalidLine(line.to_string())));
+        }
+
+        Ok(())
+    }
+
+    fn build(self) -> Result<Pokemon, ShowdownError> {
+        let pokedex = get_all_pokemon_species();
+        let movedex = get_all_moves();
+
+        let species_id = pokedex.get_species_id(&to_id(&self.species)).ok_or_else(|| {
+            ShowdownError {
+                line: self.first_line,
+                kind: ShowdownErrorKind::UnknownSpecies(self.species.clone()),
+            }
+        })?;
+        let species = pokedex.get_species(species_id);
+
+        let mut builder = PokemonBuilder::default()
+            .with_evs(self.evs)
+            .with_ivs(self.ivs);
+
+        if let Some((line, ability)) = self.ability {
+            let ability_id = to_id(&ability);
+            let is_valid = species
+                .abilities
+                .iter()
+                .chain(&species.hidden_abilities)
+                .any(|species_ability| *species_ability == ability_id);
+
+            if !is_valid {
+                return Err(ShowdownError {
+                    line,
+                    kind: ShowdownErrorKind::UnknownAbility(ability),
+                });
+            }
+
+            builder = builder.with_ability(ability_id);
+        }
+
+        if let Some(nature) = self.nature {
+            builder = builder.with_nature(nature);
+        }
+
+        if let Some(gender) = self.gender {
+            builder = builder.with_gender(gender);
+        }
+
+        if let Some(held_item) = self.held_item {
+            builder = builder.with_held_item(held_item);
+        }
+
+        if !self.moves.is_empty() {
+            let mut moves: [Option<MoveId>; MOVE_LIMIT] = Default::default();
+
+            for (slot, (line, move_name)) in moves.iter_mut().zip(self.moves) {
+                let move_id = movedex.get_move_id(&to_id(&move_name)).ok_or_else(|| {
+                    ShowdownError {
+                        line,
+                        kind: ShowdownErrorKind::UnknownMove(move_name),
+                    }
+                })?;
+
+                *slot = Some(move_id);
+            }
+
+            builder = builder.with_moves(moves);
+        }
+
+        let mut pokemon = builder.build(species, movedex, self.level);
+        pokemon.nickname = self.nickname;
+
+        Ok(pokemon)
+    }
+}
+
+/// Parses a team written in the Showdown format. Fails on the first invalid
+/// line, unknown species, move or ability.
+pub fn parse_team(text: &str) -> Result<Vec<Pokemon>, ShowdownError> {
+    let mut result = Vec::new();
+    let mut current_entry: Option<PokemonEntry> = None;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() {
+            if let Some(entry) = current_entry.take() {
+                result.push(entry.build()?);
+            }
+        } else if let Some(entry) = current_entry.as_mut() {
+            entry.parse_line(line_number, line)?;
+        } else {
+            current_entry = Some(PokemonEntry::from_header(line_number, line));
+        }
+    }
+
+    if let Some(entry) = current_entry {
+        result.push(entry.build()?);
+    }
+
+    Ok(result)
+}
+
+/// Writes a single Pokémon in the Showdown format. Fields that have their
+/// default value are omitted.
+pub fn export_pokemon(pokemon: &Pokemon) -> String {
+    let pokedex = get_all_pokemon_species();
+    let movedex = get_all_moves();
+    let mut result = String::new();
+
+    let species_name = &pokedex.get_species(pokemon.species_id).display_name;
+
+    match &pokemon.nickname {
+        Some(nickname) => write!(result, "{} ({})", nickname, species_name).unwrap(),
+        None => result.push_str(species_name),
+    }
+
+    match pokemon.gender {
+        Gender::Male => result.push_str(" (M)"),
+        Gender::Female => result.push_str(" (F)"),
+        Gender::Genderless => {},
+    }
+
+    if let Some(held_item) = &pokemon.held_item {
+        write!(result, " @ {}", from_id(held_item)).unwrap();
+    }
+
+    result.push('\n');
+    writeln!(result, "Ability: {}", from_id(&pokemon.ability)).unwrap();
+
+    if pokemon.level != DEFAULT_LEVEL {
+        writeln!(result, "Level: {}", pokemon.level).unwrap();
+    }
+
+    write_stats("EVs", &pokemon.evs, DEFAULT_EV, &mut result);
+    writeln!(result, "{:?} Nature", pokemon.nature).unwrap();
+    write_stats("IVs", &pokemon.natural_ivs, DEFAULT_IV, &mut result);
+
+    for move_id in pokemon.moves.iter().flatten() {
+        writeln!(result, "- {}", movedex.get_move(*move_id).display_name).unwrap();
+    }
+
+    result
+}
+
+/// Writes a team in the Showdown format.
+pub fn export_team(team: &[Pokemon]) -> String {
+    team.iter()
+        .map(export_pokemon)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,159 @@
+//! Team files, which describe a list of Pokémon in a compact format that can
+//! be written by hand. Every field that is omitted is picked the same way as
+//! for a wild Pokémon (see `PokemonBuilder`).
+
+use crate::constants::MOVE_LIMIT;
+
+use ron::de::{from_reader, Error as DeserializationError};
+
+use serde::{Deserialize, Serialize};
+
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::File,
+    io::Error as IoError,
+    path::Path,
+};
+
+use super::{
+    generator::PokemonBuilder,
+    get_all_moves,
+    get_all_pokemon_species,
+    movement::MoveId,
+    Nature,
+    Pokemon,
+};
+
+/// Describes a single Pokémon of a team file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PokemonSet {
+    pub species: String,
+    pub level: usize,
+    #[serde(default)]
+    pub nickname: Option<String>,
+    #[serde(default)]
+    pub nature: Option<Nature>,
+    #[serde(default)]
+    pub ability: Option<String>,
+    #[serde(default)]
+    pub held_item: Option<String>,
+    /// The ids of the moves of this Pokémon. If empty, the Pokémon knows the
+    /// last moves it would have learned by leveling up.
+    #[serde(default)]
+    pub moves: Vec<String>,
+    #[serde(default)]
+    pub ivs: Option<[usize; 6]>,
+    #[serde(default)]
+    pub evs: Option<[usize; 6]>,
+}
+
+#[derive(Debug)]
+pub enum TeamError {
+    Io(IoError),
+    Deserialization(DeserializationError),
+    UnknownSpecies(String),
+    UnknownMove(String),
+    TooManyMoves(String),
+    EmptyTeam,
+}
+
+impl From<IoError> for TeamError {
+    fn from(error: IoError) -> TeamError {
+        TeamError::Io(error)
+    }
+}
+
+impl From<DeserializationError> for TeamError {
+    fn from(error: DeserializationError) -> TeamError {
+        TeamError::Deserialization(error)
+    }
+}
+
+impl Display for TeamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TeamError::Io(error) => write!(f, "{}", error),
+            TeamError::Deserialization(error) => write!(f, "{}", error),
+            TeamError::UnknownSpecies(species) => write!(f, "unknown species \"{}\"", species),
+            TeamError::UnknownMove(movement) => write!(f, "unknown move \"{}\"", movement),
+            TeamError::TooManyMoves(species) => {
+                write!(f, "{} knows more than {} moves", species, MOVE_LIMIT)
+            },
+            TeamError::EmptyTeam => write!(f, "the team has no Pokémon"),
+        }
+    }
+}
+
+impl PokemonSet {
+    pub fn build(&self) -> Result<Pokemon, TeamError> {
+        let pokedex = get_all_pokemon_species();
+        let movedex = get_all_moves();
+
+        let species_id = pokedex
+            .get_species_id(&self.species)
+            .ok_or_else(|| TeamError::UnknownSpecies(self.species.clone()))?;
+        let species = pokedex.get_species(species_id);
+
+        let mut builder = PokemonBuilder::default();
+
+        if let Some(nature) = self.nature {
+            builder = builder.with_nature(nature);
+        }
+
+        if let Some(ability) = &self.ability {
+            builder = builder.with_ability(ability.clone());
+        }
+
+        if let Some(held_item) = &self.held_item {
+            builder = builder.with_held_item(held_item.clone());
+        }
+
+        if let Some(ivs) = self.ivs {
+            builder = builder.with_ivs(ivs);
+        }
+
+        if let Some(evs) = self.evs {
+            builder = builder.with_evs(evs);
+        }
+
+        if !self.moves.is_empty() {
+            if self.moves.len() > MOVE_LIMIT {
+                return Err(TeamError::TooManyMoves(self.species.clone()));
+            }
+
+            let mut moves: [Option<MoveId>; MOVE_LIMIT] = Default::default();
+
+            for (slot, name) in moves.iter_mut().zip(&self.moves) {
+                let move_id = movedex
+                    .get_move_id(name)
+                    .ok_or_else(|| TeamError::UnknownMove(name.clone()))?;
+
+                *slot = Some(move_id);
+            }
+
+            builder = builder.with_moves(moves);
+        }
+
+        let mut pokemon = builder.build(species, movedex, self.level);
+        pokemon.nickname = self.nickname.clone();
+
+        Ok(pokemon)
+    }
+}
+
+/// Reads a team file, which contains a list of `PokemonSet`s in RON format.
+pub fn load_team(path: impl AsRef<Path>) -> Result<Vec<PokemonSet>, TeamError> {
+    let file = File::open(path)?;
+    let team: Vec<PokemonSet> = from_reader(file)?;
+
+    if team.is_empty() {
+        return Err(TeamError::EmptyTeam);
+    }
+
+    Ok(team)
+}
+
+/// Builds every Pokémon of a team.
+pub fn build_team(team: &[PokemonSet]) -> Result<Vec<Pokemon>, TeamError> {
+    team.iter().map(PokemonSet::build).collect()
+}
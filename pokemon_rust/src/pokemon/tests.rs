@@ -0,0 +1,286 @@
+use crate::battle::rules::{Clause, Ruleset};
+
+use ron::{de::from_str, ser::to_string};
+
+use super::{
+    generator::PokemonBuilder,
+    get_all_moves,
+    get_all_pokemon_species,
+    movement::MoveId,
+    showdown::{export_team, parse_team, ShowdownError, ShowdownErrorKind},
+    validation::{validate_pokemon, validate_team, validate_team_for_ruleset, LegalityViolation},
+    Gender,
+    Nature,
+    Pokemon,
+    SpeciesId,
+    Stat,
+};
+
+const HITMONCHAN_SET: &str = "\
+Punchy (Hitmonchan) (M) @ Leftovers
+Ability: Iron Fist
+Level: 50
+EVs: 252 Atk / 4 SpD / 252 Spe
+Adamant Nature
+IVs: 0 SpA
+- Mach Punch
+- Ice Punch
+";
+
+fn move_id(name: &str) -> MoveId {
+    get_all_moves().get_move_id(name).unwrap()
+}
+
+fn species_id(name: &str) -> SpeciesId {
+    get_all_pokemon_species().get_species_id(name).unwrap()
+}
+
+fn assert_same_pokemon(first: &Pokemon, second: &Pokemon) {
+    assert_eq!(first.species_id, second.species_id);
+    assert_eq!(first.nickname, second.nickname);
+    assert_eq!(first.gender, second.gender);
+    assert_eq!(first.held_item, second.held_item);
+    assert_eq!(first.ability, second.ability);
+    assert_eq!(first.level, second.level);
+    assert_eq!(first.evs, second.evs);
+    assert_eq!(first.natural_ivs, second.natural_ivs);
+    assert_eq!(first.nature, second.nature);
+    assert_eq!(first.moves, second.moves);
+    assert_eq!(first.stats, second.stats);
+}
+
+#[test]
+fn parses_showdown_teams() {
+    let team = parse_team(HITMONCHAN_SET).unwrap();
+
+    assert_eq!(team.len(), 1);
+
+    let pokemon = &team[0];
+    assert_eq!(pokemon.species_id, species_id("Hitmonchan"));
+    assert_eq!(pokemon.nickname, Some("Punchy".to_string()));
+    assert_eq!(pokemon.gender, Gender::Male);
+    assert_eq!(pokemon.held_item, Some("Leftovers".to_string()));
+    assert_eq!(pokemon.ability, "IronFist");
+    assert_eq!(pokemon.level, 50);
+    assert_eq!(pokemon.evs, [0, 252, 0, 0, 4, 252]);
+    assert_eq!(pokemon.natural_ivs, [31, 31, 31, 0, 31, 31]);
+    assert_eq!(pokemon.nature, Nature::Adamant);
+    assert_eq!(
+        pokemon.moves,
+        [Some(move_id("MachPunch")), Some(move_id("IcePunch")), None, None]
+    );
+}
+
+#[test]
+fn parses_pokemon_with_default_fields() {
+    let team = parse_team("Lapras\nModest Nature\n\n\nJynx (F)\nTimid Nature\n").unwrap();
+
+    assert_eq!(team.len(), 2);
+    assert_eq!(team[0].species_id, species_id("Lapras"));
+    assert_eq!(team[0].nickname, None);
+    assert_eq!(team[0].level, 100);
+    assert_eq!(team[0].evs, [0; 6]);
+    assert_eq!(team[0].natural_ivs, [31; 6]);
+    assert_eq!(team[1].species_id, species_id("Jynx"));
+    assert_eq!(team[1].gender, Gender::Female);
+}
+
+#[test]
+fn reports_errors_with_line_numbers() {
+    let error_kind = |text: &str, line| {
+        let error = parse_team(text).unwrap_err();
+        assert_eq!(error.line, line);
+        error.kind
+    };
+
+    assert_eq!(
+        error_kind("Lapras\n- Ice Beam\n\nMissingno\n", 4),
+        ShowdownErrorKind::UnknownSpecies("Missingno".to_string())
+    );
+    assert_eq!(
+        error_kind(HITMONCHAN_SET.replace("Ice Punch", "Ice Kick").as_str(), 8),
+        ShowdownErrorKind::UnknownMove("Ice Kick".to_string())
+    );
+    assert_eq!(
+        error_kind(HITMONCHAN_SET.replace("Iron Fist", "Levitate").as_str(), 2),
+        ShowdownErrorKind::UnknownAbility("Levitate".to_string())
+    );
+    assert_eq!(
+        error_kind(HITMONCHAN_SET.replace("Adamant", "Grumpy").as_str(), 5),
+        ShowdownErrorKind::UnknownNature("Grumpy".to_string())
+    );
+    assert_eq!(
+        error_kind("Lapras\n- Ice Beam\n- Ice Beam\n- Ice Beam\n- Ice Beam\n- Ice Beam\n", 6),
+        ShowdownErrorKind::TooManyMoves
+    );
+}
+
+#[test]
+fn formats_showdown_errors() {
+    let error = ShowdownError {
+        line: 3,
+        kind: ShowdownErrorKind::UnknownMove("Ice Kick".to_string()),
+    };
+
+    assert_eq!(error.to_string(), "line 3: unknown move \"Ice Kick\"");
+}
+
+#[test]
+fn exports_showdown_teams() {
+    let team = parse_team(HITMONCHAN_SET).unwrap();
+
+    assert_eq!(export_team(&team), HITMONCHAN_SET);
+}
+
+#[test]
+fn round_trips_pokemon_through_the_showdown_format() {
+    let pokedex = get_all_pokemon_species();
+    let movedex = get_all_moves();
+
+    let team: Vec<Pokemon> = ["Lapras", "Jynx", "Hitmonchan", "Metapod"]
+        .iter()
+        .zip(&[5, 24, 77, 100])
+        .map(|(name, level)| {
+            let species = pokedex.get_species(species_id(name));
+            let mut pokemon = PokemonBuilder::default()
+                .with_evs([4, 8, 12, 16, 20, 24])
+                .with_held_item("KingsRock".to_string())
+                .build(species, movedex, *level);
+
+            pokemon.nickname = Some(format!("My {}", name));
+            pokemon
+        })
+        .collect();
+
+    let parsed_team = parse_team(&export_team(&team)).unwrap();
+
+    assert_eq!(parsed_team.len(), team.len());
+
+    for (original, parsed) in team.iter().zip(&parsed_team) {
+        assert_same_pokemon(original, parsed);
+    }
+}
+
+#[test]
+fn accepts_legal_pokemon() {
+    let team = parse_team(HITMONCHAN_SET).unwrap();
+
+    assert_eq!(validate_team(&team), vec![]);
+}
+
+#[test]
+fn rejects_unlearnable_and_duplicate_moves() {
+    let mut pokemon = parse_team(HITMONCHAN_SET).unwrap().remove(0);
+    pokemon.level = 20;
+    pokemon.moves[2] = Some(move_id("IceBeam"));
+    pokemon.moves[3] = Some(move_id("MachPunch"));
+
+    assert_eq!(
+        validate_pokemon(&pokemon),
+        vec![
+            LegalityViolation::UnlearnableMove(move_id("IcePunch")),
+            LegalityViolation::UnlearnableMove(move_id("IceBeam")),
+            LegalityViolation::DuplicateMove(move_id("MachPunch")),
+        ]
+    );
+}
+
+#[test]
+fn rejects_invalid_evs_and_ivs() {
+    let mut pokemon = parse_team(HITMONCHAN_SET).unwrap().remove(0);
+    pokemon.evs = [252, 253, 0, 0, 0, 252];
+    pokemon.natural_ivs[2] = 32;
+
+    assert_eq!(
+        validate_pokemon(&pokemon),
+        vec![
+            LegalityViolation::TooManyEvsInStat {
+                stat: Stat::Attack,
+                evs: 253,
+            },
+            LegalityViolation::TooManyEvs(757),
+            LegalityViolation::InvalidIv {
+                stat: Stat::Defense,
+                iv: 32,
+            },
+        ]
+    );
+}
+
+#[test]
+fn rejects_invalid_levels_abilities_and_genders() {
+    let mut pokemon = parse_team(HITMONCHAN_SET).unwrap().remove(0);
+    pokemon.level = 101;
+    pokemon.ability = "Levitate".to_string();
+    pokemon.gender = Gender::Female;
+
+    assert_eq!(
+        validate_pokemon(&pokemon),
+        vec![
+            LegalityViolation::InvalidLevel(101),
+            LegalityViolation::InvalidAbility("Levitate".to_string()),
+            LegalityViolation::InvalidGender(Gender::Female),
+        ]
+    );
+}
+
+#[test]
+fn reports_the_index_of_illegal_pokemon_in_teams() {
+    let mut team = parse_team("Lapras\n\nJynx (F)\n").unwrap();
+    team[1].gender = Gender::Male;
+
+    assert_eq!(
+        validate_team(&team),
+        vec![(1, LegalityViolation::InvalidGender(Gender::Male))]
+    );
+}
+
+#[test]
+fn enforces_the_clauses_of_rulesets() {
+    let mut team = parse_team("Lapras\n- Ice Beam\n\nLapras\n- Ice Beam\n").unwrap();
+    team[0].moves[0] = Some(move_id("Fissure"));
+
+    assert_eq!(
+        validate_team_for_ruleset(&team, &Ruleset::default()),
+        vec![(0, LegalityViolation::UnlearnableMove(move_id("Fissure")))]
+    );
+
+    let ruleset = Ruleset::standard().with_clause(Clause::LevelCap(50));
+
+    assert_eq!(
+        validate_team_for_ruleset(&team, &ruleset),
+        vec![
+            (0, LegalityViolation::UnlearnableMove(move_id("Fissure"))),
+            (0, LegalityViolation::AboveLevelCap { level: 100, cap: 50 }),
+            (0, LegalityViolation::BannedMove(move_id("Fissure"))),
+            (1, LegalityViolation::AboveLevelCap { level: 100, cap: 50 }),
+            (1, LegalityViolation::DuplicateSpecies(species_id("Lapras"))),
+        ]
+    );
+}
+
+#[test]
+fn looks_up_species_and_moves_by_id() {
+    let pokedex = get_all_pokemon_species();
+    let movedex = get_all_moves();
+
+    assert_eq!(pokedex.get_species(species_id("Lapras")).display_name, "Lapras");
+    assert_eq!(movedex.get_move(move_id("IceBeam")).display_name, "Ice Beam");
+    assert_eq!(move_id("IceBeam").name(), "IceBeam");
+    assert_eq!(pokedex.get_species_id("Missingno"), None);
+    assert_eq!(movedex.get_move_id("IceKick"), None);
+}
+
+#[test]
+fn serializes_ids_as_names() {
+    assert_eq!(to_string(&move_id("IceBeam")).unwrap(), "\"IceBeam\"");
+    assert_eq!(to_string(&species_id("Lapras")).unwrap(), "\"Lapras\"");
+    assert_eq!(from_str::<MoveId>("\"IceBeam\"").unwrap(), move_id("IceBeam"));
+    assert_eq!(from_str::<SpeciesId>("\"Lapras\"").unwrap(), species_id("Lapras"));
+}
+
+#[test]
+fn rejects_unknown_names_when_deserializing() {
+    assert!(from_str::<MoveId>("\"IceKick\"").is_err());
+    assert!(from_str::<SpeciesId>("\"Missingno\"").is_err());
+}
@@ -0,0 +1,236 @@
+//! Checks whether a Pokémon could have been obtained legitimately, according
+//! to the data of its species.
+
+use crate::{
+    battle::rules::{Clause, Ruleset},
+    constants::{MAX_EVS_PER_STAT, MAX_IV, MAX_LEVEL, MAX_TOTAL_EVS},
+};
+
+use std::fmt::{self, Display, Formatter};
+
+use super::{
+    get_all_moves,
+    get_all_pokemon_species,
+    movement::MoveId,
+    Gender,
+    LearningCondition,
+    Pokemon,
+    PokemonSpeciesData,
+    SpeciesId,
+    Stat,
+};
+
+const STATS: [Stat; 6] = [
+    Stat::HP,
+    Stat::Attack,
+    Stat::Defense,
+    Stat::SpecialAttack,
+    Stat::SpecialDefense,
+    Stat::Speed,
+];
+
+/// A reason why a Pokémon is illegal. Its species and moves always exist,
+/// since their ids are checked when the Pokémon is created.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LegalityViolation {
+    InvalidLevel(usize),
+    /// The species can't learn this move at the level of the Pokémon.
+    UnlearnableMove(MoveId),
+    DuplicateMove(MoveId),
+    TooManyEvsInStat { stat: Stat, evs: usize },
+    TooManyEvs(usize),
+    InvalidIv { stat: Stat, iv: usize },
+    InvalidAbility(String),
+    InvalidGender(Gender),
+    /// The level is above the level cap of the ruleset.
+    AboveLevelCap { level: usize, cap: usize },
+    /// A clause of the ruleset bans this move.
+    BannedMove(MoveId),
+    /// Another Pokémon of the team has the same species, which the Species
+    /// Clause forbids.
+    DuplicateSpecies(SpeciesId),
+}
+
+impl Display for LegalityViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LegalityViolation::InvalidLevel(level) => {
+                write!(f, "level {} is not between 1 and {}", level, MAX_LEVEL)
+            },
+            LegalityViolation::UnlearnableMove(move_id) => {
+                write!(f, "the species can't learn \"{}\"", move_id)
+            },
+            LegalityViolation::DuplicateMove(move_id) => {
+                write!(f, "\"{}\" is known more than once", move_id)
+            },
+            LegalityViolation::TooManyEvsInStat { stat, evs } => write!(
+                f,
+                "{} EVs in {:?}, but the limit is {}",
+                evs, stat, MAX_EVS_PER_STAT
+            ),
+            LegalityViolation::TooManyEvs(evs) => {
+                write!(f, "{} EVs in total, but the limit is {}", evs, MAX_TOTAL_EVS)
+            },
+            LegalityViolation::InvalidIv { stat, iv } => {
+                write!(f, "{} IVs in {:?}, but the limit is {}", iv, stat, MAX_IV)
+            },
+            LegalityViolation::InvalidAbility(ability) => {
+                write!(f, "the species can't have the ability \"{}\"", ability)
+            },
+            LegalityViolation::InvalidGender(gender) => {
+                write!(f, "the species can't have the gender {:?}", gender)
+            },
+            LegalityViolation::AboveLevelCap { level, cap } => {
+                write!(f, "level {} is above the level cap of {}", level, cap)
+            },
+            LegalityViolation::BannedMove(move_id) => {
+                write!(f, "\"{}\" is banned by the ruleset", move_id)
+            },
+            LegalityViolation::DuplicateSpecies(species) => {
+                write!(f, "the team already has a {}", species)
+            },
+        }
+    }
+}
+
+fn can_learn_move(species: &PokemonSpeciesData, move_id: MoveId, level: usize) -> bool {
+    let in_move_table = species
+        .move_table
+        .iter()
+        .any(|(condition, learnable_move)| {
+            *learnable_move == move_id
+                && match condition {
+                    LearningCondition::Level(required_level) => *required_level <= level,
+                    LearningCondition::Evolution => true,
+                }
+        });
+
+    in_move_table || species.egg_moves.contains(&move_id)
+}
+
+fn is_valid_gender(male_ratio: Option<f32>, gender: &Gender) -> bool {
+    match (male_ratio, gender) {
+        (None, Gender::Genderless) => true,
+        (None, _) | (Some(_), Gender::Genderless) => false,
+        (Some(ratio), Gender::Male) => ratio > 0.,
+        (Some(ratio), Gender::Female) => ratio < 100.,
+    }
+}
+
+/// Returns every reason why a Pokémon is illegal. The Pokémon is legal if the
+/// result is empty.
+pub fn validate_pokemon(pokemon: &Pokemon) -> Vec<LegalityViolation> {
+    let mut result = Vec::new();
+
+    let species = get_all_pokemon_species().get_species(pokemon.species_id);
+
+    if pokemon.level == 0 || pokemon.level > MAX_LEVEL {
+        result.push(LegalityViolation::InvalidLevel(pokemon.level));
+    }
+
+    let known_moves: Vec<MoveId> = pokemon.moves.iter().flatten().copied().collect();
+
+    for (index, move_id) in known_moves.iter().enumerate() {
+        if !can_learn_move(species, *move_id, pokemon.level) {
+            result.push(LegalityViolation::UnlearnableMove(*move_id));
+        }
+
+        if known_moves[..index].contains(move_id) {
+            result.push(LegalityViolation::DuplicateMove(*move_id));
+        }
+    }
+
+    for (stat, evs) in STATS.iter().zip(&pokemon.evs) {
+        if *evs > MAX_EVS_PER_STAT {
+            result.push(LegalityViolation::TooManyEvsInStat {
+                stat: *stat,
+                evs: *evs,
+            });
+        }
+    }
+
+    let total_evs = pokemon.evs.iter().sum();
+
+    if total_evs > MAX_TOTAL_EVS {
+        result.push(LegalityViolation::TooManyEvs(total_evs));
+    }
+
+    for (stat, iv) in STATS.iter().zip(&pokemon.natural_ivs) {
+        if *iv > MAX_IV {
+            result.push(LegalityViolation::InvalidIv {
+                stat: *stat,
+                iv: *iv,
+            });
+        }
+    }
+
+    let has_valid_ability = species
+        .abilities
+        .iter()
+        .chain(&species.hidden_abilities)
+        .any(|ability| *ability == pokemon.ability);
+
+    if !has_valid_ability {
+        result.push(LegalityViolation::InvalidAbility(pokemon.ability.clone()));
+    }
+
+    if !is_valid_gender(species.male_ratio, &pokemon.gender) {
+        result.push(LegalityViolation::InvalidGender(pokemon.gender.clone()));
+    }
+
+    result
+}
+
+/// Validates every Pokémon of a team. Each violation comes with the index of
+/// the Pokémon it refers to.
+pub fn validate_team<'a>(
+    team: impl IntoIterator<Item = &'a Pokemon>,
+) -> Vec<(usize, LegalityViolation)> {
+    team.into_iter()
+        .enumerate()
+        .flat_map(|(index, pokemon)| {
+            validate_pokemon(pokemon)
+                .into_iter()
+                .map(move |violation| (index, violation))
+        })
+        .collect()
+}
+
+/// Validates every Pokémon of a team like `validate_team`, additionally
+/// enforcing the clauses of a ruleset.
+pub fn validate_team_for_ruleset<'a>(
+    team: impl IntoIterator<Item = &'a Pokemon>,
+    ruleset: &Ruleset,
+) -> Vec<(usize, LegalityViolation)> {
+    let team: Vec<&Pokemon> = team.into_iter().collect();
+    let mut result = validate_team(team.iter().copied());
+    let movedex = get_all_moves();
+
+    for (index, pokemon) in team.iter().enumerate() {
+        if let Some(cap) = ruleset.get_level_cap() {
+            if pokemon.level > cap {
+                result.push((index, LegalityViolation::AboveLevelCap {
+                    level: pokemon.level,
+                    cap,
+                }));
+            }
+        }
+
+        for move_id in pokemon.moves.iter().flatten() {
+            if !ruleset.allows_move(movedex.get_move(*move_id)) {
+                result.push((index, LegalityViolation::BannedMove(*move_id)));
+            }
+        }
+
+        let is_duplicate_species = team[..index]
+            .iter()
+            .any(|other| other.species_id == pokemon.species_id);
+
+        if ruleset.has_clause(&Clause::Species) && is_duplicate_species {
+            result.push((index, LegalityViolation::DuplicateSpecies(pokemon.species_id)));
+        }
+    }
+
+    result.sort_by_key(|(index, _)| *index);
+    result
+}
@@ -0,0 +1,129 @@
+//! A system responsible for animating a choice box on the screen.
+
+use amethyst::{
+    core::Time,
+    ecs::{Entities, Join, Read, ReadExpect, ReaderId, System, World, WorldExt, WriteStorage},
+    input::{InputEvent, StringBindings},
+    shrev::EventChannel,
+    ui::{UiImage, UiText, UiTransform},
+};
+
+use crate::{
+    audio::{Sound, SoundKit},
+    common::CommonResources,
+    config::GameConfig,
+    constants::AXIS_SENSITIVITY,
+};
+
+use super::{
+    advance_text,
+    is_text_box_finished,
+    move_choice_selection,
+    show_choice_options,
+    ChoiceBox,
+};
+
+/// A system responsible for animating a choice box on the screen. Once the
+/// player picks an option, the choice box stays in the world with its
+/// `selected_option` filled, and whoever created it is responsible for
+/// deleting it.
+pub struct ChoiceSystem {
+    event_reader: ReaderId<InputEvent<StringBindings>>,
+}
+
+impl ChoiceSystem {
+    pub fn new(world: &mut World) -> ChoiceSystem {
+        ChoiceSystem {
+            event_reader: world
+                .write_resource::<EventChannel<InputEvent<StringBindings>>>()
+                .register_reader(),
+        }
+    }
+}
+
+impl<'a> System<'a> for ChoiceSystem {
+    type SystemData = (
+        WriteStorage<'a, ChoiceBox>,
+        WriteStorage<'a, UiImage>,
+        WriteStorage<'a, UiText>,
+        WriteStorage<'a, UiTransform>,
+        Entities<'a>,
+        ReadExpect<'a, CommonResources>,
+        ReadExpect<'a, GameConfig>,
+        Read<'a, Time>,
+        Read<'a, EventChannel<InputEvent<StringBindings>>>,
+        SoundKit<'a>,
+    );
+
+    fn run(
+        &mut self,
+        (
+        mut choice_boxes,
+        mut ui_images,
+        mut ui_texts,
+        mut ui_transforms,
+        entities,
+        resources,
+        game_config,
+        time,
+        input_event_channel,
+        sound_kit,
+    ): Self::SystemData,
+    ) {
+        let mut pressed_action_key = false;
+        let mut offset = 0;
+
+        for event in input_event_channel.read(&mut self.event_reader) {
+            match event {
+                InputEvent::ActionPressed(action) if action == "action" => {
+                    pressed_action_key = true;
+                },
+                InputEvent::AxisMoved { axis, value } if axis == "vertical" => {
+                    if *value < -AXIS_SENSITIVITY {
+                        offset = 1;
+                    } else if *value > AXIS_SENSITIVITY {
+                        offset = -1;
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        for choice_box in (&mut choice_boxes).join() {
+            if choice_box.selected_option.is_some() {
+                continue;
+            }
+
+            if choice_box.option_entities.is_some() {
+                if pressed_action_key {
+                    sound_kit.play_sound(Sound::SelectOption);
+                    choice_box.selected_option = Some(choice_box.focused_option.into());
+                } else if offset != 0 {
+                    sound_kit.play_sound(Sound::SelectOption);
+                    move_choice_selection(choice_box, offset, &mut ui_transforms);
+                }
+            } else if is_text_box_finished(&choice_box.text_box) {
+                show_choice_options(
+                    choice_box,
+                    &mut ui_images,
+                    &mut ui_texts,
+                    &mut ui_transforms,
+                    &entities,
+                    &resources,
+                );
+            } else {
+                if pressed_action_key {
+                    sound_kit.play_sound(Sound::SelectOption);
+                }
+
+                advance_text(
+                    pressed_action_key,
+                    &mut choice_box.text_box,
+                    &game_config,
+                    &time,
+                    &mut ui_texts,
+                );
+            }
+        }
+    }
+}
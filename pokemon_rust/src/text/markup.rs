@@ -0,0 +1,404 @@
+//! A small markup language for text boxes. Supported syntax:
+//!
+//! * `{PLAYER}` and `{RIVAL}`: the names of the player and of the rival,
+//!   stored in the `PLAYER_NAME` and `RIVAL_NAME` string variables;
+//! * `{VAR:NAME}` and `{STR:NAME}`: the value of an integer/string variable;
+//! * `{COLOR:RED}...{/COLOR}`: changes the color of a span of text. Accepts
+//!   the names in `get_named_color` or a hex code like `{COLOR:#FF8000}`;
+//! * `{SPEED:2}...{/SPEED}`: multiplies the text speed of a span of text;
+//! * `{PAUSE:0.5}`: waits for a number of seconds before going on;
+//! * `\n` and `\p`: forces a line break and a page break, respectively;
+//! * `\{` and `\\`: a literal brace and a literal backslash.
+//!
+//! Tags that can't be recognized are displayed as plain text.
+
+use crate::game_variables::GameVariables;
+
+use std::ops::Range;
+
+/// An RGBA color.
+pub type TextColor = [f32; 4];
+
+pub const DEFAULT_TEXT_COLOR: TextColor = [1., 1., 1., 1.];
+const DEFAULT_PLAYER_NAME: &str = "RED";
+const DEFAULT_RIVAL_NAME: &str = "BLUE";
+
+/// A single element of a parsed text.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarkupToken {
+    Character {
+        character: char,
+        color: TextColor,
+        speed: f32,
+    },
+    Pause(f32),
+    LineBreak,
+    PageBreak,
+}
+
+/// A character that has been placed in a page.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextUnit {
+    pub character: char,
+    /// How long to wait before displaying this character, in seconds, on top
+    /// of the regular text delay.
+    pub pause: f32,
+    /// A multiplier for the text speed of this character.
+    pub speed: f32,
+}
+
+/// A sequence of characters of a page that share the same line and color,
+/// and can therefore be rendered by a single `UiText`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextRun {
+    pub line: usize,
+    /// The horizontal offset of the first character of this run, relative to
+    /// the beginning of the line.
+    pub x: f32,
+    pub color: TextColor,
+    /// The indices of the `TextUnit`s of this run.
+    pub units: Range<usize>,
+}
+
+/// The contents of a single page of a text box.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextPage {
+    pub units: Vec<TextUnit>,
+    pub runs: Vec<TextRun>,
+}
+
+impl TextPage {
+    /// Returns the text of a run, limited to the first `displayed_units`
+    /// characters of this page.
+    pub fn get_run_text(&self, run: &TextRun, displayed_units: usize) -> String {
+        let end = run.units.end.min(displayed_units).max(run.units.start);
+
+        self.units[run.units.start..end]
+            .iter()
+            .map(|unit| unit.character)
+            .collect()
+    }
+}
+
+/// Parses a marked up text, replacing every variable by its value.
+///
+/// # Examples
+///
+/// ```
+/// use pokemon_rust::{
+///     game_variables::GameVariables,
+///     text::markup::{parse_markup, MarkupToken},
+/// };
+///
+/// let mut variables = GameVariables::default();
+/// variables.set_string("PLAYER_NAME", "ASH");
+///
+/// let tokens = parse_markup("Hi {PLAYER}!\\p", &variables);
+/// let characters: String = tokens
+///     .iter()
+///     .filter_map(|token| match token {
+///         MarkupToken::Character { character, .. } => Some(*character),
+///         _ => None,
+///     })
+///     .collect();
+///
+/// assert_eq!(characters, "Hi ASH!");
+/// assert_eq!(tokens.last(), Some(&MarkupToken::PageBreak));
+/// ```
+pub fn parse_markup(text: &str, variables: &GameVariables) -> Vec<MarkupToken> {
+    let mut parser = MarkupParser {
+        tokens: Vec::new(),
+        colors: vec![DEFAULT_TEXT_COLOR],
+        speeds: vec![1.],
+    };
+    let mut chars = text.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        match character {
+            '\\' => match chars.peek() {
+                Some('n') => {
+                    chars.next();
+                    parser.tokens.push(MarkupToken::LineBreak);
+                },
+                Some('p') => {
+                    chars.next();
+                    parser.tokens.push(MarkupToken::PageBreak);
+                },
+                Some(&escaped) if escaped == '{' || escaped == '\\' => {
+                    chars.next();
+                    parser.push_text(&escaped.to_string());
+                },
+                _ => parser.push_text("\\"),
+            },
+            '\n' => parser.tokens.push(MarkupToken::LineBreak),
+            '{' => {
+                let tag: String = chars.clone().take_while(|c| *c != '}').collect();
+                let is_closed = chars.clone().nth(tag.chars().count()) == Some('}');
+
+                if is_closed && parser.apply_tag(&tag, variables) {
+                    chars.nth(tag.chars().count());
+                } else {
+                    parser.push_text("{");
+                }
+            },
+            _ => parser.push_text(&character.to_string()),
+        }
+    }
+
+    parser.tokens
+}
+
+struct MarkupParser {
+    tokens: Vec<MarkupToken>,
+    colors: Vec<TextColor>,
+    speeds: Vec<f32>,
+}
+
+impl MarkupParser {
+    fn push_text(&mut self, text: &str) {
+        let color = *self.colors.last().unwrap();
+        let speed = *self.speeds.last().unwrap();
+
+        self.tokens
+            .extend(text.chars().map(|character| MarkupToken::Character {
+                character,
+                color,
+                speed,
+            }));
+    }
+
+    /// Applies the effects of a tag. Returns false if it isn't a valid tag.
+    fn apply_tag(&mut self, tag: &str, variables: &GameVariables) -> bool {
+        let (name, argument) = match tag.find(':') {
+            Some(index) => (&tag[..index], Some(&tag[index + 1..])),
+            None => (tag, None),
+        };
+
+        match (name, argument) {
+            ("PLAYER", None) => {
+                let player_name = variables.get_string("PLAYER_NAME");
+                self.push_text(player_name.unwrap_or(DEFAULT_PLAYER_NAME));
+            },
+            ("RIVAL", None) => {
+                let rival_name = variables.get_string("RIVAL_NAME");
+                self.push_text(rival_name.unwrap_or(DEFAULT_RIVAL_NAME));
+            },
+            ("VAR", Some(variable)) => self.push_text(&variables.get_var(variable).to_string()),
+            ("STR", Some(variable)) => self.push_text(variables.get_string(variable).unwrap_or("")),
+            ("COLOR", Some(color)) => match parse_color(color) {
+                Some(color) => self.colors.push(color),
+                None => return false,
+            },
+            ("SPEED", Some(speed)) => match speed.parse::<f32>() {
+                Ok(speed) if speed > 0. => self.speeds.push(speed),
+                _ => return false,
+            },
+            ("PAUSE", Some(duration)) => match duration.parse::<f32>() {
+                Ok(duration) if duration >= 0. => self.tokens.push(MarkupToken::Pause(duration)),
+                _ => return false,
+            },
+            ("/COLOR", None) if self.colors.len() > 1 => {
+                self.colors.pop();
+            },
+            ("/SPEED", None) if self.speeds.len() > 1 => {
+                self.speeds.pop();
+            },
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+/// Returns the color corresponding to a color name used in markup.
+pub fn get_named_color(name: &str) -> Option<TextColor> {
+    let color = match name {
+        "WHITE" => DEFAULT_TEXT_COLOR,
+        "BLACK" => [0., 0., 0., 1.],
+        "GRAY" => [0.6, 0.6, 0.6, 1.],
+        "RED" => [0.94, 0.25, 0.25, 1.],
+        "GREEN" => [0.35, 0.85, 0.35, 1.],
+        "BLUE" => [0.35, 0.55, 1., 1.],
+        "YELLOW" => [1., 0.85, 0.2, 1.],
+        _ => return None,
+    };
+
+    Some(color)
+}
+
+fn parse_color(color: &str) -> Option<TextColor> {
+    if !color.starts_with('#') {
+        return get_named_color(color);
+    }
+
+    let hex = &color[1..];
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let component = |index: usize| {
+        f32::from(u8::from_str_radix(&hex[index..index + 2], 16).unwrap()) / 255.
+    };
+
+    Some([component(0), component(2), component(4), 1.])
+}
+
+/// Splits parsed text in pages, wrapping lines at word boundaries whenever
+/// possible. Words that don't fit in a single line are split.
+///
+/// `char_width` must return the width of a character in the same unit as
+/// `max_line_width`.
+pub fn paginate(
+    tokens: &[MarkupToken],
+    max_line_width: f32,
+    max_lines: usize,
+    char_width: impl Fn(char) -> f32,
+) -> Vec<TextPage> {
+    let mut paginator = Paginator {
+        pages: Vec::new(),
+        page: TextPage::default(),
+        line: 0,
+        x: 0.,
+        pending_pause: 0.,
+        max_line_width,
+        max_lines,
+    };
+    let mut index = 0;
+
+    while index < tokens.len() {
+        match &tokens[index] {
+            MarkupToken::Character { character, .. } if character.is_whitespace() => {
+                if paginator.x > 0. {
+                    let width = char_width(*character);
+
+                    if paginator.x + width > max_line_width {
+                        paginator.break_line();
+                    } else {
+                        paginator.place(&tokens[index], width);
+                    }
+                }
+
+                index += 1;
+            },
+            MarkupToken::Character { .. } => {
+                // Pauses don't end a word, so that they can't cause a line
+                // break in the middle of it.
+                let word_end = tokens[index..]
+                    .iter()
+                    .position(|token| match token {
+                        MarkupToken::Character { character, .. } => character.is_whitespace(),
+                        MarkupToken::Pause(_) => false,
+                        _ => true,
+                    })
+                    .map_or(tokens.len(), |offset| index + offset);
+
+                let word = &tokens[index..word_end];
+                let word_width: f32 = word.iter().map(|token| get_width(token, &char_width)).sum();
+
+                if paginator.x > 0. && paginator.x + word_width > max_line_width {
+                    paginator.break_line();
+                }
+
+                for token in word {
+                    if let MarkupToken::Pause(duration) = token {
+                        paginator.pending_pause += duration;
+                        continue;
+                    }
+
+                    let width = get_width(token, &char_width);
+
+                    if paginator.x > 0. && paginator.x + width > max_line_width {
+                        paginator.break_line();
+                    }
+
+                    paginator.place(token, width);
+                }
+
+                index = word_end;
+            },
+            MarkupToken::Pause(duration) => {
+                paginator.pending_pause += duration;
+                index += 1;
+            },
+            MarkupToken::LineBreak => {
+                paginator.break_line();
+                index += 1;
+            },
+            MarkupToken::PageBreak => {
+                paginator.break_page();
+                index += 1;
+            },
+        }
+    }
+
+    if !paginator.page.units.is_empty() || paginator.pages.is_empty() {
+        paginator.break_page();
+    }
+
+    paginator.pages
+}
+
+fn get_width(token: &MarkupToken, char_width: &impl Fn(char) -> f32) -> f32 {
+    match token {
+        MarkupToken::Character { character, .. } => char_width(*character),
+        _ => 0.,
+    }
+}
+
+struct Paginator {
+    pages: Vec<TextPage>,
+    page: TextPage,
+    line: usize,
+    x: f32,
+    pending_pause: f32,
+    max_line_width: f32,
+    max_lines: usize,
+}
+
+impl Paginator {
+    fn place(&mut self, token: &MarkupToken, width: f32) {
+        if let MarkupToken::Character {
+            character,
+            color,
+            speed,
+        } = token
+        {
+            let unit_index = self.page.units.len();
+            self.page.units.push(TextUnit {
+                character: *character,
+                pause: self.pending_pause,
+                speed: *speed,
+            });
+            self.pending_pause = 0.;
+
+            match self.page.runs.last_mut() {
+                Some(run) if run.line == self.line && run.color == *color => {
+                    run.units.end = unit_index + 1;
+                },
+                _ => self.page.runs.push(TextRun {
+                    line: self.line,
+                    x: self.x,
+                    color: *color,
+                    units: unit_index..(unit_index + 1),
+                }),
+            }
+
+            self.x = (self.x + width).min(self.max_line_width);
+        }
+    }
+
+    fn break_line(&mut self) {
+        if self.line + 1 >= self.max_lines {
+            self.break_page();
+        } else {
+            self.line += 1;
+            self.x = 0.;
+        }
+    }
+
+    fn break_page(&mut self) {
+        self.pages.push(std::mem::take(&mut self.page));
+        self.line = 0;
+        self.x = 0.;
+    }
+}
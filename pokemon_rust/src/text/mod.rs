@@ -1,29 +1,67 @@
-//! A TextBox component used for rendering text.
+//! A TextBox component used for rendering text, and a ChoiceBox component
+//! used for asking the player to pick one of several options.
 
+pub mod markup;
+
+mod choice_system;
 mod text_system;
 
+#[cfg(test)]
+mod tests;
+
 use amethyst::{
+    assets::AssetStorage,
     core::Time,
-    ecs::{Component, DenseVecStorage, Entities, Entity, WriteStorage},
+    ecs::{
+        Component,
+        DenseVecStorage,
+        Entities,
+        Entity,
+        Read,
+        ReadExpect,
+        SystemData,
+        World,
+        WriteStorage,
+    },
     renderer::SpriteRender,
-    ui::{Anchor, LineMode, UiImage, UiText, UiTransform},
+    shred::ResourceId,
+    ui::{Anchor, FontAsset, LineMode, UiImage, UiText, UiTransform},
 };
 
-use crate::{common::CommonResources, config::GameConfig};
+use crate::{
+    common::{cycle_option, CommonResources},
+    config::GameConfig,
+    game_variables::GameVariables,
+};
+
+use glyph_brush::rusttype::Scale;
+
+use self::markup::{paginate, parse_markup, TextPage, TextRun};
 
-pub use self::text_system::TextSystem;
+pub use self::{choice_system::ChoiceSystem, text_system::TextSystem};
 
-/// A component used for rendering text.
+const TEXT_FONT_SIZE: f32 = 30.;
+const TEXT_LINE_HEIGHT: f32 = 33.;
+const TEXT_MAX_LINES: usize = 3;
+const TEXT_WIDTH: f32 = 640.;
+
+const CHOICE_BOX_MARGIN: f32 = 10.;
+const CHOICE_BOX_WIDTH: f32 = 200.;
+const CHOICE_OPTION_HEIGHT: f32 = 40.;
+const CHOICE_ARROW_WIDTH: f32 = 24.;
+const CHOICE_ARROW_HEIGHT: f32 = 28.;
+
+/// A component used for rendering text. The text may contain markup (see
+/// the [markup](markup/index.html) module).
 pub struct TextBox {
-    /// The entire text that should be displayed. Doesn't necessarily fit in a
-    /// single page.
-    pub full_text: String,
-    /// The index of the first character to be displayed in the current page.
-    pub displayed_text_start: usize,
-    /// The index **after** the last character to be displayed in the current
-    /// page. This varies over time (see [TextSystem](../../systems/text_system/struct.TextSystem.html))
+    /// The entire text that should be displayed, already split in pages.
+    pub pages: Vec<TextPage>,
+    /// The index of the page currently being displayed.
+    pub current_page: usize,
+    /// The number of characters of the current page that are being
+    /// displayed. This varies over time (see [TextSystem](../../systems/text_system/struct.TextSystem.html))
     /// to make the text appear progressively.
-    pub displayed_text_end: usize,
+    pub displayed_units: usize,
     /// Whether or not this text box is waiting for a keypress to go on to the
     /// next page (or close if this is the last page).
     pub awaiting_keypress: bool,
@@ -33,14 +71,50 @@ pub struct TextBox {
     pub cooldown: f32,
     /// The entity corresponding to the box that this text appears in.
     pub box_entity: Entity,
-    /// The entity corresponding to the text that displays this struct.
-    pub text_entity: Entity,
+    /// The entities displaying the text of each page, one per `TextRun`.
+    pub text_entities: Vec<Vec<Entity>>,
+}
+
+/// The resources needed to lay out the text of a text box.
+#[derive(SystemData)]
+pub struct TextLayoutData<'a> {
+    fonts: Read<'a, AssetStorage<FontAsset>>,
+    game_variables: ReadExpect<'a, GameVariables>,
 }
 
 impl Component for TextBox {
     type Storage = DenseVecStorage<Self>;
 }
 
+/// A component used for rendering a text box followed by a list of options.
+/// The options only appear once the entire text has been displayed.
+pub struct ChoiceBox {
+    /// The text box displaying the prompt. It is not registered as a separate
+    /// component, so the [TextSystem](../../systems/text_system/struct.TextSystem.html)
+    /// never closes it on its own.
+    pub text_box: TextBox,
+    /// The options that the player can choose from.
+    pub options: Vec<String>,
+    /// The index of the option currently pointed at by the selection arrow.
+    pub focused_option: u8,
+    /// The index of the chosen option, if the player has already made a choice.
+    pub selected_option: Option<usize>,
+    /// The entities used to display the options. Only present once the
+    /// options are visible.
+    pub option_entities: Option<ChoiceOptionEntities>,
+}
+
+impl Component for ChoiceBox {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// The entities that make up the option list of a `ChoiceBox`.
+pub struct ChoiceOptionEntities {
+    pub box_entity: Entity,
+    pub selection_arrow_entity: Entity,
+    pub text_entities: Vec<Entity>,
+}
+
 /// Represents the possible states that a text box can be in.
 #[derive(Debug, Eq, PartialEq)]
 pub enum TextState {
@@ -55,15 +129,29 @@ pub fn create_text_box(
     ui_transforms: &mut WriteStorage<UiTransform>,
     entities: &Entities,
     resources: &CommonResources,
+    layout_data: &TextLayoutData,
 ) -> TextBox {
+    let pages = layout_text(&full_text, resources, layout_data);
+    let text_entities = pages
+        .iter()
+        .map(|page| {
+            page.runs
+                .iter()
+                .map(|run| {
+                    initialise_text_entity(run, &entities, ui_texts, ui_transforms, &resources)
+                })
+                .collect()
+        })
+        .collect();
+
     TextBox {
-        full_text,
-        displayed_text_start: 0,
-        displayed_text_end: 0,
+        pages,
+        current_page: 0,
+        displayed_units: 0,
         awaiting_keypress: false,
         cooldown: 0.,
         box_entity: initialise_box_entity(&entities, ui_images, ui_transforms, &resources),
-        text_entity: initialise_text_entity(&entities, ui_texts, ui_transforms, &resources),
+        text_entities,
     }
 }
 
@@ -71,12 +159,175 @@ pub fn delete_text_box(entity: Entity, text_box: &mut TextBox, entities: &Entiti
     entities
         .delete(text_box.box_entity)
         .expect("Failed to delete box");
-    entities
-        .delete(text_box.text_entity)
-        .expect("Failed to delete text");
+
+    for text_entity in text_box.text_entities.iter().flatten() {
+        entities
+            .delete(*text_entity)
+            .expect("Failed to delete text");
+    }
+
     entities.delete(entity).expect("Failed to delete text box");
 }
 
+/// Creates a choice box whose prompt is displayed by a given text box.
+pub fn create_choice_box(text_box: TextBox, options: Vec<String>) -> ChoiceBox {
+    assert!(!options.is_empty(), "A choice box needs at least one option");
+
+    ChoiceBox {
+        text_box,
+        options,
+        focused_option: 0,
+        selected_option: None,
+        option_entities: None,
+    }
+}
+
+/// Displays the option list of a choice box, with the selection arrow
+/// pointing to the first option.
+pub fn show_choice_options(
+    choice_box: &mut ChoiceBox,
+    ui_images: &mut WriteStorage<UiImage>,
+    ui_texts: &mut WriteStorage<UiText>,
+    ui_transforms: &mut WriteStorage<UiTransform>,
+    entities: &Entities,
+    resources: &CommonResources,
+) {
+    let num_options = choice_box.options.len();
+    let box_height = CHOICE_OPTION_HEIGHT * num_options as f32 + 2. * CHOICE_BOX_MARGIN;
+
+    let box_entity = entities
+        .build_entity()
+        .with(
+            UiImage::Sprite(SpriteRender {
+                sprite_sheet: resources.text_box.clone(),
+                sprite_number: 0,
+            }),
+            ui_images,
+        )
+        .with(
+            UiTransform::new(
+                "Choice Box".to_string(),
+                Anchor::BottomRight,
+                Anchor::BottomRight,
+                -CHOICE_BOX_MARGIN,
+                get_choice_box_bottom_y(),
+                2.,
+                CHOICE_BOX_WIDTH,
+                box_height,
+            ),
+            ui_transforms,
+        )
+        .build();
+
+    let text_entities = choice_box
+        .options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let mut ui_text = UiText::new(
+                resources.font.clone(),
+                option.clone(),
+                [1., 1., 1., 1.],
+                30.,
+            );
+            ui_text.align = Anchor::MiddleLeft;
+
+            let ui_transform = UiTransform::new(
+                format!("Choice Option {}", index),
+                Anchor::BottomRight,
+                Anchor::BottomRight,
+                -2. * CHOICE_BOX_MARGIN,
+                get_choice_option_bottom_y(num_options, index),
+                3.,
+                CHOICE_BOX_WIDTH - CHOICE_ARROW_WIDTH - 3. * CHOICE_BOX_MARGIN,
+                CHOICE_OPTION_HEIGHT,
+            );
+
+            entities
+                .build_entity()
+                .with(ui_text, ui_texts)
+                .with(ui_transform, ui_transforms)
+                .build()
+        })
+        .collect();
+
+    let selection_arrow_entity = entities
+        .build_entity()
+        .with(
+            UiImage::Sprite(SpriteRender {
+                sprite_sheet: resources.selection_arrow.clone(),
+                sprite_number: 0,
+            }),
+            ui_images,
+        )
+        .with(
+            UiTransform::new(
+                "Choice Selection Arrow".to_string(),
+                Anchor::BottomRight,
+                Anchor::BottomRight,
+                CHOICE_ARROW_WIDTH - CHOICE_BOX_WIDTH,
+                get_choice_arrow_y(num_options, 0),
+                3.,
+                CHOICE_ARROW_WIDTH,
+                CHOICE_ARROW_HEIGHT,
+            ),
+            ui_transforms,
+        )
+        .build();
+
+    choice_box.focused_option = 0;
+    choice_box.option_entities = Some(ChoiceOptionEntities {
+        box_entity,
+        selection_arrow_entity,
+        text_entities,
+    });
+}
+
+/// Moves the selection arrow of a choice box by a given offset. Does nothing
+/// if the options aren't visible yet.
+pub fn move_choice_selection(
+    choice_box: &mut ChoiceBox,
+    offset: i8,
+    ui_transforms: &mut WriteStorage<UiTransform>,
+) {
+    if let Some(option_entities) = &choice_box.option_entities {
+        let num_options = choice_box.options.len();
+
+        choice_box.focused_option =
+            cycle_option(choice_box.focused_option, num_options as u8, offset);
+
+        ui_transforms
+            .get_mut(option_entities.selection_arrow_entity)
+            .expect("Failed to retrieve UiTransform")
+            .local_y = get_choice_arrow_y(num_options, choice_box.focused_option.into());
+    }
+}
+
+pub fn delete_choice_box(entity: Entity, choice_box: &mut ChoiceBox, entities: &Entities) {
+    if let Some(option_entities) = choice_box.option_entities.take() {
+        entities
+            .delete(option_entities.box_entity)
+            .expect("Failed to delete choice box");
+        entities
+            .delete(option_entities.selection_arrow_entity)
+            .expect("Failed to delete selection arrow");
+
+        for text_entity in option_entities.text_entities {
+            entities
+                .delete(text_entity)
+                .expect("Failed to delete choice option");
+        }
+    }
+
+    delete_text_box(entity, &mut choice_box.text_box, entities);
+}
+
+/// Checks if a text box has displayed its last page and is now waiting for a
+/// keypress.
+pub fn is_text_box_finished(text_box: &TextBox) -> bool {
+    text_box.awaiting_keypress && text_box.current_page + 1 == text_box.pages.len()
+}
+
 pub fn advance_text(
     pressed_action_key: bool,
     text_box: &mut TextBox,
@@ -84,51 +335,100 @@ pub fn advance_text(
     time: &Time,
     ui_texts: &mut WriteStorage<UiText>,
 ) -> TextState {
-    let full_text_length = text_box.full_text.len();
-    // TODO: extract to constant or make this more flexible
-    let maximum_display_length = 150;
-
     match (pressed_action_key, text_box.awaiting_keypress) {
         (true, true) => {
-            if text_box.displayed_text_end == full_text_length {
+            if is_text_box_finished(text_box) {
                 return TextState::Closed;
             } else {
-                text_box.displayed_text_start = text_box.displayed_text_end;
+                text_box.displayed_units = 0;
+                update_displayed_text(text_box, ui_texts);
+
+                text_box.current_page += 1;
                 text_box.awaiting_keypress = false;
             }
         },
         (true, false) => {
-            text_box.displayed_text_end =
-                full_text_length.min(text_box.displayed_text_start + maximum_display_length);
+            text_box.displayed_units = text_box.pages[text_box.current_page].units.len();
         },
         (false, false) => {
+            let units = &text_box.pages[text_box.current_page].units;
+
             text_box.cooldown += time.delta_seconds();
-            while text_box.cooldown >= game_config.text_delay {
-                text_box.cooldown -= game_config.text_delay;
+            loop {
+                match units.get(text_box.displayed_units) {
+                    Some(unit) => {
+                        let delay = unit.pause + game_config.text_delay / unit.speed;
 
-                let displayed_length = text_box.displayed_text_end - text_box.displayed_text_start;
+                        if text_box.cooldown < delay {
+                            break;
+                        }
 
-                if text_box.displayed_text_end == full_text_length
-                    || displayed_length == maximum_display_length
-                {
-                    text_box.awaiting_keypress = true;
-                } else {
-                    text_box.displayed_text_end += 1;
+                        text_box.cooldown -= delay;
+                        text_box.displayed_units += 1;
+                    },
+                    None => {
+                        text_box.awaiting_keypress = true;
+                        break;
+                    },
                 }
             }
         },
         _ => {},
     }
 
-    ui_texts
-        .get_mut(text_box.text_entity)
-        .expect("Failed to retrieve UiText")
-        .text =
-        text_box.full_text[text_box.displayed_text_start..text_box.displayed_text_end].to_string();
+    update_displayed_text(text_box, ui_texts);
 
     TextState::Running
 }
 
+fn update_displayed_text(text_box: &TextBox, ui_texts: &mut WriteStorage<UiText>) {
+    let page = &text_box.pages[text_box.current_page];
+    let text_entities = &text_box.text_entities[text_box.current_page];
+
+    for (run, text_entity) in page.runs.iter().zip(text_entities) {
+        ui_texts
+            .get_mut(*text_entity)
+            .expect("Failed to retrieve UiText")
+            .text = page.get_run_text(run, text_box.displayed_units);
+    }
+}
+
+/// Parses the markup of a text and splits it in pages, measuring each
+/// character with the font used by text boxes.
+fn layout_text(
+    full_text: &str,
+    resources: &CommonResources,
+    layout_data: &TextLayoutData,
+) -> Vec<TextPage> {
+    let tokens = parse_markup(full_text, &layout_data.game_variables);
+    let scale = Scale::uniform(TEXT_FONT_SIZE);
+
+    match layout_data.fonts.get(&resources.font) {
+        Some(font) => paginate(&tokens, TEXT_WIDTH, TEXT_MAX_LINES, |character| {
+            font.0.glyph(character).scaled(scale).h_metrics().advance_width
+        }),
+        // Rough estimate for when the font hasn't been loaded yet
+        None => paginate(&tokens, TEXT_WIDTH, TEXT_MAX_LINES, |_| TEXT_FONT_SIZE / 2.),
+    }
+}
+
+fn get_choice_box_bottom_y() -> f32 {
+    // Right above the text box
+    130.
+}
+
+fn get_choice_option_bottom_y(num_options: usize, option_index: usize) -> f32 {
+    let inverted_option = (num_options - 1 - option_index) as f32;
+
+    get_choice_box_bottom_y() + CHOICE_BOX_MARGIN + CHOICE_OPTION_HEIGHT * inverted_option
+}
+
+fn get_choice_arrow_y(num_options: usize, focused_option: usize) -> f32 {
+    let height_difference = CHOICE_OPTION_HEIGHT - CHOICE_ARROW_HEIGHT;
+
+    get_choice_option_bottom_y(num_options, focused_option) + height_difference / 2.
+}
+
 fn initialise_box_entity(
     entities: &Entities,
     ui_images: &mut WriteStorage<UiImage>,
@@ -159,6 +459,7 @@ fn initialise_box_entity(
 }
 
 fn initialise_text_entity(
+    run: &TextRun,
     entities: &Entities,
     ui_texts: &mut WriteStorage<UiText>,
     ui_transforms: &mut WriteStorage<UiTransform>,
@@ -167,21 +468,21 @@ fn initialise_text_entity(
     let mut ui_text = UiText::new(
         resources.font.clone(),
         "".to_string(),
-        [1., 1., 1., 1.],
-        30.,
+        run.color,
+        TEXT_FONT_SIZE,
     );
-    ui_text.line_mode = LineMode::Wrap;
+    ui_text.line_mode = LineMode::Single;
     ui_text.align = Anchor::TopLeft;
 
     let ui_transform = UiTransform::new(
         "Text".to_string(),
         Anchor::BottomMiddle,
-        Anchor::BottomLeft,
-        -320.,
-        17.,
+        Anchor::TopLeft,
+        -TEXT_WIDTH / 2. + run.x,
+        117. - TEXT_LINE_HEIGHT * run.line as f32,
         3.,
-        640.,
-        100.,
+        TEXT_WIDTH - run.x,
+        TEXT_LINE_HEIGHT,
     );
 
     entities
@@ -0,0 +1,100 @@
+use crate::game_variables::GameVariables;
+
+use super::markup::{paginate, parse_markup, TextPage};
+
+const MAX_LINE_WIDTH: f32 = 5.;
+const MAX_LINES: usize = 2;
+
+fn paginate_text(text: &str) -> Vec<TextPage> {
+    let tokens = parse_markup(text, &GameVariables::default());
+
+    paginate(&tokens, MAX_LINE_WIDTH, MAX_LINES, |_| 1.)
+}
+
+/// Returns the text of every line of a page.
+fn get_lines(page: &TextPage) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for run in &page.runs {
+        if lines.len() <= run.line {
+            lines.resize(run.line + 1, String::new());
+        }
+
+        lines[run.line].push_str(&page.get_run_text(run, page.units.len()));
+    }
+
+    lines
+}
+
+fn get_pages(text: &str) -> Vec<Vec<String>> {
+    paginate_text(text).iter().map(get_lines).collect()
+}
+
+fn to_pages(pages: &[&[&str]]) -> Vec<Vec<String>> {
+    pages
+        .iter()
+        .map(|lines| lines.iter().map(|line| line.to_string()).collect())
+        .collect()
+}
+
+#[test]
+fn wraps_lines_at_word_boundaries() {
+    assert_eq!(
+        get_pages("ab cde fg"),
+        to_pages(&[&["ab ", "cde "], &["fg"]])
+    );
+}
+
+#[test]
+fn splits_words_longer_than_a_line() {
+    assert_eq!(get_pages("abcdefg"), to_pages(&[&["abcde", "fg"]]));
+}
+
+#[test]
+fn does_not_break_words_at_pauses() {
+    let pages = paginate_text("ab he{PAUSE:0.5}llo");
+
+    assert_eq!(
+        pages.iter().map(get_lines).collect::<Vec<_>>(),
+        to_pages(&[&["ab ", "hello"]])
+    );
+
+    let pauses: Vec<f32> = pages[0].units.iter().map(|unit| unit.pause).collect();
+    assert_eq!(pauses, vec![0., 0., 0., 0., 0., 0.5, 0., 0.]);
+}
+
+#[test]
+fn does_not_break_words_at_color_changes() {
+    let pages = paginate_text("ab he{COLOR:RED}ll{/COLOR}o");
+
+    assert_eq!(
+        pages.iter().map(get_lines).collect::<Vec<_>>(),
+        to_pages(&[&["ab ", "hello"]])
+    );
+    assert_eq!(pages[0].runs.len(), 4);
+    assert!(pages[0].runs[1..].iter().all(|run| run.line == 1));
+}
+
+#[test]
+fn starts_a_new_page_when_lines_run_out() {
+    assert_eq!(
+        get_pages("abc de fgh ij"),
+        to_pages(&[&["abc ", "de "], &["fgh ", "ij"]])
+    );
+}
+
+#[test]
+fn applies_explicit_line_and_page_breaks() {
+    assert_eq!(
+        get_pages("ab\\ncd\\pef"),
+        to_pages(&[&["ab", "cd"], &["ef"]])
+    );
+}
+
+#[test]
+fn carries_pauses_across_page_breaks() {
+    let pages = paginate_text("ab{PAUSE:1}\\pcd");
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[1].units[0].pause, 1.);
+}